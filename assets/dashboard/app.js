@@ -0,0 +1,534 @@
+// Tableau de bord IronForge : page statique qui n'utilise que l'API HTTP publique
+'use strict';
+
+const API = '/v1';
+const KEY_STORAGE = 'ironforge.apiKey';
+const STATS_INTERVAL_MS = 5000;
+const HISTORY_POINTS = 120;
+const THROUGHPUT_MINUTES = 30;
+
+const state = {
+  depthHistory: [],
+  finishedPerMinute: new Map(),
+  jobsCursor: null,
+  dlqCursor: null,
+  statsTimer: null,
+  events: null,
+};
+
+// ---------------------------------------------------------------- helpers
+
+const $ = (selector) => document.querySelector(selector);
+
+function escapeHtml(value) {
+  return String(value ?? '').replace(/[&<>"']/g, (c) => ({
+    '&': '&amp;', '<': '&lt;', '>': '&gt;', '"': '&quot;', "'": '&#39;',
+  }[c]));
+}
+
+function formatDate(value) {
+  return value ? new Date(value).toLocaleString() : '—';
+}
+
+function formatJson(value) {
+  return escapeHtml(JSON.stringify(value, null, 2));
+}
+
+function authHeaders() {
+  const key = localStorage.getItem(KEY_STORAGE);
+  return key ? { Authorization: `Bearer ${key}` } : {};
+}
+
+function showError(message) {
+  const banner = $('#error');
+  banner.textContent = message;
+  banner.hidden = !message;
+}
+
+async function api(method, path, body) {
+  const options = { method, headers: authHeaders() };
+  if (body !== undefined) {
+    options.headers['Content-Type'] = 'application/json';
+    options.body = JSON.stringify(body);
+  }
+
+  const response = await fetch(API + path, options);
+  if (response.status === 204) {
+    return null;
+  }
+
+  const payload = await response.json().catch(() => null);
+  if (!response.ok) {
+    // Corps d'erreur RFC 7807
+    const detail = payload && (payload.detail || payload.title);
+    throw new Error(`${response.status} ${detail || response.statusText}`);
+  }
+  return payload;
+}
+
+async function guarded(action) {
+  try {
+    showError('');
+    await action();
+  } catch (e) {
+    showError(e.message);
+  }
+}
+
+function query(params) {
+  const search = new URLSearchParams();
+  for (const [name, value] of Object.entries(params)) {
+    if (value !== '' && value !== null && value !== undefined) {
+      search.set(name, value);
+    }
+  }
+  const text = search.toString();
+  return text ? `?${text}` : '';
+}
+
+function statusBadge(status) {
+  return `<span class="badge ${escapeHtml(status)}">${escapeHtml(status)}</span>`;
+}
+
+// ---------------------------------------------------------------- charts
+
+function drawLine(svg, values, color) {
+  const width = 600;
+  const height = 120;
+  const max = Math.max(1, ...values);
+  const step = values.length > 1 ? width / (values.length - 1) : width;
+  const points = values
+    .map((value, i) => `${(i * step).toFixed(1)},${(height - (value / max) * (height - 10)).toFixed(1)}`)
+    .join(' ');
+
+  svg.innerHTML = `
+    <polyline fill="none" stroke="${color}" stroke-width="2" points="${points}"></polyline>
+    <text x="4" y="12" class="axis">${max}</text>`;
+}
+
+function minuteKey(date) {
+  return Math.floor(date.getTime() / 60000);
+}
+
+function drawThroughput() {
+  const now = minuteKey(new Date());
+  const values = [];
+  for (let minute = now - THROUGHPUT_MINUTES + 1; minute <= now; minute += 1) {
+    values.push(state.finishedPerMinute.get(minute) || 0);
+  }
+  for (const minute of state.finishedPerMinute.keys()) {
+    if (minute <= now - THROUGHPUT_MINUTES) {
+      state.finishedPerMinute.delete(minute);
+    }
+  }
+
+  drawLine($('#throughput-chart'), values, '#2f9e44');
+  const lastMinute = values[values.length - 2] || 0;
+  $('#throughput-summary').textContent =
+    `${lastMinute} jobs finished last minute, ${values.reduce((a, b) => a + b, 0)} in the last ${THROUGHPUT_MINUTES} minutes.`;
+}
+
+// ---------------------------------------------------------------- live events
+
+// EventSource ne peut pas envoyer de header Authorization : le flux est lu avec fetch
+async function watchEvents() {
+  if (state.events) {
+    state.events.abort();
+  }
+  const controller = new AbortController();
+  state.events = controller;
+
+  try {
+    const response = await fetch(`${API}/events`, { headers: authHeaders(), signal: controller.signal });
+    if (!response.ok || !response.body) {
+      return;
+    }
+
+    const reader = response.body.pipeThrough(new TextDecoderStream()).getReader();
+    let buffer = '';
+    for (;;) {
+      const { value, done } = await reader.read();
+      if (done) {
+        break;
+      }
+      buffer += value;
+
+      let end;
+      while ((end = buffer.indexOf('\n\n')) >= 0) {
+        const block = buffer.slice(0, end);
+        buffer = buffer.slice(end + 2);
+        const data = block
+          .split('\n')
+          .filter((line) => line.startsWith('data:'))
+          .map((line) => line.slice(5).trim())
+          .join('\n');
+        if (data) {
+          onEvent(JSON.parse(data));
+        }
+      }
+    }
+  } catch (e) {
+    if (e.name === 'AbortError') {
+      return;
+    }
+  }
+
+  // Reconnexion après une coupure
+  if (state.events === controller) {
+    setTimeout(watchEvents, STATS_INTERVAL_MS);
+  }
+}
+
+function onEvent(event) {
+  if (event.type !== 'status' || !['completed', 'failed', 'deadletter'].includes(event.status)) {
+    return;
+  }
+  const minute = minuteKey(new Date(event.timestamp));
+  state.finishedPerMinute.set(minute, (state.finishedPerMinute.get(minute) || 0) + 1);
+  drawThroughput();
+}
+
+// ---------------------------------------------------------------- overview
+
+async function refreshStats() {
+  const stats = await api('GET', '/queues/stats');
+
+  const cards = [
+    ['Queued', stats.queue_depth],
+    ['Running', stats.active_jobs],
+    ['Delayed', stats.delayed_jobs],
+    ['Parked', stats.parked_jobs],
+    ['Dead letters', stats.dlq_depth],
+    ['Total', stats.total_jobs],
+  ];
+  // Un tenant ne reçoit que la profondeur de sa queue
+  $('#stats').innerHTML = cards
+    .filter(([, value]) => value !== undefined)
+    .map(([label, value]) => `<div class="card"><span>${label}</span><strong>${value ?? 0}</strong></div>`)
+    .join('');
+
+  const tenants = Object.entries(stats.tenant_queue_depths || {});
+  $('#tenants').innerHTML = tenants.length
+    ? '<tr><th>Tenant</th><th>Queued</th></tr>' +
+      tenants.map(([tenant, depth]) => `<tr><td>${escapeHtml(tenant)}</td><td>${depth}</td></tr>`).join('')
+    : '<tr><td class="hint">No tenant queues</td></tr>';
+
+  const paused = [
+    ...(stats.paused_queues || []).map((name) => ['queue', 'queues', name]),
+    ...(stats.paused_kinds || []).map((name) => ['kind', 'kinds', name]),
+  ];
+  $('#paused').innerHTML = paused.length
+    ? paused
+        .map(([label, path, name]) => `<li>${label} <code>${escapeHtml(name)}</code>
+          <button data-resume="/${path}/${encodeURIComponent(name)}/resume">Resume</button></li>`)
+        .join('')
+    : '<li class="hint">Nothing is paused</li>';
+
+  state.depthHistory.push(stats.queue_depth);
+  if (state.depthHistory.length > HISTORY_POINTS) {
+    state.depthHistory.shift();
+  }
+  drawLine($('#depth-chart'), state.depthHistory, '#1c7ed6');
+  drawThroughput();
+}
+
+// ---------------------------------------------------------------- jobs
+
+function jobFilters(form) {
+  const data = new FormData(form);
+  const params = {};
+  for (const name of ['status', 'kind', 'queue']) {
+    if (data.has(name)) {
+      params[name] = data.get(name).trim();
+    }
+  }
+  const createdAfter = data.get('created_after');
+  if (createdAfter) {
+    params.created_after = new Date(createdAfter).toISOString();
+  }
+  const metadata = (data.get('metadata') || '').trim();
+  if (metadata.includes('=')) {
+    const [key, ...rest] = metadata.split('=');
+    params[`metadata.${key.trim()}`] = rest.join('=').trim();
+  }
+  return params;
+}
+
+function jobRow(job, selectable) {
+  return `<tr>
+    ${selectable ? `<td><input type="checkbox" value="${escapeHtml(job.id)}"></td>` : ''}
+    <td><a href="#job/${escapeHtml(job.id)}"><code>${escapeHtml(job.id.slice(0, 8))}</code></a></td>
+    <td>${escapeHtml(job.kind)}</td>
+    <td>${escapeHtml(job.queue)}</td>
+    <td>${statusBadge(job.status)}</td>
+    <td>${escapeHtml(job.priority)}</td>
+    <td>${job.retry_count}/${job.max_retries}</td>
+    <td>${formatDate(job.created_at)}</td>
+    ${selectable ? `<td class="error-text">${escapeHtml(job.last_error || '')}</td>` : ''}
+  </tr>`;
+}
+
+function jobHeader(selectable) {
+  return `<tr>${selectable ? '<th><input type="checkbox" data-select-all></th>' : ''}
+    <th>Id</th><th>Kind</th><th>Queue</th><th>Status</th><th>Priority</th><th>Retries</th><th>Created</th>
+    ${selectable ? '<th>Last error</th>' : ''}</tr>`;
+}
+
+async function loadJobs(append) {
+  const params = { ...jobFilters($('#job-search')), limit: 50 };
+  if (append) {
+    params.cursor = state.jobsCursor;
+  }
+  const page = await api('GET', `/jobs${query(params)}`);
+
+  const table = $('#jobs');
+  const rows = page.jobs.map((job) => jobRow(job, false)).join('');
+  table.innerHTML = append ? table.innerHTML + rows : jobHeader(false) + rows;
+  state.jobsCursor = page.next_cursor;
+  $('#jobs-more').hidden = !page.next_cursor;
+}
+
+async function showJob(id) {
+  const job = await api('GET', `/jobs/${encodeURIComponent(id)}`);
+
+  const attempts = (job.attempts || [])
+    .map((attempt) => `<tr>
+      <td>${attempt.attempt}</td>
+      <td><code>${escapeHtml(attempt.worker_id)}</code></td>
+      <td>${formatDate(attempt.started_at)}</td>
+      <td>${attempt.duration_ms === null || attempt.duration_ms === undefined ? '—' : `${attempt.duration_ms} ms`}</td>
+      <td>${statusBadge(attempt.outcome)}</td>
+      <td class="error-text">${escapeHtml(attempt.error || '')}</td>
+    </tr>`)
+    .join('');
+
+  const actions = [];
+  if (['failed', 'deadletter'].includes(job.status)) {
+    actions.push(`<button data-job-action="retry" data-id="${escapeHtml(job.id)}">Retry</button>`);
+  }
+  if (['queued', 'waiting'].includes(job.status)) {
+    actions.push(`<button class="danger" data-job-action="delete" data-id="${escapeHtml(job.id)}">Delete</button>`);
+  }
+
+  $('#job-detail').innerHTML = `
+    <h2>${escapeHtml(job.kind)} ${statusBadge(job.status)}</h2>
+    <div class="actions">${actions.join('')}</div>
+    <dl>
+      <dt>Id</dt><dd><code>${escapeHtml(job.id)}</code></dd>
+      <dt>Queue</dt><dd>${escapeHtml(job.queue)}</dd>
+      <dt>Priority</dt><dd>${escapeHtml(job.priority)}</dd>
+      <dt>Retries</dt><dd>${job.retry_count}/${job.max_retries}</dd>
+      <dt>Created</dt><dd>${formatDate(job.created_at)}</dd>
+      <dt>Scheduled for</dt><dd>${formatDate(job.scheduled_for)}</dd>
+      <dt>Timeout</dt><dd>${job.timeout_ms} ms</dd>
+      <dt>Version</dt><dd>${job.version ?? 0}</dd>
+      ${job.tenant ? `<dt>Tenant</dt><dd>${escapeHtml(job.tenant)}</dd>` : ''}
+      ${job.submitted_by ? `<dt>Submitted by</dt><dd>${escapeHtml(job.submitted_by)}</dd>` : ''}
+      ${job.last_error ? `<dt>Last error</dt><dd class="error-text">${escapeHtml(job.last_error)}</dd>` : ''}
+    </dl>
+    <h3>Attempts</h3>
+    ${attempts
+      ? `<table class="list"><tr><th>#</th><th>Worker</th><th>Started</th><th>Duration</th><th>Outcome</th><th>Error</th></tr>${attempts}</table>`
+      : '<p class="hint">Not executed yet</p>'}
+    <h3>Result</h3>
+    <pre>${job.result === undefined || job.result === null ? '—' : formatJson(job.result)}</pre>
+    <h3>Payload</h3>
+    <pre>${formatJson(job.payload)}</pre>
+    <h3>Metadata</h3>
+    <pre>${formatJson(job.metadata || {})}</pre>`;
+}
+
+async function jobAction(action, id) {
+  if (action === 'retry') {
+    await api('POST', `/jobs/${encodeURIComponent(id)}/retry`, {});
+  } else if (action === 'delete') {
+    if (!confirm('Delete this job?')) {
+      return;
+    }
+    await api('DELETE', `/jobs/${encodeURIComponent(id)}`);
+    location.hash = '#jobs';
+    return;
+  }
+  await showJob(id);
+}
+
+// ---------------------------------------------------------------- dead letters
+
+async function loadDlq(append) {
+  const params = { ...jobFilters($('#dlq-search')), limit: 50 };
+  if (append) {
+    params.cursor = state.dlqCursor;
+  }
+  const page = await api('GET', `/dlq${query(params)}`);
+
+  const table = $('#dlq');
+  const rows = page.jobs.map((job) => jobRow(job, true)).join('');
+  table.innerHTML = append ? table.innerHTML + rows : jobHeader(true) + rows;
+  state.dlqCursor = page.next_cursor;
+  $('#dlq-more').hidden = !page.next_cursor;
+}
+
+async function dlqBulk(operation, selectedOnly) {
+  const body = { reset_retry_count: $('#dlq-reset').checked };
+  let scope = '';
+  let description;
+
+  if (selectedOnly) {
+    body.ids = [...document.querySelectorAll('#dlq td input:checked')].map((input) => input.value);
+    if (!body.ids.length) {
+      showError('Select at least one job');
+      return;
+    }
+    description = `${body.ids.length} selected jobs`;
+  } else {
+    scope = query(jobFilters($('#dlq-search')));
+    description = 'every dead-lettered job matching the filters';
+  }
+  if (!confirm(`${operation === 'purge' ? 'Purge' : 'Requeue'} ${description}?`)) {
+    return;
+  }
+
+  // Les opérations groupées sont bornées : relancer tant qu'il en reste
+  let total = 0;
+  for (;;) {
+    const result = await api('POST', `/dlq/${operation}${scope}`, body);
+    total += result.count;
+    if (!result.has_more || body.ids) {
+      break;
+    }
+  }
+  showError('');
+  alert(`${total} jobs ${operation === 'purge' ? 'purged' : 'requeued'}`);
+  await loadDlq(false);
+}
+
+// ---------------------------------------------------------------- workers and schedules
+
+async function loadWorkers() {
+  const workers = await api('GET', '/workers');
+  $('#workers').innerHTML = '<tr><th>Id</th><th>Host</th><th>Version</th><th>Kinds</th><th>Busy</th><th>Started</th><th>Last heartbeat</th></tr>' +
+    workers
+      .map((worker) => `<tr>
+        <td><code>${escapeHtml(worker.id)}</code></td>
+        <td>${escapeHtml(worker.hostname)}</td>
+        <td>${escapeHtml(worker.version)}</td>
+        <td>${worker.kinds.length ? worker.kinds.map(escapeHtml).join(', ') : 'all'}</td>
+        <td>${worker.current_jobs.length}/${worker.concurrency}</td>
+        <td>${formatDate(worker.started_at)}</td>
+        <td>${formatDate(worker.last_heartbeat)}</td>
+      </tr>`)
+      .join('');
+}
+
+async function loadSchedules() {
+  const schedules = await api('GET', '/schedules');
+  $('#schedules').innerHTML = '<tr><th>Name</th><th>Cron</th><th>Timezone</th><th>Job kind</th><th>Enabled</th><th>Last run</th><th>Next run</th></tr>' +
+    schedules
+      .map((schedule) => `<tr>
+        <td>${escapeHtml(schedule.name)}</td>
+        <td><code>${escapeHtml(schedule.cron)}</code></td>
+        <td>${escapeHtml(schedule.timezone)}</td>
+        <td>${escapeHtml(schedule.job.kind)}</td>
+        <td>${schedule.enabled ? 'yes' : 'no'}</td>
+        <td>${schedule.last_job_id
+          ? `<a href="#job/${escapeHtml(schedule.last_job_id)}">${formatDate(schedule.last_run_at)}</a>`
+          : formatDate(schedule.last_run_at)}</td>
+        <td>${formatDate(schedule.next_run_at)}</td>
+      </tr>`)
+      .join('');
+}
+
+// ---------------------------------------------------------------- routing
+
+function route() {
+  const [view, id] = (location.hash.slice(1) || 'overview').split('/');
+  for (const section of document.querySelectorAll('.view')) {
+    section.hidden = section.id !== `view-${view}`;
+  }
+  for (const link of document.querySelectorAll('nav a')) {
+    link.classList.toggle('active', link.getAttribute('href') === `#${view}`);
+  }
+
+  clearInterval(state.statsTimer);
+  state.statsTimer = null;
+
+  switch (view) {
+    case 'overview':
+      guarded(refreshStats);
+      state.statsTimer = setInterval(() => guarded(refreshStats), STATS_INTERVAL_MS);
+      break;
+    case 'jobs':
+      guarded(() => loadJobs(false));
+      break;
+    case 'job':
+      guarded(() => showJob(id));
+      break;
+    case 'dlq':
+      guarded(() => loadDlq(false));
+      break;
+    case 'workers':
+      guarded(loadWorkers);
+      break;
+    case 'schedules':
+      guarded(loadSchedules);
+      break;
+    default:
+      location.hash = '#overview';
+  }
+}
+
+function init() {
+  $('#api-key').value = localStorage.getItem(KEY_STORAGE) || '';
+  $('#auth').addEventListener('submit', (event) => {
+    event.preventDefault();
+    const key = $('#api-key').value.trim();
+    if (key) {
+      localStorage.setItem(KEY_STORAGE, key);
+    } else {
+      localStorage.removeItem(KEY_STORAGE);
+    }
+    watchEvents();
+    route();
+  });
+
+  $('#job-search').addEventListener('submit', (event) => {
+    event.preventDefault();
+    guarded(() => loadJobs(false));
+  });
+  $('#jobs-more').addEventListener('click', () => guarded(() => loadJobs(true)));
+
+  $('#dlq-search').addEventListener('submit', (event) => {
+    event.preventDefault();
+    guarded(() => loadDlq(false));
+  });
+  $('#dlq-more').addEventListener('click', () => guarded(() => loadDlq(true)));
+  $('#dlq-requeue-selected').addEventListener('click', () => guarded(() => dlqBulk('requeue', true)));
+  $('#dlq-purge-selected').addEventListener('click', () => guarded(() => dlqBulk('purge', true)));
+  $('#dlq-requeue-all').addEventListener('click', () => guarded(() => dlqBulk('requeue', false)));
+  $('#dlq-purge-all').addEventListener('click', () => guarded(() => dlqBulk('purge', false)));
+
+  document.addEventListener('click', (event) => {
+    const target = event.target;
+    if (target.dataset.jobAction) {
+      guarded(() => jobAction(target.dataset.jobAction, target.dataset.id));
+    } else if (target.dataset.resume) {
+      guarded(async () => {
+        await api('POST', target.dataset.resume);
+        await refreshStats();
+      });
+    }
+  });
+  document.addEventListener('change', (event) => {
+    if (event.target.dataset.selectAll !== undefined) {
+      for (const input of document.querySelectorAll('#dlq td input[type=checkbox]')) {
+        input.checked = event.target.checked;
+      }
+    }
+  });
+
+  window.addEventListener('hashchange', route);
+  watchEvents();
+  route();
+}
+
+init();
@@ -0,0 +1,10 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // protoc embarqué : aucune installation requise pour compiler le crate
+    std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+
+    let includes = ["proto".into(), protoc_bin_vendored::include_path()?];
+    tonic_build::configure().compile_protos(&["proto/ironforge.proto"], &includes)?;
+
+    println!("cargo:rerun-if-changed=proto");
+    Ok(())
+}
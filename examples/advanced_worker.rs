@@ -95,6 +95,7 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         dequeue_timeout_secs: 5,
         worker_count: 4, // 4 workers concurrents
         graceful_shutdown: true,
+        ..Default::default()
     };
 
     tracing::info!(
@@ -0,0 +1,262 @@
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Request},
+    http::{header, request::Parts, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use super::config::ApiConfig;
+use super::error::ApiError;
+use crate::models::{validate_tenant, Job, JobEvent};
+
+/// Header alternatif à `Authorization: Bearer <clé>`
+pub const API_KEY_HEADER: &str = "x-api-key";
+
+/// Droits d'une clé d'API
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Scope {
+    /// Soumettre des jobs, lots et workflows
+    #[serde(rename = "jobs:write")]
+    JobsWrite,
+    /// Consulter les jobs, workflows, planifications, statistiques et événements
+    #[serde(rename = "jobs:read")]
+    JobsRead,
+    /// Supprimer des jobs, gérer les planifications, mettre en pause (inclut tous les droits)
+    #[serde(rename = "admin")]
+    Admin,
+    /// Consulter et gérer la DLQ
+    #[serde(rename = "dlq")]
+    Dlq,
+}
+
+/// Clé d'API déclarée dans le fichier `API_KEYS_FILE`
+#[derive(Debug, Clone, Deserialize)]
+pub struct ApiKey {
+    /// Nom de la clé, enregistré sur les jobs qu'elle soumet
+    pub name: String,
+
+    /// Clé en clair
+    #[serde(default)]
+    pub key: Option<String>,
+
+    /// Empreinte SHA-256 (hex) de la clé, à préférer à `key`
+    #[serde(default)]
+    pub key_sha256: Option<String>,
+
+    pub scopes: Vec<Scope>,
+
+    /// Types de jobs accessibles (vide = tous)
+    #[serde(default)]
+    pub kinds: Vec<String>,
+
+    /// Queues accessibles (vide = toutes)
+    #[serde(default)]
+    pub queues: Vec<String>,
+
+    /// Tenant de la clé : ses jobs y sont rattachés et elle ne voit que ceux-ci
+    /// (absent = clé de plateforme, qui voit tous les jobs)
+    #[serde(default)]
+    pub tenant: Option<String>,
+}
+
+impl ApiKey {
+    /// Vérifie la cohérence de la déclaration
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if let Some(tenant) = &self.tenant {
+            validate_tenant(tenant).map_err(|e| format!("API key {}: {}", self.name, e))?;
+        }
+
+        match (&self.key, &self.key_sha256) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            _ => Err(format!("API key {} must define exactly one of key or key_sha256", self.name)),
+        }
+    }
+
+    /// Compare le jeton présenté à la clé (en temps constant sur les empreintes)
+    fn matches(&self, token: &str) -> bool {
+        let expected = match (&self.key, &self.key_sha256) {
+            (Some(key), _) => hex::encode(Sha256::digest(key.as_bytes())),
+            (None, Some(digest)) => digest.to_ascii_lowercase(),
+            (None, None) => return false,
+        };
+        let presented = hex::encode(Sha256::digest(token.as_bytes()));
+
+        expected.len() == presented.len()
+            && expected
+                .bytes()
+                .zip(presented.bytes())
+                .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+                == 0
+    }
+
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scopes.contains(&scope) || self.scopes.contains(&Scope::Admin)
+    }
+
+    /// Indique si la clé peut agir sur un job de ce type et de cette queue
+    pub fn allows(&self, kind: &str, queue: &str) -> bool {
+        (self.kinds.is_empty() || self.kinds.iter().any(|k| k == kind))
+            && (self.queues.is_empty() || self.queues.iter().any(|q| q == queue))
+    }
+}
+
+/// Appelant authentifié de la requête (anonyme et sans restriction quand
+/// l'authentification est désactivée)
+#[derive(Debug, Clone, Default)]
+pub struct Caller(Option<Arc<ApiKey>>);
+
+impl Caller {
+    /// Nom de la clé utilisée
+    pub fn name(&self) -> Option<String> {
+        self.0.as_ref().map(|key| key.name.clone())
+    }
+
+    /// Tenant de la clé utilisée
+    pub fn tenant(&self) -> Option<String> {
+        self.0.as_ref().and_then(|key| key.tenant.clone())
+    }
+
+    /// Indique si l'appelant peut agir sur un job de ce type, de cette queue
+    /// et de ce tenant
+    pub fn allows(&self, kind: &str, queue: &str, tenant: Option<&str>) -> bool {
+        self.sees_tenant(tenant) && self.0.as_ref().is_none_or(|key| key.allows(kind, queue))
+    }
+
+    /// Indique si l'appelant voit les ressources de ce tenant
+    /// (une clé sans tenant voit tous les tenants)
+    pub fn sees_tenant(&self, tenant: Option<&str>) -> bool {
+        self.0
+            .as_ref()
+            .and_then(|key| key.tenant.as_deref())
+            .is_none_or(|own| tenant == Some(own))
+    }
+
+    pub fn allows_job(&self, job: &Job) -> bool {
+        self.allows(&job.kind, &job.queue, job.tenant.as_deref())
+    }
+
+    pub fn allows_event(&self, event: &JobEvent) -> bool {
+        self.allows(&event.kind, &event.queue, event.tenant.as_deref())
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Caller {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts.extensions.get::<Caller>().cloned().unwrap_or_default())
+    }
+}
+
+/// Authentifie la requête et vérifie que la clé possède `scope`.
+///
+/// Sans clé configurée, l'authentification est désactivée.
+pub async fn authorize(config: Arc<ApiConfig>, scope: Scope, mut req: Request, next: Next) -> Response {
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or_else(|| req.headers().get(API_KEY_HEADER).and_then(|value| value.to_str().ok()));
+
+    match authenticate(&config, token, scope) {
+        Ok(caller) => {
+            req.extensions_mut().insert(caller);
+            next.run(req).await
+        }
+        Err(error) if error.status == StatusCode::UNAUTHORIZED => {
+            ([(header::WWW_AUTHENTICATE, "Bearer")], error).into_response()
+        }
+        Err(error) => error.into_response(),
+    }
+}
+
+/// Identifie l'appelant à partir du jeton présenté et vérifie qu'il possède
+/// `scope` (appelant anonyme quand l'authentification est désactivée)
+pub fn authenticate(config: &ApiConfig, token: Option<&str>, scope: Scope) -> Result<Caller, ApiError> {
+    if config.api_keys.is_empty() {
+        return Ok(Caller::default());
+    }
+
+    let Some(key) = token.and_then(|token| config.api_keys.iter().find(|key| key.matches(token))) else {
+        return Err(ApiError::new(StatusCode::UNAUTHORIZED, "Missing or invalid API key"));
+    };
+
+    if !key.has_scope(scope) {
+        tracing::warn!(key = %key.name, scope = ?scope, "API key lacks required scope");
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            format!("API key {} lacks the required scope", key.name),
+        ));
+    }
+
+    Ok(Caller(Some(Arc::new(key.clone()))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(json: serde_json::Value) -> ApiKey {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_api_key_matching_and_restrictions() {
+        let plain = key(serde_json::json!({
+            "name": "ci",
+            "key": "s3cret",
+            "scopes": ["jobs:write"],
+            "kinds": ["email.send"],
+        }));
+        assert!(plain.validate().is_ok());
+        assert!(plain.matches("s3cret"));
+        assert!(!plain.matches("s3cre"));
+        assert!(plain.has_scope(Scope::JobsWrite));
+        assert!(!plain.has_scope(Scope::Dlq));
+        assert!(plain.allows("email.send", "default"));
+        assert!(!plain.allows("report.generate", "default"));
+
+        let hashed = key(serde_json::json!({
+            "name": "ops",
+            "key_sha256": hex::encode(Sha256::digest(b"ops-key")),
+            "scopes": ["admin"],
+        }));
+        assert!(hashed.matches("ops-key"));
+        assert!(hashed.has_scope(Scope::Dlq));
+
+        let invalid = key(serde_json::json!({ "name": "none", "scopes": [] }));
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_caller_tenant_visibility() {
+        let caller = Caller(Some(Arc::new(key(serde_json::json!({
+            "name": "team-a-ci",
+            "key": "a",
+            "scopes": ["jobs:read"],
+            "tenant": "team-a",
+        })))));
+        assert_eq!(caller.tenant().as_deref(), Some("team-a"));
+
+        let mut job = Job::new("email.send".to_string(), serde_json::json!({}));
+        assert!(!caller.allows_job(&job));
+        assert!(Caller::default().allows_job(&job));
+
+        job.tenant = Some("team-a".to_string());
+        assert!(caller.allows_job(&job));
+        job.tenant = Some("team-b".to_string());
+        assert!(!caller.allows_job(&job));
+
+        let invalid = key(serde_json::json!({
+            "name": "bad", "key": "b", "scopes": [], "tenant": "team:b"
+        }));
+        assert!(invalid.validate().is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use axum::extract::FromRef;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::auth::ApiKey;
+use crate::models::{validate_tenant, IronForgeError, Result, TenantQuota, ValidationRules};
+use crate::queue::QueueBackend;
+
+/// Configuration de l'API HTTP
+#[derive(Debug, Clone)]
+pub struct ApiConfig {
+    /// Durée de conservation des clés d'idempotence (en secondes)
+    pub idempotency_ttl_secs: u64,
+
+    /// Clés d'API acceptées (vide = authentification désactivée)
+    pub api_keys: Vec<ApiKey>,
+
+    /// Quotas par tenant (tenant absent = illimité)
+    pub tenant_quotas: HashMap<String, TenantQuota>,
+
+    /// Règles de validation des jobs soumis
+    pub validation: ValidationRules,
+}
+
+impl Default for ApiConfig {
+    fn default() -> Self {
+        Self {
+            idempotency_ttl_secs: 24 * 3600,
+            api_keys: Vec::new(),
+            tenant_quotas: HashMap::new(),
+            validation: ValidationRules::default(),
+        }
+    }
+}
+
+impl ApiConfig {
+    /// Charge la configuration depuis les variables d'environnement
+    /// (valeurs par défaut pour les variables absentes ou invalides).
+    ///
+    /// Un fichier `API_KEYS_FILE` ou `TENANT_QUOTAS_FILE` illisible ou invalide,
+    /// ou un `JOB_KIND_PATTERN` invalide, est une erreur : le serveur ne démarre
+    /// pas sans la protection demandée.
+    pub fn from_env() -> Result<Self> {
+        let mut config = Self::default();
+
+        if let Some(ttl) = env_parse("IDEMPOTENCY_TTL_SECS") {
+            config.idempotency_ttl_secs = ttl;
+        }
+
+        if let Ok(path) = std::env::var("API_KEYS_FILE") {
+            config.api_keys = load_api_keys(&path)?;
+        }
+
+        if let Ok(path) = std::env::var("TENANT_QUOTAS_FILE") {
+            config.tenant_quotas = load_tenant_quotas(&path)?;
+        }
+
+        if let Ok(pattern) = std::env::var("JOB_KIND_PATTERN") {
+            config.validation = config.validation.with_kind_pattern(&pattern)?;
+        }
+        let rules = &mut config.validation;
+        if let Some(bytes) = env_parse("MAX_PAYLOAD_BYTES") {
+            rules.max_payload_bytes = bytes;
+        }
+        if let Some(timeout_ms) = env_parse("MIN_TIMEOUT_MS") {
+            rules.min_timeout_ms = timeout_ms;
+        }
+        if let Some(timeout_ms) = env_parse("MAX_TIMEOUT_MS") {
+            rules.max_timeout_ms = timeout_ms;
+        }
+        if let Some(retries) = env_parse("MAX_RETRIES") {
+            rules.max_retries = retries;
+        }
+        if let Some(entries) = env_parse("MAX_METADATA_ENTRIES") {
+            rules.max_metadata_entries = entries;
+        }
+        rules.callbacks_enabled = std::env::var("WEBHOOK_SECRET").is_ok_and(|secret| !secret.is_empty());
+
+        Ok(config)
+    }
+}
+
+/// Lit un fichier JSON contenant la liste des clés d'API
+pub fn load_api_keys(path: &str) -> Result<Vec<ApiKey>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| IronForgeError::Config(format!("cannot read {}: {}", path, e)))?;
+    let keys: Vec<ApiKey> = serde_json::from_str(&content)
+        .map_err(|e| IronForgeError::Config(format!("invalid API keys file {}: {}", path, e)))?;
+
+    for key in &keys {
+        key.validate().map_err(IronForgeError::Config)?;
+    }
+
+    Ok(keys)
+}
+
+/// Lit un fichier JSON associant à chaque tenant ses quotas
+pub fn load_tenant_quotas(path: &str) -> Result<HashMap<String, TenantQuota>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| IronForgeError::Config(format!("cannot read {}: {}", path, e)))?;
+    let quotas: HashMap<String, TenantQuota> = serde_json::from_str(&content)
+        .map_err(|e| IronForgeError::Config(format!("invalid tenant quotas file {}: {}", path, e)))?;
+
+    for tenant in quotas.keys() {
+        validate_tenant(tenant).map_err(IronForgeError::Config)?;
+    }
+
+    Ok(quotas)
+}
+
+fn env_parse<T: std::str::FromStr>(name: &str) -> Option<T> {
+    std::env::var(name).ok().and_then(|value| value.parse().ok())
+}
+
+/// État partagé par les handlers
+pub struct AppState<Q: QueueBackend> {
+    pub queue: Arc<Q>,
+    pub config: Arc<ApiConfig>,
+}
+
+impl<Q: QueueBackend> Clone for AppState<Q> {
+    fn clone(&self) -> Self {
+        Self {
+            queue: self.queue.clone(),
+            config: self.config.clone(),
+        }
+    }
+}
+
+impl<Q: QueueBackend> FromRef<AppState<Q>> for Arc<Q> {
+    fn from_ref(state: &AppState<Q>) -> Self {
+        state.queue.clone()
+    }
+}
+
+impl<Q: QueueBackend> FromRef<AppState<Q>> for Arc<ApiConfig> {
+    fn from_ref(state: &AppState<Q>) -> Self {
+        state.config.clone()
+    }
+}
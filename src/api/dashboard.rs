@@ -0,0 +1,66 @@
+use axum::{
+    http::{header, HeaderValue},
+    response::{IntoResponse, Redirect, Response},
+    routing::get,
+    Router,
+};
+
+/// Chemin du tableau de bord web embarqué
+pub const DASHBOARD_PATH: &str = "/dashboard";
+
+/// Fichiers du tableau de bord, compilés dans le binaire
+const INDEX_HTML: &str = include_str!("../../assets/dashboard/index.html");
+const APP_JS: &str = include_str!("../../assets/dashboard/app.js");
+const STYLE_CSS: &str = include_str!("../../assets/dashboard/style.css");
+
+/// Routes du tableau de bord (sans authentification : les pages sont statiques,
+/// et le navigateur présente la clé d'API à chaque appel de `/v1`)
+pub fn dashboard_router<S: Clone + Send + Sync + 'static>() -> Router<S> {
+    Router::new()
+        .route(DASHBOARD_PATH, get(|| async { Redirect::permanent("/dashboard/") }))
+        .route("/dashboard/", get(|| async { asset("text/html; charset=utf-8", INDEX_HTML) }))
+        .route("/dashboard/app.js", get(|| async { asset("text/javascript; charset=utf-8", APP_JS) }))
+        .route("/dashboard/style.css", get(|| async { asset("text/css; charset=utf-8", STYLE_CSS) }))
+}
+
+fn asset(content_type: &'static str, body: &'static str) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, HeaderValue::from_static(content_type)),
+            // Les assets changent avec le binaire : revalider à chaque chargement
+            (header::CACHE_CONTROL, HeaderValue::from_static("no-cache")),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, extract::Request, http::StatusCode};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_dashboard_serves_embedded_assets() {
+        let app: Router = dashboard_router();
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let redirect = app.clone().oneshot(request(DASHBOARD_PATH)).await.unwrap();
+        assert_eq!(redirect.status(), StatusCode::PERMANENT_REDIRECT);
+
+        for (uri, content_type) in [
+            ("/dashboard/", "text/html; charset=utf-8"),
+            ("/dashboard/app.js", "text/javascript; charset=utf-8"),
+            ("/dashboard/style.css", "text/css; charset=utf-8"),
+        ] {
+            let response = app.clone().oneshot(request(uri)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK, "{}", uri);
+            assert_eq!(response.headers()[header::CONTENT_TYPE], content_type);
+        }
+
+        // Le tableau de bord n'utilise que l'API versionnée
+        assert!(INDEX_HTML.contains("app.js") && INDEX_HTML.contains("style.css"));
+        assert!(APP_JS.contains("'/v1'"));
+    }
+}
@@ -0,0 +1,300 @@
+use axum::{
+    async_trait,
+    extract::{
+        rejection::{JsonRejection, PathRejection},
+        FromRequest, FromRequestParts, Path, Request,
+    },
+    http::{header, request::Parts, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::{FieldViolation, IronForgeError};
+
+/// Type de contenu des erreurs (RFC 7807)
+pub const PROBLEM_CONTENT_TYPE: &str = "application/problem+json";
+
+/// Code d'erreur stable, sur lequel les clients peuvent s'appuyer
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// Corps de requête illisible (JSON invalide, champ manquant ou mal typé)
+    InvalidBody,
+    /// Paramètre de chemin invalide (ex: UUID mal formé)
+    InvalidPath,
+    InvalidQuery,
+    /// Requête bien formée mais refusée par les règles de validation
+    ValidationFailed,
+    InvalidSchedule,
+    DependencyNotFound,
+    IdempotencyKeyConflict,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    JobNotFound,
+    JobAlreadyExists,
+    /// L'état actuel du job ne permet pas l'opération
+    InvalidJobState,
+    InvalidStatusTransition,
+    /// Le job a été modifié entre sa lecture et son écriture
+    VersionConflict,
+    /// Une soumission équivalente est encore en cours
+    SubmissionInProgress,
+    Conflict,
+    QuotaExceeded,
+    BackendUnavailable,
+    Timeout,
+    InternalError,
+}
+
+impl ErrorCode {
+    /// Code par défaut d'un statut HTTP
+    pub fn for_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::BAD_REQUEST => ErrorCode::InvalidQuery,
+            StatusCode::UNAUTHORIZED => ErrorCode::Unauthorized,
+            StatusCode::FORBIDDEN => ErrorCode::Forbidden,
+            StatusCode::NOT_FOUND => ErrorCode::NotFound,
+            StatusCode::CONFLICT => ErrorCode::Conflict,
+            StatusCode::UNPROCESSABLE_ENTITY => ErrorCode::ValidationFailed,
+            StatusCode::TOO_MANY_REQUESTS => ErrorCode::QuotaExceeded,
+            StatusCode::SERVICE_UNAVAILABLE => ErrorCode::BackendUnavailable,
+            StatusCode::GATEWAY_TIMEOUT => ErrorCode::Timeout,
+            _ => ErrorCode::InternalError,
+        }
+    }
+
+    pub fn as_str(&self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+/// Erreur de l'API, sérialisée au format RFC 7807 (`application/problem+json`)
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ApiError {
+    /// URI du type d'erreur (`urn:ironforge:error:<code>`)
+    #[serde(rename = "type")]
+    pub type_uri: String,
+
+    pub title: String,
+
+    #[serde(serialize_with = "serialize_status")]
+    #[schema(value_type = u16)]
+    pub status: StatusCode,
+
+    pub code: ErrorCode,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    /// Règles de validation non respectées, champ par champ
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldViolation>,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, title: impl Into<String>) -> Self {
+        let code = ErrorCode::for_status(status);
+        Self {
+            type_uri: type_uri(code),
+            title: title.into(),
+            status,
+            code,
+            detail: None,
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn with_details(status: StatusCode, title: impl Into<String>, detail: impl Into<String>) -> Self {
+        Self {
+            detail: Some(detail.into()),
+            ..Self::new(status, title)
+        }
+    }
+
+    /// Erreur dont le statut et le code découlent de l'erreur interne
+    pub fn from_error(title: impl Into<String>, error: IronForgeError) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::from(error)
+        }
+    }
+
+    /// Requête refusée par les règles de validation
+    pub fn validation(errors: Vec<FieldViolation>) -> Self {
+        Self {
+            errors,
+            ..Self::new(StatusCode::UNPROCESSABLE_ENTITY, "Validation failed")
+        }
+    }
+
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        self.code = code;
+        self.type_uri = type_uri(code);
+        self
+    }
+}
+
+impl From<IronForgeError> for ApiError {
+    fn from(error: IronForgeError) -> Self {
+        let (status, code, title) = match &error {
+            IronForgeError::Redis(_) | IronForgeError::QueueBackend(_) => (
+                StatusCode::SERVICE_UNAVAILABLE,
+                ErrorCode::BackendUnavailable,
+                "Queue backend unavailable",
+            ),
+            IronForgeError::JobNotFound(_) => (StatusCode::NOT_FOUND, ErrorCode::JobNotFound, "Job not found"),
+            IronForgeError::JobAlreadyExists(_) => {
+                (StatusCode::CONFLICT, ErrorCode::JobAlreadyExists, "Job already exists")
+            }
+            IronForgeError::InvalidStatusTransition { .. } => (
+                StatusCode::CONFLICT,
+                ErrorCode::InvalidStatusTransition,
+                "Invalid job status transition",
+            ),
+            IronForgeError::InvalidJobState(_) => {
+                (StatusCode::CONFLICT, ErrorCode::InvalidJobState, "Invalid job state")
+            }
+            IronForgeError::VersionConflict { .. } => (
+                StatusCode::CONFLICT,
+                ErrorCode::VersionConflict,
+                "Job was modified concurrently",
+            ),
+            IronForgeError::Timeout => (StatusCode::GATEWAY_TIMEOUT, ErrorCode::Timeout, "Timeout exceeded"),
+            IronForgeError::InvalidSchedule(_) => {
+                (StatusCode::UNPROCESSABLE_ENTITY, ErrorCode::InvalidSchedule, "Invalid schedule")
+            }
+            IronForgeError::InvalidQuery(_) => {
+                (StatusCode::BAD_REQUEST, ErrorCode::InvalidQuery, "Invalid query parameters")
+            }
+            IronForgeError::IdempotencyKeyConflict(_) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ErrorCode::IdempotencyKeyConflict,
+                "Idempotency key already used with a different payload",
+            ),
+            IronForgeError::QuotaExceeded(_) => {
+                (StatusCode::TOO_MANY_REQUESTS, ErrorCode::QuotaExceeded, "Quota exceeded")
+            }
+            IronForgeError::Serialization(_)
+            | IronForgeError::Worker(_)
+            | IronForgeError::PermanentFailure(_)
+            | IronForgeError::Config(_)
+            | IronForgeError::LeadershipLost(_) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, ErrorCode::InternalError, "Internal error")
+            }
+        };
+
+        Self::with_details(status, title, error.to_string()).with_code(code)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (
+            self.status,
+            [(header::CONTENT_TYPE, PROBLEM_CONTENT_TYPE)],
+            Json(self),
+        )
+            .into_response()
+    }
+}
+
+fn type_uri(code: ErrorCode) -> String {
+    format!("urn:ironforge:error:{}", code.as_str())
+}
+
+fn serialize_status<S: serde::Serializer>(status: &StatusCode, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u16(status.as_u16())
+}
+
+/// Extracteur JSON dont les rejets sont des `ApiError`
+pub struct ApiJson<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequest<S> for ApiJson<T>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        match Json::<T>::from_request(req, state).await {
+            Ok(Json(value)) => Ok(ApiJson(value)),
+            Err(rejection) => Err(body_error(rejection)),
+        }
+    }
+}
+
+fn body_error(rejection: JsonRejection) -> ApiError {
+    ApiError::with_details(rejection.status(), "Invalid request body", rejection.body_text())
+        .with_code(ErrorCode::InvalidBody)
+}
+
+/// Extracteur de paramètres de chemin dont les rejets sont des `ApiError`
+pub struct ApiPath<T>(pub T);
+
+#[async_trait]
+impl<S, T> FromRequestParts<S> for ApiPath<T>
+where
+    T: DeserializeOwned + Send,
+    S: Send + Sync,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        match Path::<T>::from_request_parts(parts, state).await {
+            Ok(Path(value)) => Ok(ApiPath(value)),
+            Err(rejection) => Err(path_error(rejection)),
+        }
+    }
+}
+
+fn path_error(rejection: PathRejection) -> ApiError {
+    ApiError::with_details(rejection.status(), "Invalid path parameter", rejection.body_text())
+        .with_code(ErrorCode::InvalidPath)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_problem_body_from_error() {
+        let error = ApiError::from(IronForgeError::JobNotFound("42".to_string()));
+        assert_eq!(error.status, StatusCode::NOT_FOUND);
+
+        let body = serde_json::to_value(&error).unwrap();
+        assert_eq!(body["type"], "urn:ironforge:error:job_not_found");
+        assert_eq!(body["code"], "job_not_found");
+        assert_eq!(body["status"], 404);
+        assert_eq!(body["detail"], "Job not found: 42");
+        assert!(body.get("errors").is_none());
+
+        let error = ApiError::from_error(
+            "Failed to list jobs",
+            IronForgeError::QueueBackend("connection refused".to_string()),
+        );
+        assert_eq!(error.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(error.code, ErrorCode::BackendUnavailable);
+        assert_eq!(error.title, "Failed to list jobs");
+
+        let error = ApiError::from(IronForgeError::VersionConflict {
+            job_id: "42".to_string(),
+            expected: 3,
+            actual: 4,
+        });
+        assert_eq!(error.status, StatusCode::CONFLICT);
+        assert_eq!(error.code, ErrorCode::VersionConflict);
+
+        let error = ApiError::validation(vec![FieldViolation::new("kind", "must match the kind pattern")]);
+        let body = serde_json::to_value(&error).unwrap();
+        assert_eq!(body["code"], "validation_failed");
+        assert_eq!(body["errors"][0]["field"], "kind");
+    }
+}
@@ -1,42 +1,45 @@
 use axum::{
-    extract::{Path, State},
-    http::StatusCode,
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::sse::{Event, KeepAlive, Sse},
     Json,
 };
+use futures::stream::{self, BoxStream, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use std::collections::HashMap;
 use std::sync::Arc;
 use uuid::Uuid;
+use utoipa::ToSchema;
 
+use super::{
+    auth::Caller,
+    config::ApiConfig,
+    error::{ApiError, ApiJson, ApiPath, ErrorCode},
+};
 use crate::{
-    models::{CreateJobRequest, CreateJobResponse, Job, JobStatus},
-    queue::QueueBackend,
+    models::{
+        CreateJobRequest, CreateJobResponse, CreateWorkflowRequest, EventFilter, FieldViolation,
+        IronForgeError, Job, JobCursor, JobEvent, JobFilter, JobPage, JobStatus, Schedule,
+        ScheduleRequest, UniquePolicy, UpdateJobRequest, ValidationRules, WorkerInfo, WorkflowKind, WorkflowStatus,
+        DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT,
+    },
+    queue::{PauseTarget, QueueBackend},
 };
 
-/// Response pour les erreurs
-#[derive(Debug, Serialize)]
-pub struct ErrorResponse {
-    pub error: String,
-    pub details: Option<String>,
-}
-
-impl ErrorResponse {
-    fn new(error: impl Into<String>) -> Self {
-        Self {
-            error: error.into(),
-            details: None,
-        }
-    }
-
-    fn with_details(error: impl Into<String>, details: impl Into<String>) -> Self {
-        Self {
-            error: error.into(),
-            details: Some(details.into()),
-        }
-    }
-}
+/// Header portant la clé d'idempotence de `POST /jobs`
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
 
 /// Health check endpoint
+#[utoipa::path(
+    get,
+    path = "/health",
+    tag = "health",
+    responses(
+        (status = 200, description = "Service is up", body = Object),
+    ),
+    security(()),
+)]
 pub async fn health() -> (StatusCode, Json<Value>) {
     (
         StatusCode::OK,
@@ -49,43 +52,127 @@ pub async fn health() -> (StatusCode, Json<Value>) {
 }
 
 /// Créer un nouveau job
+#[utoipa::path(
+    post,
+    path = "/jobs",
+    tag = "jobs",
+    request_body = CreateJobRequest,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Makes retries of this request safe"),
+    ),
+    responses(
+        (status = 201, description = "Job created", body = CreateJobResponse),
+        (status = 200, description = "Idempotent replay or duplicate of a unique job", body = CreateJobResponse),
+        (status = 403, description = "Job outside the API key scope", body = ApiError, content_type = "application/problem+json"),
+        (status = 409, description = "Matching submission still in progress", body = ApiError, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid job", body = ApiError, content_type = "application/problem+json"),
+        (status = 429, description = "Tenant quota exceeded", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
 pub async fn create_job<Q: QueueBackend>(
     State(queue): State<Arc<Q>>,
-    Json(req): Json<CreateJobRequest>,
-) -> Result<(StatusCode, Json<CreateJobResponse>), (StatusCode, Json<ErrorResponse>)> {
-    // Créer le job de base
-    let mut job = Job::new(req.kind.clone(), req.payload.clone());
+    State(config): State<Arc<ApiConfig>>,
+    caller: Caller,
+    headers: HeaderMap,
+    ApiJson(req): ApiJson<CreateJobRequest>,
+) -> Result<(StatusCode, Json<CreateJobResponse>), ApiError> {
+    validate_job(&config.validation, &req)?;
+
+    // Le header est prioritaire sur le champ du payload ; chaque tenant a ses clés
+    let idempotency_key = headers
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+        .or_else(|| req.idempotency_key.clone())
+        .filter(|key| !key.is_empty())
+        .map(|key| match caller.tenant() {
+            Some(tenant) => format!("{}/{}", tenant, key),
+            None => key,
+        });
 
-    // Appliquer les paramètres optionnels
-    if let Some(priority) = req.priority {
-        job.priority = priority;
+    let fingerprint = req.fingerprint();
+    let mut job = req.into_job();
+
+    job.submitted_by = caller.name();
+    job.assign_tenant(caller.tenant());
+    if !caller.allows_job(&job) {
+        return Err(forbidden_job(&job));
+    }
+
+    if let Some(key) = &idempotency_key {
+        match queue
+            .claim_idempotency_key(key, &fingerprint, job.id, config.idempotency_ttl_secs)
+            .await
+        {
+            Ok(()) => {}
+            Err(IronForgeError::JobAlreadyExists(existing_id)) => {
+                return existing_job_response(queue.as_ref(), &existing_id).await;
+            }
+            Err(IronForgeError::IdempotencyKeyConflict(_)) => {
+                return Err(ApiError::with_details(
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    "Idempotency key already used with a different payload",
+                    key.clone(),
+                )
+                .with_code(ErrorCode::IdempotencyKeyConflict));
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to claim idempotency key");
+                return Err(ApiError::from_error("Failed to claim idempotency key", e));
+            }
+        }
     }
 
-    if let Some(max_retries) = req.max_retries {
-        job.max_retries = max_retries;
+    let release_idempotency_key = || async {
+        // Libère la clé pour que le client puisse réessayer
+        if let Some(key) = &idempotency_key {
+            if let Err(e) = queue.release_idempotency_key(key).await {
+                tracing::warn!(error = %e, "Failed to release idempotency key");
+            }
+        }
+    };
+
+    // Un job unique équivalent existe déjà : la soumission est ignorée ou fusionnée
+    let existing = match queue.claim_unique(&job).await {
+        Ok(existing) => existing,
+        Err(e) => {
+            release_idempotency_key().await;
+            tracing::error!(error = %e, "Failed to claim unique lock");
+            return Err(ApiError::from_error("Failed to claim unique lock", e));
+        }
+    };
+
+    if let Some(existing_id) = existing {
+        release_idempotency_key().await;
+        return duplicate_job_response(queue.as_ref(), existing_id, &job).await;
     }
 
-    if let Some(timeout_ms) = req.timeout_ms {
-        job.timeout_ms = timeout_ms;
+    if let Err(e) = reserve_quota(queue.as_ref(), &config, &caller, 1).await {
+        release_idempotency_key().await;
+        release_unique_locks(queue.as_ref(), &[&job]).await;
+        return Err(e);
     }
 
-    // Ajouter les métadonnées
-    job.metadata = req.metadata;
+    // Enqueue le job (ou le met en attente de ses dépendances)
+    let enqueued = queue.enqueue_with_dependencies(&job).await;
 
-    // Enqueue le job
-    queue
-        .enqueue(&job)
-        .await
-        .map_err(|e| {
+    if enqueued.is_err() {
+        release_idempotency_key().await;
+        release_unique_locks(queue.as_ref(), &[&job]).await;
+        release_quota(queue.as_ref(), &config, &caller, 1).await;
+    }
+
+    job.status = enqueued.map_err(|e| match e {
+        IronForgeError::JobNotFound(parent_id) => ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("Dependency {} not found", parent_id),
+        )
+        .with_code(ErrorCode::DependencyNotFound),
+        e => {
             tracing::error!(error = %e, "Failed to enqueue job");
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::with_details(
-                    "Failed to enqueue job",
-                    e.to_string(),
-                )),
-            )
-        })?;
+            ApiError::from_error("Failed to enqueue job", e)
+        }
+    })?;
 
     tracing::info!(
         job_id = %job.id,
@@ -100,161 +187,1079 @@ pub async fn create_job<Q: QueueBackend>(
             id: job.id,
             status: job.status,
             created_at: job.created_at,
+            deduplicated: None,
+        }),
+    ))
+}
+
+/// Applique la politique d'unicité d'un doublon et retourne le job existant
+/// (`None` si ce dernier est encore en cours de soumission)
+async fn deduplicate<Q: QueueBackend>(
+    queue: &Q,
+    existing_id: Uuid,
+    duplicate: &Job,
+) -> crate::models::Result<Option<Job>> {
+    match duplicate.unique.as_ref().map(|unique| unique.on_conflict) {
+        Some(UniquePolicy::Merge) => queue.merge_unique(existing_id, duplicate).await,
+        _ => queue.get_job(existing_id).await,
+    }
+}
+
+/// Réponse à la soumission d'un doublon : le job unique existant (200)
+async fn duplicate_job_response<Q: QueueBackend>(
+    queue: &Q,
+    existing_id: Uuid,
+    duplicate: &Job,
+) -> Result<(StatusCode, Json<CreateJobResponse>), ApiError> {
+    let existing = deduplicate(queue, existing_id, duplicate).await.map_err(|e| {
+        tracing::error!(error = %e, job_id = %existing_id, "Failed to deduplicate job");
+        ApiError::from_error("Failed to deduplicate job", e)
+    })?;
+
+    let existing = existing.ok_or_else(|| {
+        ApiError::new(
+            StatusCode::CONFLICT,
+            format!("Matching unique job {} is still being submitted", existing_id),
+        )
+        .with_code(ErrorCode::SubmissionInProgress)
+    })?;
+
+    let policy = duplicate.unique.as_ref().map(|unique| unique.on_conflict);
+
+    tracing::info!(
+        job_id = %existing.id,
+        kind = %existing.kind,
+        policy = ?policy,
+        "Duplicate submission of unique job"
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(CreateJobResponse {
+            id: existing.id,
+            status: existing.status,
+            created_at: existing.created_at,
+            deduplicated: policy,
+        }),
+    ))
+}
+
+/// Réponse à une requête rejouée : le job créé par la requête d'origine
+async fn existing_job_response<Q: QueueBackend>(
+    queue: &Q,
+    job_id: &str,
+) -> Result<(StatusCode, Json<CreateJobResponse>), ApiError> {
+    let job = match Uuid::parse_str(job_id) {
+        Ok(job_id) => queue.get_job(job_id).await.map_err(|e| {
+            ApiError::from_error("Failed to get job", e)
+        })?,
+        Err(_) => None,
+    };
+
+    // La requête d'origine n'a pas encore enregistré son job
+    let job = job.ok_or_else(|| {
+        ApiError::new(
+            StatusCode::CONFLICT,
+            "A request with this idempotency key is still in progress",
+        )
+        .with_code(ErrorCode::SubmissionInProgress)
+    })?;
+
+    tracing::info!(job_id = %job.id, "Idempotent replay, returning existing job");
+
+    Ok((
+        StatusCode::OK,
+        Json(CreateJobResponse {
+            id: job.id,
+            status: job.status,
+            created_at: job.created_at,
+            deduplicated: None,
         }),
     ))
 }
 
+/// Réserve la soumission de `count` jobs dans les quotas du tenant de l'appelant
+async fn reserve_quota<Q: QueueBackend>(
+    queue: &Q,
+    config: &ApiConfig,
+    caller: &Caller,
+    count: usize,
+) -> Result<(), ApiError> {
+    let Some(tenant) = caller.tenant() else {
+        return Ok(());
+    };
+    let Some(quota) = config.tenant_quotas.get(&tenant) else {
+        return Ok(());
+    };
+
+    match queue.reserve_submissions(&tenant, quota, count as u64).await {
+        Ok(()) => Ok(()),
+        Err(IronForgeError::QuotaExceeded(reason)) => {
+            tracing::warn!(tenant = %tenant, reason = %reason, "Tenant quota exceeded");
+            Err(ApiError::with_details(
+                StatusCode::TOO_MANY_REQUESTS,
+                "Tenant quota exceeded",
+                reason,
+            ))
+        }
+        Err(e) => {
+            tracing::error!(error = %e, "Failed to check tenant quota");
+            Err(ApiError::from_error("Failed to check tenant quota", e))
+        }
+    }
+}
+
+/// Rend les soumissions réservées pour des jobs finalement non enqueue
+async fn release_quota<Q: QueueBackend>(queue: &Q, config: &ApiConfig, caller: &Caller, count: usize) {
+    let Some(tenant) = caller.tenant() else {
+        return;
+    };
+    let Some(quota) = config.tenant_quotas.get(&tenant) else {
+        return;
+    };
+
+    if let Err(e) = queue.release_submissions(&tenant, quota, count as u64).await {
+        tracing::warn!(error = %e, tenant = %tenant, "Failed to release tenant quota");
+    }
+}
+
+/// Libère les verrous d'unicité de jobs finalement non soumis
+async fn release_unique_locks<Q: QueueBackend>(queue: &Q, jobs: &[&Job]) {
+    for job in jobs {
+        if let Err(e) = queue.release_unique(job).await {
+            tracing::warn!(error = %e, job_id = %job.id, "Failed to release unique lock");
+        }
+    }
+}
+
+/// Message de refus d'un job hors du périmètre de la clé d'API
+fn not_allowed(job: &Job) -> String {
+    format!(
+        "API key is not allowed to submit {} jobs on queue {}",
+        job.kind, job.queue
+    )
+}
+
+/// Contrôles intrinsèques du job, puis règles de validation configurées
+fn validate_job(rules: &ValidationRules, req: &CreateJobRequest) -> Result<(), ApiError> {
+    req.validate()
+        .map_err(|e| ApiError::with_details(StatusCode::UNPROCESSABLE_ENTITY, "Invalid job", e))?;
+
+    let violations = rules.check(req);
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ApiError::validation(violations))
+    }
+}
+
+fn job_not_found(job_id: Uuid) -> ApiError {
+    ApiError::new(StatusCode::NOT_FOUND, format!("Job {} not found", job_id))
+        .with_code(ErrorCode::JobNotFound)
+}
+
+/// Réponse 403 pour un job hors du périmètre de la clé d'API
+fn forbidden_job(job: &Job) -> ApiError {
+    ApiError::new(StatusCode::FORBIDDEN, not_allowed(job))
+}
+
 /// Récupérer un job par son ID
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}",
+    tag = "jobs",
+    params(("id" = Uuid, Path, description = "Job id")),
+    responses(
+        (status = 200, description = "Job", body = Job),
+        (status = 404, description = "Job not found", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
 pub async fn get_job<Q: QueueBackend>(
     State(queue): State<Arc<Q>>,
-    Path(job_id): Path<Uuid>,
-) -> Result<Json<Job>, (StatusCode, Json<ErrorResponse>)> {
+    caller: Caller,
+    ApiPath(job_id): ApiPath<Uuid>,
+) -> Result<Json<Job>, ApiError> {
+    // Un job hors du périmètre de la clé est présenté comme inexistant
     let job = queue
         .get_job(job_id)
         .await
         .map_err(|e| {
             tracing::error!(error = %e, job_id = %job_id, "Failed to fetch job");
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::with_details(
-                    "Failed to fetch job",
-                    e.to_string(),
-                )),
-            )
+            ApiError::from_error("Failed to fetch job", e)
         })?
+        .filter(|job| caller.allows_job(job))
         .ok_or_else(|| {
             tracing::warn!(job_id = %job_id, "Job not found");
-            (
-                StatusCode::NOT_FOUND,
-                Json(ErrorResponse::new(format!("Job {} not found", job_id))),
-            )
+            job_not_found(job_id)
         })?;
 
     Ok(Json(job))
 }
 
-/// Supprimer un job (seulement si en état Queued)
-pub async fn delete_job<Q: QueueBackend>(
+/// Modifier un job en attente (priorité, date d'exécution, timeout, métadonnées)
+#[utoipa::path(
+    patch,
+    path = "/jobs/{id}",
+    tag = "jobs",
+    request_body = UpdateJobRequest,
+    params(("id" = Uuid, Path, description = "Job id")),
+    responses(
+        (status = 200, description = "Updated job", body = Job),
+        (status = 404, description = "Job not found", body = ApiError, content_type = "application/problem+json"),
+        (status = 409, description = "Job is neither queued nor waiting", body = ApiError, content_type = "application/problem+json"),
+        (status = 422, description = "Validation failed", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn patch_job<Q: QueueBackend>(
     State(queue): State<Arc<Q>>,
-    Path(job_id): Path<Uuid>,
-) -> Result<StatusCode, (StatusCode, Json<ErrorResponse>)> {
-    // Vérifier que le job existe et est en état Queued
+    State(config): State<Arc<ApiConfig>>,
+    caller: Caller,
+    ApiPath(job_id): ApiPath<Uuid>,
+    ApiJson(req): ApiJson<UpdateJobRequest>,
+) -> Result<Json<Job>, ApiError> {
     let job = queue
         .get_job(job_id)
         .await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::with_details("Failed to fetch job", e.to_string())),
-            )
+            ApiError::from_error("Failed to fetch job", e)
         })?
-        .ok_or((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(format!("Job {} not found", job_id))),
-        ))?;
+        .filter(|job| caller.allows_job(job))
+        .ok_or_else(|| job_not_found(job_id))?;
 
-    if job.status != JobStatus::Queued {
-        return Err((
-            StatusCode::CONFLICT,
-            Json(ErrorResponse::new(format!(
-                "Cannot delete job in status {:?}",
-                job.status
-            ))),
+    let violations = config.validation.check_update(&req, &job);
+    if !violations.is_empty() {
+        return Err(ApiError::validation(violations));
+    }
+
+    // Réécrit le job et recalcule sa place dans la queue (ou parmi les jobs
+    // différés) dans la même écriture conditionnelle
+    let job = queue
+        .modify_job(job_id, None, |job| req.apply(job))
+        .await
+        .map_err(|e| match e {
+            IronForgeError::InvalidJobState(status) => ApiError::new(
+                StatusCode::CONFLICT,
+                format!("Cannot modify job in status {:?}", status),
+            )
+            .with_code(ErrorCode::InvalidJobState),
+            IronForgeError::JobNotFound(_) => job_not_found(job_id),
+            e => ApiError::from_error("Failed to update job", e),
+        })?;
+
+    tracing::info!(job_id = %job_id, version = job.version, "Job updated");
+    Ok(Json(job))
+}
+
+/// Nombre maximum de jobs par lot
+pub const MAX_BATCH_SIZE: usize = 1000;
+
+/// Résultat de la soumission d'un élément d'un lot
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "outcome", rename_all = "lowercase")]
+pub enum BatchItemResult {
+    Accepted {
+        index: usize,
+        id: Uuid,
+        status: JobStatus,
+    },
+    /// Doublon d'un job unique : `id` est celui du job existant
+    Deduplicated {
+        index: usize,
+        id: Uuid,
+        status: JobStatus,
+        policy: UniquePolicy,
+    },
+    Rejected {
+        index: usize,
+        code: ErrorCode,
+        error: String,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        errors: Vec<FieldViolation>,
+    },
+}
+
+impl BatchItemResult {
+    fn rejected(index: usize, error: ApiError) -> Self {
+        BatchItemResult::Rejected {
+            index,
+            code: error.code,
+            error: error.detail.unwrap_or(error.title),
+            errors: error.errors,
+        }
+    }
+}
+
+/// Réponse de soumission d'un lot
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateBatchResponse {
+    pub batch_id: Uuid,
+    pub accepted: usize,
+    pub rejected: usize,
+    pub results: Vec<BatchItemResult>,
+}
+
+/// Soumettre un lot de jobs.
+///
+/// Chaque élément est validé séparément : les éléments invalides sont rejetés
+/// et les autres enqueue. Les jobs sans dépendances sont écrits en un seul
+/// pipeline atomique. Réponse 201 si tout est accepté, 207 si le lot est
+/// partiellement accepté, 422 si tout est rejeté.
+#[utoipa::path(
+    post,
+    path = "/jobs/batch",
+    tag = "jobs",
+    request_body = Vec<CreateJobRequest>,
+    responses(
+        (status = 201, description = "Every job accepted", body = CreateBatchResponse),
+        (status = 207, description = "Some jobs rejected", body = CreateBatchResponse),
+        (status = 422, description = "Every job rejected", body = CreateBatchResponse),
+        (status = 429, description = "Tenant quota exceeded", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn create_batch<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    State(config): State<Arc<ApiConfig>>,
+    caller: Caller,
+    ApiJson(items): ApiJson<Vec<Value>>,
+) -> Result<(StatusCode, Json<CreateBatchResponse>), ApiError> {
+    if items.is_empty() || items.len() > MAX_BATCH_SIZE {
+        return Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("Batch must contain between 1 and {} jobs", MAX_BATCH_SIZE),
         ));
     }
 
+    let batch_id = Uuid::new_v4();
+    let mut results = Vec::with_capacity(items.len());
+    let mut accepted_jobs: Vec<(usize, Job)> = Vec::new();
+    // Doublons d'un job unique du même lot : (index, position du job retenu, politique)
+    let mut batch_duplicates = Vec::new();
+    let mut unique_positions: HashMap<String, usize> = HashMap::new();
+
+    for (index, item) in items.into_iter().enumerate() {
+        let req = serde_json::from_value::<CreateJobRequest>(item)
+            .map_err(|e| {
+                ApiError::with_details(StatusCode::UNPROCESSABLE_ENTITY, "Invalid job", e.to_string())
+                    .with_code(ErrorCode::InvalidBody)
+            })
+            .and_then(|req| validate_job(&config.validation, &req).map(|()| req));
+
+        let mut job = match req {
+            Ok(req) => req.into_job(),
+            Err(error) => {
+                results.push(BatchItemResult::rejected(index, error));
+                continue;
+            }
+        };
+
+        job.batch_id = Some(batch_id);
+        job.submitted_by = caller.name();
+        job.assign_tenant(caller.tenant());
+        if !caller.allows_job(&job) {
+            results.push(BatchItemResult::rejected(index, forbidden_job(&job)));
+            continue;
+        }
+
+        if let Some(unique) = job.unique.clone() {
+            if let Some(&position) = unique_positions.get(&unique.key) {
+                if unique.on_conflict == UniquePolicy::Merge {
+                    accepted_jobs[position].1.merge_duplicate(&job);
+                }
+                batch_duplicates.push((index, position, unique.on_conflict));
+                continue;
+            }
+
+            match queue.claim_unique(&job).await {
+                Ok(None) => {}
+                Ok(Some(existing_id)) => {
+                    results.push(match deduplicate(queue.as_ref(), existing_id, &job).await {
+                        Ok(Some(existing)) => BatchItemResult::Deduplicated {
+                            index,
+                            id: existing.id,
+                            status: existing.status,
+                            policy: unique.on_conflict,
+                        },
+                        Ok(None) => BatchItemResult::rejected(
+                            index,
+                            ApiError::new(
+                                StatusCode::CONFLICT,
+                                format!("Matching unique job {} is still being submitted", existing_id),
+                            )
+                            .with_code(ErrorCode::SubmissionInProgress),
+                        ),
+                        Err(e) => BatchItemResult::rejected(index, e.into()),
+                    });
+                    continue;
+                }
+                Err(e) => {
+                    results.push(BatchItemResult::rejected(index, e.into()));
+                    continue;
+                }
+            }
+
+            unique_positions.insert(unique.key, accepted_jobs.len());
+        }
+
+        accepted_jobs.push((index, job));
+    }
+
+    if !accepted_jobs.is_empty() {
+        if let Err(e) = reserve_quota(queue.as_ref(), &config, &caller, accepted_jobs.len()).await {
+            let jobs: Vec<&Job> = accepted_jobs.iter().map(|(_, job)| job).collect();
+            release_unique_locks(queue.as_ref(), &jobs).await;
+            return Err(e);
+        }
+    }
+
+    // Les jobs sans dépendances sont enqueue ensemble, ou pas du tout
+    let jobs: Vec<Job> = accepted_jobs
+        .iter()
+        .filter(|(_, job)| job.depends_on.is_empty())
+        .map(|(_, job)| job.clone())
+        .collect();
+    if let Err(e) = queue.enqueue_batch(&jobs).await {
+        let jobs: Vec<&Job> = accepted_jobs.iter().map(|(_, job)| job).collect();
+        release_unique_locks(queue.as_ref(), &jobs).await;
+        release_quota(queue.as_ref(), &config, &caller, jobs.len()).await;
+        tracing::error!(error = %e, batch_id = %batch_id, "Failed to enqueue batch");
+        return Err(ApiError::from_error("Failed to enqueue batch", e));
+    }
+
+    let mut statuses = Vec::with_capacity(accepted_jobs.len());
+    for (index, job) in &accepted_jobs {
+        if job.depends_on.is_empty() {
+            statuses.push(Some(job.status));
+            results.push(BatchItemResult::Accepted { index: *index, id: job.id, status: job.status });
+            continue;
+        }
+
+        match queue.enqueue_with_dependencies(job).await {
+            Ok(status) => {
+                statuses.push(Some(status));
+                results.push(BatchItemResult::Accepted { index: *index, id: job.id, status });
+            }
+            Err(e) => {
+                release_unique_locks(queue.as_ref(), &[job]).await;
+                release_quota(queue.as_ref(), &config, &caller, 1).await;
+                statuses.push(None);
+                results.push(BatchItemResult::rejected(*index, e.into()));
+            }
+        }
+    }
+
+    for (index, position, policy) in batch_duplicates {
+        let (_, job) = &accepted_jobs[position];
+        results.push(match statuses[position] {
+            Some(status) => BatchItemResult::Deduplicated { index, id: job.id, status, policy },
+            None => BatchItemResult::rejected(
+                index,
+                ApiError::new(StatusCode::CONFLICT, format!("Matching unique job {} was rejected", job.id)),
+            ),
+        });
+    }
+
+    results.sort_by_key(|result| match result {
+        BatchItemResult::Accepted { index, .. }
+        | BatchItemResult::Deduplicated { index, .. }
+        | BatchItemResult::Rejected { index, .. } => *index,
+    });
+
+    let accepted = results
+        .iter()
+        .filter(|result| !matches!(result, BatchItemResult::Rejected { .. }))
+        .count();
+    let rejected = results.len() - accepted;
+
+    let status = match (accepted, rejected) {
+        (_, 0) => StatusCode::CREATED,
+        (0, _) => StatusCode::UNPROCESSABLE_ENTITY,
+        _ => StatusCode::MULTI_STATUS,
+    };
+
+    tracing::info!(batch_id = %batch_id, accepted, rejected, "Batch submitted");
+
+    Ok((
+        status,
+        Json(CreateBatchResponse {
+            batch_id,
+            accepted,
+            rejected,
+            results,
+        }),
+    ))
+}
+
+/// Récapitulatif d'un lot
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BatchSummaryResponse {
+    pub batch_id: Uuid,
+    pub total: usize,
+    pub statuses: HashMap<JobStatus, usize>,
+}
+
+/// Récupérer l'avancement d'un lot (nombre de jobs par statut)
+#[utoipa::path(
+    get,
+    path = "/batches/{id}",
+    tag = "jobs",
+    params(("id" = Uuid, Path, description = "Batch id")),
+    responses(
+        (status = 200, description = "Job count per status", body = BatchSummaryResponse),
+        (status = 404, description = "Batch not found", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn get_batch<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(batch_id): ApiPath<Uuid>,
+) -> Result<Json<BatchSummaryResponse>, ApiError> {
+    let filter = JobFilter {
+        batch_id: Some(batch_id),
+        tenant: caller.tenant(),
+        ..Default::default()
+    };
+
+    let mut statuses = HashMap::new();
+    let mut total = 0;
+    let mut cursor = None;
+
+    loop {
+        let page = queue
+            .list_jobs(&filter, cursor, MAX_PAGE_LIMIT)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = %e, batch_id = %batch_id, "Failed to fetch batch");
+                ApiError::from_error("Failed to fetch batch", e)
+            })?;
+
+        total += page.jobs.len();
+        for job in &page.jobs {
+            *statuses.entry(job.status).or_insert(0) += 1;
+        }
+
+        match page.next_cursor.and_then(|cursor| cursor.parse().ok()) {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+
+    if total == 0 {
+        return Err(ApiError::new(StatusCode::NOT_FOUND, format!("Batch {} not found", batch_id)));
+    }
+
+    Ok(Json(BatchSummaryResponse {
+        batch_id,
+        total,
+        statuses,
+    }))
+}
+
+/// Lister et rechercher les jobs.
+///
+/// Paramètres : `status`, `kind`, `queue`, `batch`, `metadata.<clé>=<valeur>`,
+/// `created_after` (RFC 3339), `cursor` et `limit`.
+#[utoipa::path(
+    get,
+    path = "/jobs",
+    tag = "jobs",
+    params(
+        ("status" = Option<JobStatus>, Query, description = "Job status"),
+        ("kind" = Option<String>, Query, description = "Job kind"),
+        ("queue" = Option<String>, Query, description = "Queue name"),
+        ("batch" = Option<Uuid>, Query, description = "Batch id"),
+        ("created_after" = Option<String>, Query, description = "RFC 3339 timestamp"),
+        ("cursor" = Option<String>, Query, description = "Cursor returned by the previous page"),
+        ("limit" = Option<usize>, Query, description = "Page size (1 to 500, default 50)"),
+    ),
+    responses(
+        (status = 200, description = "Page of jobs", body = JobPage),
+        (status = 400, description = "Invalid query parameters", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn list_jobs<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<JobPage>, ApiError> {
+    let (mut filter, cursor, limit) = parse_list_params(params).map_err(invalid_query)?;
+    filter.tenant = caller.tenant();
+
+    let mut page = queue.list_jobs(&filter, cursor, limit).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to list jobs");
+        ApiError::from_error("Failed to list jobs", e)
+    })?;
+    // Une clé restreinte peut recevoir des pages plus courtes que `limit`
+    page.jobs.retain(|job| caller.allows_job(job));
+
+    Ok(Json(page))
+}
+
+fn parse_list_params(
+    params: HashMap<String, String>,
+) -> Result<(JobFilter, Option<JobCursor>, usize), IronForgeError> {
+    let invalid = |name: &str, value: &str| {
+        IronForgeError::InvalidQuery(format!("invalid value for {}: {}", name, value))
+    };
+
+    let mut filter = JobFilter::default();
+    let mut cursor = None;
+    let mut limit = DEFAULT_PAGE_LIMIT;
+
+    for (name, value) in params {
+        match name.as_str() {
+            "status" => {
+                filter.status = Some(
+                    serde_json::from_value(Value::String(value.clone()))
+                        .map_err(|_| invalid(&name, &value))?,
+                );
+            }
+            "kind" => filter.kind = Some(value),
+            "batch" => {
+                filter.batch_id = Some(Uuid::parse_str(&value).map_err(|_| invalid(&name, &value))?);
+            }
+            "queue" => filter.queue = Some(value),
+            "created_after" => {
+                let after = chrono::DateTime::parse_from_rfc3339(&value)
+                    .map_err(|_| invalid(&name, &value))?;
+                filter.created_after = Some(after.with_timezone(&chrono::Utc));
+            }
+            "cursor" => cursor = Some(value.parse::<JobCursor>()?),
+            "limit" => {
+                limit = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|limit| (1..=MAX_PAGE_LIMIT).contains(limit))
+                    .ok_or_else(|| invalid(&name, &value))?;
+            }
+            _ => match name.strip_prefix("metadata.") {
+                Some(key) if !key.is_empty() => filter.metadata.push((key.to_string(), value)),
+                _ => {
+                    return Err(IronForgeError::InvalidQuery(format!(
+                        "unknown parameter: {}",
+                        name
+                    )))
+                }
+            },
+        }
+    }
+
+    // Ordre déterministe : le premier critère de métadonnées sert d'index
+    filter.metadata.sort();
+
+    Ok((filter, cursor, limit))
+}
+
+/// Flux SSE d'événements de jobs
+type EventStream = Sse<BoxStream<'static, Result<Event, axum::Error>>>;
+
+/// Suivre un job en direct (SSE) : état courant, puis changements d'état et
+/// avancements, jusqu'à un état terminal
+#[utoipa::path(
+    get,
+    path = "/jobs/{id}/events",
+    tag = "events",
+    params(("id" = Uuid, Path, description = "Job id")),
+    responses(
+        (status = 200, description = "Server-sent events until the job ends", body = JobEvent, content_type = "text/event-stream"),
+        (status = 404, description = "Job not found", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn job_events<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(job_id): ApiPath<Uuid>,
+) -> Result<EventStream, ApiError> {
+    let events = job_event_stream(queue.as_ref(), &caller, job_id).await?;
+    Ok(sse_stream(events))
+}
+
+/// Événements d'un job : son état courant, puis ses changements d'état et
+/// avancements, jusqu'à un état terminal (partagé par le SSE et le gRPC)
+pub async fn job_event_stream<Q: QueueBackend>(
+    queue: &Q,
+    caller: &Caller,
+    job_id: Uuid,
+) -> Result<BoxStream<'static, JobEvent>, ApiError> {
+    // Abonnement avant la lecture du job : aucune transition ne peut être manquée
+    let events = queue.subscribe_events().await.map_err(events_error)?;
+
+    let job = queue
+        .get_job(job_id)
+        .await
+        .map_err(events_error)?
+        .filter(|job| caller.allows_job(job))
+        .ok_or_else(|| job_not_found(job_id))?;
+
+    let filter = EventFilter {
+        job_id: Some(job_id),
+        ..Default::default()
+    };
+
+    let events = stream::once(async move { JobEvent::status(&job) })
+        .chain(events.filter(move |event| std::future::ready(filter.matches(event))))
+        .scan(false, |finished, event| {
+            if *finished {
+                return std::future::ready(None);
+            }
+            *finished = event.is_final();
+            std::future::ready(Some(event))
+        });
+
+    Ok(events.boxed())
+}
+
+/// Suivre en direct (SSE) les événements de tous les jobs, filtrés par `kind` et `queue`
+#[utoipa::path(
+    get,
+    path = "/events",
+    tag = "events",
+    params(
+        ("kind" = Option<String>, Query, description = "Job kind"),
+        ("queue" = Option<String>, Query, description = "Queue name"),
+    ),
+    responses(
+        (status = 200, description = "Server-sent events of matching jobs", body = JobEvent, content_type = "text/event-stream"),
+        (status = 400, description = "Invalid query parameters", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn stream_events<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<EventStream, ApiError> {
+    let mut filter = EventFilter::default();
+
+    for (name, value) in params {
+        match name.as_str() {
+            "kind" => filter.kind = Some(value),
+            "queue" => filter.queue = Some(value),
+            _ => {
+                return Err(invalid_query(IronForgeError::InvalidQuery(format!(
+                    "unknown parameter: {}",
+                    name
+                ))))
+            }
+        }
+    }
+
+    let events = queue.subscribe_events().await.map_err(events_error)?;
+    let events = events.filter(move |event| {
+        std::future::ready(filter.matches(event) && caller.allows_event(event))
+    });
+
+    Ok(sse_stream(events))
+}
+
+fn sse_stream(events: impl futures::Stream<Item = JobEvent> + Send + 'static) -> EventStream {
+    let events = events.map(|event| Event::default().event(event.name()).json_data(&event));
+
+    Sse::new(events.boxed()).keep_alive(KeepAlive::default())
+}
+
+fn events_error(e: IronForgeError) -> ApiError {
+    tracing::error!(error = %e, "Failed to subscribe to job events");
+    ApiError::from_error("Failed to subscribe to job events", e)
+}
+
+/// Supprimer un job (seulement si en état Queued ou Waiting)
+#[utoipa::path(
+    delete,
+    path = "/jobs/{id}",
+    tag = "jobs",
+    params(("id" = Uuid, Path, description = "Job id")),
+    responses(
+        (status = 204, description = "Job deleted"),
+        (status = 404, description = "Job not found", body = ApiError, content_type = "application/problem+json"),
+        (status = 409, description = "Job is neither queued nor waiting", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn delete_job<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(job_id): ApiPath<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    // Vérifier que le job existe et est visible par l'appelant
+    queue
+        .get_job(job_id)
+        .await
+        .map_err(|e| {
+            ApiError::from_error("Failed to fetch job", e)
+        })?
+        .filter(|job| caller.allows_job(job))
+        .ok_or_else(|| job_not_found(job_id))?;
+
+    // Annuler d'abord le job (Queued ou Waiting) : un worker qui vient de le
+    // dequeue ne le démarrera pas
+    queue
+        .modify_job(job_id, Some(JobStatus::Cancelled), |_| Ok(()))
+        .await
+        .map_err(|e| match e {
+            IronForgeError::InvalidStatusTransition { from, .. } => ApiError::new(
+                StatusCode::CONFLICT,
+                format!("Cannot delete job in status {:?}", from),
+            )
+            .with_code(ErrorCode::InvalidJobState),
+            IronForgeError::JobNotFound(_) => job_not_found(job_id),
+            e => ApiError::from_error("Failed to delete job", e),
+        })?;
+
+    // Les jobs qui en dépendaient appliquent leur politique d'échec, et
+    // l'errback de son workflow est déclenché, tant que le job annulé existe
+    if let Err(e) = queue.resolve_dependents(job_id).await {
+        tracing::error!(error = %e, job_id = %job_id, "Failed to resolve dependent jobs");
+    }
+
     queue.delete_job(job_id).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::with_details("Failed to delete job", e.to_string())),
-        )
+        ApiError::from_error("Failed to delete job", e)
     })?;
 
     tracing::info!(job_id = %job_id, "Job deleted");
     Ok(StatusCode::NO_CONTENT)
 }
 
-/// Statistiques de la queue
-#[derive(Debug, Serialize)]
+/// Statistiques de la queue.
+///
+/// Pour un appelant rattaché à un tenant, `queue_depth` est celle de la queue
+/// du tenant et les compteurs globaux (tous tenants confondus) sont omis.
+#[derive(Debug, Serialize, ToSchema)]
 pub struct QueueStatsResponse {
     pub queue_depth: i64,
-    pub dlq_depth: i64,
-    pub active_jobs: i64,
-    pub total_jobs: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dlq_depth: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub active_jobs: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parked_jobs: Option<i64>,
+    /// Jobs différés dont la date d'exécution n'est pas encore atteinte
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delayed_jobs: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_jobs: Option<i64>,
+    pub paused_queues: Vec<String>,
+    pub paused_kinds: Vec<String>,
+    /// Jobs en attente d'exécution par tenant
+    pub tenant_queue_depths: HashMap<String, i64>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/queues/stats",
+    tag = "queues",
+    responses(
+        (status = 200, description = "Queue statistics", body = QueueStatsResponse),
+    ),
+)]
 pub async fn queue_stats<Q: QueueBackend>(
     State(queue): State<Arc<Q>>,
-) -> Result<Json<QueueStatsResponse>, (StatusCode, Json<ErrorResponse>)> {
+    caller: Caller,
+) -> Result<Json<QueueStatsResponse>, ApiError> {
     let stats = queue.get_stats().await.map_err(|e| {
         tracing::error!(error = %e, "Failed to fetch queue stats");
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::with_details(
-                "Failed to fetch stats",
-                e.to_string(),
-            )),
-        )
+        ApiError::from_error("Failed to fetch stats", e)
     })?;
 
-    Ok(Json(QueueStatsResponse {
-        queue_depth: stats.queue_depth,
-        dlq_depth: stats.dlq_depth,
-        active_jobs: stats.active_jobs,
-        total_jobs: stats.queue_depth + stats.active_jobs,
-    }))
+    let tenant_queue_depths: HashMap<String, i64> = stats
+        .tenant_queue_depths
+        .into_iter()
+        .filter(|(tenant, _)| caller.sees_tenant(Some(tenant)))
+        .collect();
+
+    // Les compteurs globaux ne sont pas ventilés par tenant : ils ne sont
+    // montrés qu'aux appelants qui voient tous les tenants
+    let response = match caller.tenant() {
+        Some(tenant) => QueueStatsResponse {
+            queue_depth: tenant_queue_depths.get(&tenant).copied().unwrap_or(0),
+            dlq_depth: None,
+            active_jobs: None,
+            parked_jobs: None,
+            delayed_jobs: None,
+            total_jobs: None,
+            paused_queues: stats.paused.queues,
+            paused_kinds: stats.paused.kinds,
+            tenant_queue_depths,
+        },
+        None => QueueStatsResponse {
+            queue_depth: stats.queue_depth,
+            dlq_depth: Some(stats.dlq_depth),
+            active_jobs: Some(stats.active_jobs),
+            parked_jobs: Some(stats.parked_jobs),
+            delayed_jobs: Some(stats.delayed_jobs),
+            total_jobs: Some(stats.queue_depth + stats.active_jobs + stats.parked_jobs + stats.delayed_jobs),
+            paused_queues: stats.paused.queues,
+            paused_kinds: stats.paused.kinds,
+            tenant_queue_depths,
+        },
+    };
+
+    Ok(Json(response))
+}
+
+/// Mettre en pause une queue
+#[utoipa::path(
+    post,
+    path = "/queues/{name}/pause",
+    tag = "queues",
+    params(("name" = String, Path, description = "Queue name")),
+    responses(
+        (status = 200, description = "Queue paused", body = Object),
+        (status = 403, description = "API key is bound to a tenant", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn pause_queue<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(name): ApiPath<String>,
+) -> Result<Json<Value>, ApiError> {
+    require_all_tenants(&caller)?;
+    set_paused(&queue, PauseTarget::Queue(name.clone()), true).await?;
+    Ok(Json(json!({ "queue": name, "paused": true })))
+}
+
+/// Reprendre une queue
+#[utoipa::path(
+    post,
+    path = "/queues/{name}/resume",
+    tag = "queues",
+    params(("name" = String, Path, description = "Queue name")),
+    responses(
+        (status = 200, description = "Queue resumed, with the number of released jobs", body = Object),
+        (status = 403, description = "API key is bound to a tenant", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn resume_queue<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(name): ApiPath<String>,
+) -> Result<Json<Value>, ApiError> {
+    require_all_tenants(&caller)?;
+    let released = set_paused(&queue, PauseTarget::Queue(name.clone()), false).await?;
+    Ok(Json(json!({ "queue": name, "paused": false, "released_jobs": released })))
+}
+
+/// Mettre en pause un type de job
+#[utoipa::path(
+    post,
+    path = "/kinds/{kind}/pause",
+    tag = "queues",
+    params(("kind" = String, Path, description = "Job kind")),
+    responses(
+        (status = 200, description = "Job kind paused", body = Object),
+        (status = 403, description = "API key is bound to a tenant", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn pause_kind<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(kind): ApiPath<String>,
+) -> Result<Json<Value>, ApiError> {
+    require_all_tenants(&caller)?;
+    set_paused(&queue, PauseTarget::Kind(kind.clone()), true).await?;
+    Ok(Json(json!({ "kind": kind, "paused": true })))
+}
+
+/// Reprendre un type de job
+#[utoipa::path(
+    post,
+    path = "/kinds/{kind}/resume",
+    tag = "queues",
+    params(("kind" = String, Path, description = "Job kind")),
+    responses(
+        (status = 200, description = "Job kind resumed, with the number of released jobs", body = Object),
+        (status = 403, description = "API key is bound to a tenant", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn resume_kind<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(kind): ApiPath<String>,
+) -> Result<Json<Value>, ApiError> {
+    require_all_tenants(&caller)?;
+    let released = set_paused(&queue, PauseTarget::Kind(kind.clone()), false).await?;
+    Ok(Json(json!({ "kind": kind, "paused": false, "released_jobs": released })))
+}
+
+/// Une pause s'applique à tous les tenants : elle est refusée aux clés
+/// rattachées à un tenant
+fn require_all_tenants(caller: &Caller) -> Result<(), ApiError> {
+    match caller.tenant() {
+        Some(tenant) => Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            format!("API key is bound to tenant {} and cannot pause or resume shared queues", tenant),
+        )),
+        None => Ok(()),
+    }
+}
+
+async fn set_paused<Q: QueueBackend>(
+    queue: &Arc<Q>,
+    target: PauseTarget,
+    paused: bool,
+) -> Result<u64, ApiError> {
+    let result = if paused {
+        queue.pause(&target).await.map(|_| 0)
+    } else {
+        queue.resume(&target).await
+    };
+
+    result.map_err(|e| {
+        tracing::error!(error = %e, target = ?target, paused, "Failed to update pause state");
+        ApiError::from_error("Failed to update pause state", e)
+    })
 }
 
 /// Réinjecter un job depuis la DLQ
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, ToSchema)]
 pub struct RetryJobRequest {
     #[serde(default)]
     pub reset_retry_count: bool,
 }
 
+#[utoipa::path(
+    post,
+    path = "/jobs/{id}/retry",
+    tag = "dlq",
+    request_body = RetryJobRequest,
+    params(("id" = Uuid, Path, description = "Job id")),
+    responses(
+        (status = 200, description = "Job requeued", body = Object),
+        (status = 404, description = "Job not found", body = ApiError, content_type = "application/problem+json"),
+        (status = 409, description = "Job is neither failed nor dead-lettered", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
 pub async fn retry_job<Q: QueueBackend>(
     State(queue): State<Arc<Q>>,
-    Path(job_id): Path<Uuid>,
-    Json(req): Json<RetryJobRequest>,
-) -> Result<(StatusCode, Json<Value>), (StatusCode, Json<ErrorResponse>)> {
-    let mut job = queue
+    caller: Caller,
+    ApiPath(job_id): ApiPath<Uuid>,
+    ApiJson(req): ApiJson<RetryJobRequest>,
+) -> Result<(StatusCode, Json<Value>), ApiError> {
+    queue
         .get_job(job_id)
         .await
         .map_err(|e| {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ErrorResponse::with_details("Failed to fetch job", e.to_string())),
-            )
+            ApiError::from_error("Failed to fetch job", e)
         })?
-        .ok_or((
-            StatusCode::NOT_FOUND,
-            Json(ErrorResponse::new(format!("Job {} not found", job_id))),
-        ))?;
+        .filter(|job| caller.allows_job(job))
+        .ok_or_else(|| job_not_found(job_id))?;
 
-    if job.status != JobStatus::DeadLetter && job.status != JobStatus::Failed {
-        return Err((
-            StatusCode::CONFLICT,
-            Json(ErrorResponse::new(format!(
-                "Job is not in Failed or DeadLetter status (current: {:?})",
-                job.status
-            ))),
-        ));
-    }
-
-    // Réinitialiser le compteur de retry si demandé
-    if req.reset_retry_count {
-        job.retry_count = 0;
-    }
-
-    job.status = JobStatus::Queued;
-
-    // Re-enqueue le job
-    queue.enqueue(&job).await.map_err(|e| {
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ErrorResponse::with_details("Failed to retry job", e.to_string())),
-        )
-    })?;
+    // Re-enqueue le job (Failed ou DeadLetter), en réinitialisant le compteur
+    // de retry si demandé
+    let job = queue
+        .modify_job(job_id, Some(JobStatus::Queued), |job| {
+            if req.reset_retry_count {
+                job.retry_count = 0;
+            }
+            Ok(())
+        })
+        .await
+        .map_err(|e| match e {
+            IronForgeError::InvalidStatusTransition { from, .. } => ApiError::new(
+                StatusCode::CONFLICT,
+                format!("Job is not in Failed or DeadLetter status (current: {:?})", from),
+            )
+            .with_code(ErrorCode::InvalidJobState),
+            IronForgeError::JobNotFound(_) => job_not_found(job_id),
+            e => ApiError::from_error("Failed to retry job", e),
+        })?;
 
     tracing::info!(job_id = %job_id, retry_count = job.retry_count, "Job retried");
 
@@ -267,3 +1272,1161 @@ pub async fn retry_job<Q: QueueBackend>(
         })),
     ))
 }
+
+/// Nombre maximum de jobs traités par une opération groupée sur la DLQ
+pub const MAX_DLQ_BULK: usize = 10_000;
+
+/// Lister les jobs de la DLQ (mêmes filtres et pagination que `GET /jobs`)
+#[utoipa::path(
+    get,
+    path = "/dlq",
+    tag = "dlq",
+    params(
+        ("kind" = Option<String>, Query, description = "Job kind"),
+        ("queue" = Option<String>, Query, description = "Queue name"),
+        ("batch" = Option<Uuid>, Query, description = "Batch id"),
+        ("created_after" = Option<String>, Query, description = "RFC 3339 timestamp"),
+        ("cursor" = Option<String>, Query, description = "Cursor returned by the previous page"),
+        ("limit" = Option<usize>, Query, description = "Page size (1 to 500, default 50)"),
+    ),
+    responses(
+        (status = 200, description = "Page of dead-lettered jobs", body = JobPage),
+        (status = 400, description = "Invalid query parameters", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn list_dlq<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<JobPage>, ApiError> {
+    let (mut filter, cursor, limit) = parse_dlq_params(params, true).map_err(invalid_query)?;
+    filter.tenant = caller.tenant();
+
+    let mut page = queue.list_jobs(&filter, cursor, limit).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to list DLQ");
+        ApiError::from_error("Failed to list DLQ", e)
+    })?;
+    page.jobs.retain(|job| caller.allows_job(job));
+
+    Ok(Json(page))
+}
+
+/// Sélection de jobs de la DLQ pour une opération groupée
+#[derive(Debug, Default, Deserialize, ToSchema)]
+pub struct DlqBulkRequest {
+    /// Jobs ciblés (sinon : tous les jobs correspondant aux filtres de la query)
+    #[serde(default)]
+    pub ids: Option<Vec<Uuid>>,
+
+    /// Remet le compteur de tentatives à zéro (requeue uniquement)
+    #[serde(default)]
+    pub reset_retry_count: bool,
+}
+
+/// Résultat d'une opération groupée sur la DLQ
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DlqBulkResponse {
+    pub count: usize,
+    pub job_ids: Vec<Uuid>,
+    /// D'autres jobs accessibles à l'appelant correspondent encore (limite
+    /// `MAX_DLQ_BULK` atteinte)
+    pub has_more: bool,
+}
+
+/// Réinjecter en queue des jobs de la DLQ (par liste d'IDs ou par filtre)
+#[utoipa::path(
+    post,
+    path = "/dlq/requeue",
+    tag = "dlq",
+    request_body = DlqBulkRequest,
+    params(
+        ("kind" = Option<String>, Query, description = "Job kind"),
+        ("queue" = Option<String>, Query, description = "Queue name"),
+        ("batch" = Option<Uuid>, Query, description = "Batch id"),
+        ("created_after" = Option<String>, Query, description = "RFC 3339 timestamp"),
+    ),
+    responses(
+        (status = 200, description = "Jobs requeued", body = DlqBulkResponse),
+        (status = 400, description = "Invalid query parameters", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn requeue_dlq<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    Query(params): Query<HashMap<String, String>>,
+    ApiJson(req): ApiJson<DlqBulkRequest>,
+) -> Result<Json<DlqBulkResponse>, ApiError> {
+    let (mut filter, _, _) = parse_dlq_params(params, false).map_err(invalid_query)?;
+    filter.tenant = caller.tenant();
+    let (jobs, has_more) = select_dead_letters(queue.as_ref(), &caller, &filter, req.ids.as_deref())
+        .await
+        .map_err(dlq_error)?;
+
+    let mut job_ids = Vec::with_capacity(jobs.len());
+    for job in jobs {
+        let requeued = queue
+            .modify_job(job.id, Some(JobStatus::Queued), |job| {
+                if req.reset_retry_count {
+                    job.retry_count = 0;
+                }
+                Ok(())
+            })
+            .await;
+
+        // Les jobs relancés ou supprimés entre-temps sont ignorés
+        match requeued {
+            Ok(job) => job_ids.push(job.id),
+            Err(IronForgeError::InvalidStatusTransition { .. } | IronForgeError::JobNotFound(_)) => {}
+            Err(e) => return Err(dlq_error(e)),
+        }
+    }
+
+    tracing::info!(count = job_ids.len(), reset_retry_count = req.reset_retry_count, "DLQ jobs requeued");
+
+    Ok(Json(DlqBulkResponse {
+        count: job_ids.len(),
+        job_ids,
+        has_more,
+    }))
+}
+
+/// Supprimer définitivement des jobs de la DLQ (par liste d'IDs ou par filtre)
+#[utoipa::path(
+    post,
+    path = "/dlq/purge",
+    tag = "dlq",
+    request_body = DlqBulkRequest,
+    params(
+        ("kind" = Option<String>, Query, description = "Job kind"),
+        ("queue" = Option<String>, Query, description = "Queue name"),
+        ("batch" = Option<Uuid>, Query, description = "Batch id"),
+        ("created_after" = Option<String>, Query, description = "RFC 3339 timestamp"),
+    ),
+    responses(
+        (status = 200, description = "Jobs deleted", body = DlqBulkResponse),
+        (status = 400, description = "Invalid query parameters", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn purge_dlq<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    Query(params): Query<HashMap<String, String>>,
+    ApiJson(req): ApiJson<DlqBulkRequest>,
+) -> Result<Json<DlqBulkResponse>, ApiError> {
+    let (mut filter, _, _) = parse_dlq_params(params, false).map_err(invalid_query)?;
+    filter.tenant = caller.tenant();
+    let (jobs, has_more) = select_dead_letters(queue.as_ref(), &caller, &filter, req.ids.as_deref())
+        .await
+        .map_err(dlq_error)?;
+
+    let mut job_ids = Vec::with_capacity(jobs.len());
+    for job in jobs {
+        queue.delete_job(job.id).await.map_err(dlq_error)?;
+        job_ids.push(job.id);
+    }
+
+    tracing::warn!(count = job_ids.len(), "DLQ jobs purged");
+
+    Ok(Json(DlqBulkResponse {
+        count: job_ids.len(),
+        job_ids,
+        has_more,
+    }))
+}
+
+/// Paramètres de recherche limités à la DLQ (le statut est imposé)
+fn parse_dlq_params(
+    params: HashMap<String, String>,
+    paginated: bool,
+) -> Result<(JobFilter, Option<JobCursor>, usize), IronForgeError> {
+    let forbidden: &[&str] = if paginated { &["status"] } else { &["status", "cursor", "limit"] };
+    if let Some(name) = forbidden.iter().find(|name| params.contains_key(**name)) {
+        return Err(IronForgeError::InvalidQuery(format!("unknown parameter: {}", name)));
+    }
+
+    let (mut filter, cursor, limit) = parse_list_params(params)?;
+    filter.status = Some(JobStatus::DeadLetter);
+
+    Ok((filter, cursor, limit))
+}
+
+/// Jobs de la DLQ ciblés par une opération groupée et accessibles à l'appelant,
+/// et s'il en reste au-delà de la limite
+async fn select_dead_letters<Q: QueueBackend>(
+    queue: &Q,
+    caller: &Caller,
+    filter: &JobFilter,
+    ids: Option<&[Uuid]>,
+) -> crate::models::Result<(Vec<Job>, bool)> {
+    let mut jobs = Vec::new();
+
+    if let Some(ids) = ids {
+        for job_id in ids.iter().take(MAX_DLQ_BULK) {
+            if let Some(job) = queue.get_job(*job_id).await? {
+                if filter.matches(&job) && caller.allows_job(&job) {
+                    jobs.push(job);
+                }
+            }
+        }
+        return Ok((jobs, ids.len() > MAX_DLQ_BULK));
+    }
+
+    // La limite ne porte que sur les jobs que l'appelant peut traiter : un job
+    // hors de ses droits ne doit pas faire annoncer `has_more` indéfiniment
+    let mut cursor = None;
+    loop {
+        let page = queue.list_jobs(filter, cursor, MAX_PAGE_LIMIT).await?;
+        for job in page.jobs.into_iter().filter(|job| caller.allows_job(job)) {
+            if jobs.len() == MAX_DLQ_BULK {
+                return Ok((jobs, true));
+            }
+            jobs.push(job);
+        }
+
+        match page.next_cursor {
+            Some(next) => cursor = Some(next.parse()?),
+            None => return Ok((jobs, false)),
+        }
+    }
+}
+
+fn invalid_query(e: IronForgeError) -> ApiError {
+    ApiError::with_details(StatusCode::BAD_REQUEST, "Invalid query parameters", e.to_string())
+}
+
+fn dlq_error(e: IronForgeError) -> ApiError {
+    tracing::error!(error = %e, "DLQ operation failed");
+    ApiError::from_error("DLQ operation failed", e)
+}
+
+/// Lister les workers vivants
+#[utoipa::path(
+    get,
+    path = "/workers",
+    tag = "workers",
+    responses(
+        (status = 200, description = "Live workers", body = Vec<WorkerInfo>),
+    ),
+)]
+pub async fn list_workers<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+) -> Result<Json<Vec<WorkerInfo>>, ApiError> {
+    let workers = queue.list_workers().await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to list workers");
+        ApiError::from_error("Failed to list workers", e)
+    })?;
+
+    Ok(Json(workers))
+}
+
+/// Récupérer un worker par son ID
+#[utoipa::path(
+    get,
+    path = "/workers/{id}",
+    tag = "workers",
+    params(("id" = String, Path, description = "Worker id")),
+    responses(
+        (status = 200, description = "Worker", body = WorkerInfo),
+        (status = 404, description = "Worker not found", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn get_worker<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    ApiPath(worker_id): ApiPath<String>,
+) -> Result<Json<WorkerInfo>, ApiError> {
+    let worker = queue
+        .get_worker(&worker_id)
+        .await
+        .map_err(|e| {
+            ApiError::from_error("Failed to fetch worker", e)
+        })?
+        .ok_or_else(|| {
+            ApiError::new(StatusCode::NOT_FOUND, format!("Worker {} not found", worker_id))
+        })?;
+
+    Ok(Json(worker))
+}
+
+/// Réponse de création d'un workflow
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CreateWorkflowResponse {
+    pub id: Uuid,
+    pub kind: WorkflowKind,
+    pub job_ids: Vec<Uuid>,
+    pub callback_id: Option<Uuid>,
+    pub errback_id: Option<Uuid>,
+}
+
+/// Créer un workflow (chain, group ou chord)
+#[utoipa::path(
+    post,
+    path = "/workflows",
+    tag = "workflows",
+    request_body = CreateWorkflowRequest,
+    responses(
+        (status = 201, description = "Workflow created", body = CreateWorkflowResponse),
+        (status = 403, description = "Job outside the API key scope", body = ApiError, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid workflow", body = ApiError, content_type = "application/problem+json"),
+        (status = 429, description = "Tenant quota exceeded", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn create_workflow<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    State(config): State<Arc<ApiConfig>>,
+    caller: Caller,
+    ApiJson(req): ApiJson<CreateWorkflowRequest>,
+) -> Result<(StatusCode, Json<CreateWorkflowResponse>), ApiError> {
+    for job_request in req.job_requests() {
+        validate_job(&config.validation, job_request)?;
+    }
+
+    let mut plan = req.into_plan();
+
+    if plan.jobs.is_empty() {
+        return Err(ApiError::new(
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Workflow must contain at least one job",
+        ));
+    }
+
+    plan.workflow.tenant = caller.tenant();
+    for job in plan.jobs.iter_mut().chain(plan.errback.as_mut()) {
+        job.submitted_by = caller.name();
+        job.assign_tenant(caller.tenant());
+        if !caller.allows_job(job) {
+            return Err(forbidden_job(job));
+        }
+    }
+
+    let count = plan.jobs.len() + usize::from(plan.errback.is_some());
+    reserve_quota(queue.as_ref(), &config, &caller, count).await?;
+
+    if let Err(e) = queue.create_workflow(&plan).await {
+        release_quota(queue.as_ref(), &config, &caller, count).await;
+        tracing::error!(error = %e, "Failed to create workflow");
+        return Err(ApiError::from_error("Failed to create workflow", e));
+    }
+
+    let workflow = plan.workflow;
+    Ok((
+        StatusCode::CREATED,
+        Json(CreateWorkflowResponse {
+            id: workflow.id,
+            kind: workflow.kind,
+            job_ids: workflow.job_ids,
+            callback_id: workflow.callback_id,
+            errback_id: workflow.errback_id,
+        }),
+    ))
+}
+
+/// Statut d'un job dans un workflow
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowJobStatus {
+    pub id: Uuid,
+    pub kind: String,
+    pub status: JobStatus,
+    pub result: Option<Value>,
+}
+
+impl From<Job> for WorkflowJobStatus {
+    fn from(job: Job) -> Self {
+        Self {
+            id: job.id,
+            kind: job.kind,
+            status: job.status,
+            result: job.result,
+        }
+    }
+}
+
+/// Statut agrégé d'un workflow
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WorkflowStatusResponse {
+    pub id: Uuid,
+    pub kind: WorkflowKind,
+    pub status: WorkflowStatus,
+    pub jobs: Vec<WorkflowJobStatus>,
+    pub callback_id: Option<Uuid>,
+    pub errback: Option<WorkflowJobStatus>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Récupérer le statut agrégé d'un workflow
+#[utoipa::path(
+    get,
+    path = "/workflows/{id}",
+    tag = "workflows",
+    params(("id" = Uuid, Path, description = "Workflow id")),
+    responses(
+        (status = 200, description = "Aggregate workflow status", body = WorkflowStatusResponse),
+        (status = 404, description = "Workflow not found", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn get_workflow<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(workflow_id): ApiPath<Uuid>,
+) -> Result<Json<WorkflowStatusResponse>, ApiError> {
+    let internal_error = |e: IronForgeError| {
+        tracing::error!(error = %e, workflow_id = %workflow_id, "Failed to fetch workflow");
+        ApiError::from_error("Failed to fetch workflow", e)
+    };
+
+    // Les jobs d'un workflow appartiennent tous au tenant qui l'a soumis
+    let workflow = queue
+        .get_workflow(workflow_id)
+        .await
+        .map_err(internal_error)?
+        .filter(|workflow| caller.sees_tenant(workflow.tenant.as_deref()))
+        .ok_or_else(|| {
+            ApiError::new(StatusCode::NOT_FOUND, format!("Workflow {} not found", workflow_id))
+        })?;
+
+    let mut jobs = Vec::with_capacity(workflow.job_ids.len());
+    for job_id in &workflow.job_ids {
+        if let Some(job) = queue.get_job(*job_id).await.map_err(internal_error)? {
+            jobs.push(WorkflowJobStatus::from(job));
+        }
+    }
+
+    let errback = match workflow.errback_id {
+        Some(errback_id) => queue
+            .get_job(errback_id)
+            .await
+            .map_err(internal_error)?
+            .map(WorkflowJobStatus::from),
+        None => None,
+    };
+
+    // Un job supprimé compte comme annulé
+    let mut statuses: Vec<JobStatus> = jobs.iter().map(|job| job.status).collect();
+    statuses.resize(workflow.job_ids.len(), JobStatus::Cancelled);
+
+    Ok(Json(WorkflowStatusResponse {
+        id: workflow.id,
+        kind: workflow.kind,
+        status: WorkflowStatus::aggregate(&statuses),
+        jobs,
+        callback_id: workflow.callback_id,
+        errback,
+        created_at: workflow.created_at,
+    }))
+}
+
+/// Lister les planifications
+#[utoipa::path(
+    get,
+    path = "/schedules",
+    tag = "schedules",
+    responses(
+        (status = 200, description = "Schedules", body = Vec<Schedule>),
+    ),
+)]
+pub async fn list_schedules<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+) -> Result<Json<Vec<Schedule>>, ApiError> {
+    let mut schedules = queue.list_schedules().await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to list schedules");
+        ApiError::from_error("Failed to list schedules", e)
+    })?;
+    schedules.retain(|schedule| caller.sees_tenant(schedule.tenant.as_deref()));
+
+    Ok(Json(schedules))
+}
+
+/// Créer une planification
+#[utoipa::path(
+    post,
+    path = "/schedules",
+    tag = "schedules",
+    request_body = ScheduleRequest,
+    responses(
+        (status = 201, description = "Schedule created", body = Schedule),
+        (status = 403, description = "Job template outside the API key scope", body = ApiError, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid schedule", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn create_schedule<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    State(config): State<Arc<ApiConfig>>,
+    caller: Caller,
+    ApiJson(req): ApiJson<ScheduleRequest>,
+) -> Result<(StatusCode, Json<Schedule>), ApiError> {
+    validate_job(&config.validation, &req.job)?;
+    let mut schedule = Schedule::from_request(req).map_err(invalid_schedule)?;
+    schedule.tenant = caller.tenant();
+    check_schedule_template(&caller, &schedule)?;
+
+    queue.save_schedule(&schedule).await.map_err(|e| {
+        tracing::error!(error = %e, "Failed to save schedule");
+        ApiError::from_error("Failed to save schedule", e)
+    })?;
+
+    tracing::info!(schedule_id = %schedule.id, name = %schedule.name, "Schedule created");
+
+    Ok((StatusCode::CREATED, Json(schedule)))
+}
+
+/// Récupérer une planification par son ID
+#[utoipa::path(
+    get,
+    path = "/schedules/{id}",
+    tag = "schedules",
+    params(("id" = Uuid, Path, description = "Schedule id")),
+    responses(
+        (status = 200, description = "Schedule", body = Schedule),
+        (status = 404, description = "Schedule not found", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn get_schedule<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(schedule_id): ApiPath<Uuid>,
+) -> Result<Json<Schedule>, ApiError> {
+    let schedule = fetch_schedule(&queue, &caller, schedule_id).await?;
+    Ok(Json(schedule))
+}
+
+/// Remplacer une planification
+#[utoipa::path(
+    put,
+    path = "/schedules/{id}",
+    tag = "schedules",
+    request_body = ScheduleRequest,
+    params(("id" = Uuid, Path, description = "Schedule id")),
+    responses(
+        (status = 200, description = "Schedule updated", body = Schedule),
+        (status = 404, description = "Schedule not found", body = ApiError, content_type = "application/problem+json"),
+        (status = 422, description = "Invalid schedule", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn update_schedule<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    State(config): State<Arc<ApiConfig>>,
+    caller: Caller,
+    ApiPath(schedule_id): ApiPath<Uuid>,
+    ApiJson(req): ApiJson<ScheduleRequest>,
+) -> Result<Json<Schedule>, ApiError> {
+    validate_job(&config.validation, &req.job)?;
+    let mut schedule = fetch_schedule(&queue, &caller, schedule_id).await?;
+    check_schedule_template(&caller, &schedule)?;
+    schedule.update(req).map_err(invalid_schedule)?;
+    check_schedule_template(&caller, &schedule)?;
+
+    queue.save_schedule(&schedule).await.map_err(|e| {
+        tracing::error!(error = %e, schedule_id = %schedule_id, "Failed to save schedule");
+        ApiError::from_error("Failed to save schedule", e)
+    })?;
+
+    tracing::info!(schedule_id = %schedule_id, "Schedule updated");
+
+    Ok(Json(schedule))
+}
+
+/// Supprimer une planification
+#[utoipa::path(
+    delete,
+    path = "/schedules/{id}",
+    tag = "schedules",
+    params(("id" = Uuid, Path, description = "Schedule id")),
+    responses(
+        (status = 204, description = "Schedule deleted"),
+        (status = 404, description = "Schedule not found", body = ApiError, content_type = "application/problem+json"),
+    ),
+)]
+pub async fn delete_schedule<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+    caller: Caller,
+    ApiPath(schedule_id): ApiPath<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    fetch_schedule(&queue, &caller, schedule_id).await?;
+
+    let deleted = queue.delete_schedule(schedule_id).await.map_err(|e| {
+        ApiError::from_error("Failed to delete schedule", e)
+    })?;
+
+    if !deleted {
+        return Err(ApiError::new(
+            StatusCode::NOT_FOUND,
+            format!("Schedule {} not found", schedule_id),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn fetch_schedule<Q: QueueBackend>(
+    queue: &Arc<Q>,
+    caller: &Caller,
+    schedule_id: Uuid,
+) -> Result<Schedule, ApiError> {
+    queue
+        .get_schedule(schedule_id)
+        .await
+        .map_err(|e| {
+            ApiError::from_error("Failed to fetch schedule", e)
+        })?
+        .filter(|schedule| caller.sees_tenant(schedule.tenant.as_deref()))
+        .ok_or_else(|| {
+            ApiError::new(StatusCode::NOT_FOUND, format!("Schedule {} not found", schedule_id))
+        })
+}
+
+/// Refuse une planification dont le job est hors du périmètre de la clé
+fn check_schedule_template(
+    caller: &Caller,
+    schedule: &Schedule,
+) -> Result<(), ApiError> {
+    let template = schedule.new_job();
+    if caller.allows_job(&template) {
+        Ok(())
+    } else {
+        Err(forbidden_job(&template))
+    }
+}
+
+fn invalid_schedule(e: IronForgeError) -> ApiError {
+    ApiError::from_error("Invalid schedule", e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{auth::API_KEY_HEADER, routes::create_router_with_config};
+    use crate::models::{Priority, TenantQuota};
+    use crate::queue::MemoryQueueBackend;
+    use axum::{body::Body, http::{header, Request}, Router};
+    use tower::ServiceExt;
+
+    /// Routeur sans authentification branché sur un backend en mémoire vide
+    fn app() -> (Arc<MemoryQueueBackend>, Router) {
+        app_with(ApiConfig::default())
+    }
+
+    fn app_with(config: ApiConfig) -> (Arc<MemoryQueueBackend>, Router) {
+        let queue = Arc::new(MemoryQueueBackend::new());
+        let app = create_router_with_config(queue.clone(), config);
+
+        (queue, app)
+    }
+
+    /// Configuration avec les clés d'API décrites en JSON
+    fn with_keys(keys: Value) -> ApiConfig {
+        ApiConfig {
+            api_keys: serde_json::from_value(keys).unwrap(),
+            ..Default::default()
+        }
+    }
+
+    /// Configuration avec une clé par tenant (`team-a`, `team-b`) et une clé de plateforme (`ops`)
+    fn tenant_config() -> ApiConfig {
+        with_keys(json!([
+            { "name": "team-a", "key": "team-a", "scopes": ["admin"], "tenant": "team-a" },
+            { "name": "team-b", "key": "team-b", "scopes": ["admin"], "tenant": "team-b" },
+            { "name": "ops", "key": "ops", "scopes": ["admin"] }
+        ]))
+    }
+
+    async fn send(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+        send_as(app, None, method, uri, body).await
+    }
+
+    async fn send_as(
+        app: &Router,
+        key: Option<&str>,
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let mut request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(key) = key {
+            request = request.header(API_KEY_HEADER, key);
+        }
+        let body = body.map_or_else(Body::empty, |body| Body::from(body.to_string()));
+
+        call(app, request.body(body).unwrap()).await
+    }
+
+    async fn call(app: &Router, request: Request<Body>) -> (StatusCode, Value) {
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+
+        (status, json)
+    }
+
+    #[tokio::test]
+    async fn test_batch_status_reflects_rejected_items() {
+        let (_, app) = app();
+        let valid = json!({ "kind": "email.send", "payload": {} });
+        let invalid = json!({ "payload": {} });
+
+        let (status, body) = send(&app, "POST", "/v1/jobs/batch", Some(json!([valid, valid]))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["accepted"], 2);
+
+        let (status, body) = send(&app, "POST", "/v1/jobs/batch", Some(json!([valid, invalid]))).await;
+        assert_eq!(status, StatusCode::MULTI_STATUS);
+        assert_eq!((body["accepted"].as_u64(), body["rejected"].as_u64()), (Some(1), Some(1)));
+        assert_eq!(body["results"][0]["outcome"], "accepted");
+        assert_eq!(body["results"][1]["outcome"], "rejected");
+        assert_eq!(body["results"][1]["code"], "invalid_body");
+
+        let batch_id = body["batch_id"].as_str().unwrap();
+        let (status, summary) = send(&app, "GET", &format!("/v1/batches/{}", batch_id), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(summary["total"], 1);
+        assert_eq!(summary["statuses"]["queued"], 1);
+
+        let (status, body) = send(&app, "POST", "/v1/jobs/batch", Some(json!([invalid]))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["accepted"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_idempotent_replay_returns_original_job() {
+        let (queue, app) = app();
+        let submit = |payload: Value| {
+            Request::builder()
+                .method("POST")
+                .uri("/v1/jobs")
+                .header(header::CONTENT_TYPE, "application/json")
+                .header("Idempotency-Key", "order-42")
+                .body(Body::from(json!({ "kind": "email.send", "payload": payload }).to_string()))
+                .unwrap()
+        };
+
+        let (status, first) = call(&app, submit(json!({ "to": "a" }))).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, replay) = call(&app, submit(json!({ "to": "a" }))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(replay["id"], first["id"]);
+
+        let (status, conflict) = call(&app, submit(json!({ "to": "b" }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(conflict["code"], "idempotency_key_conflict");
+
+        let page = queue.list_jobs(&JobFilter::default(), None, 10).await.unwrap();
+        assert_eq!(page.jobs.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dlq_bulk_reports_remaining_jobs() {
+        let (queue, app) = app_with(with_keys(json!([
+            { "name": "ops", "key": "ops", "scopes": ["admin"] },
+            { "name": "reports", "key": "reports", "scopes": ["admin"], "kinds": ["report.build"] }
+        ])));
+
+        let mut report = Job::new("report.build".to_string(), json!({}));
+        report.status = JobStatus::DeadLetter;
+        report.created_at -= chrono::Duration::minutes(1);
+        queue.update_job(&report).await.unwrap();
+        for _ in 0..=MAX_DLQ_BULK {
+            let mut job = Job::new("email.send".to_string(), json!({}));
+            job.status = JobStatus::DeadLetter;
+            queue.update_job(&job).await.unwrap();
+        }
+
+        // Les jobs hors des droits de la clé ne comptent pas dans la limite
+        let (status, body) = send_as(&app, Some("reports"), "POST", "/v1/dlq/purge", Some(json!({}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["job_ids"], json!([report.id]));
+        assert_eq!(body["has_more"], false);
+
+        let (status, body) = send_as(&app, Some("ops"), "POST", "/v1/dlq/requeue", Some(json!({}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["count"], MAX_DLQ_BULK);
+        assert_eq!(body["has_more"], true);
+
+        let (status, body) = send_as(&app, Some("ops"), "POST", "/v1/dlq/purge", Some(json!({}))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["count"], 1);
+        assert_eq!(body["has_more"], false);
+
+        // Liste d'IDs plus longue que la limite : seuls les premiers sont traités
+        let ids: Vec<Uuid> = (0..=MAX_DLQ_BULK).map(|_| Uuid::new_v4()).collect();
+        let (status, body) = send_as(&app, Some("ops"), "POST", "/v1/dlq/purge", Some(json!({ "ids": ids }))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["count"], 0);
+        assert_eq!(body["has_more"], true);
+    }
+
+    /// Ouvre un flux SSE et retourne le corps de la réponse
+    async fn open_stream(app: &Router, key: &str, uri: &str) -> Body {
+        let request = Request::builder()
+            .uri(uri)
+            .header(API_KEY_HEADER, key)
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/event-stream");
+
+        response.into_body()
+    }
+
+    /// Lit les `count` premiers événements d'un flux SSE (leurs données JSON)
+    async fn read_events(body: Body, count: usize) -> Vec<Value> {
+        let mut chunks = body.into_data_stream();
+        let mut buffer = String::new();
+        let mut events = Vec::new();
+
+        while events.len() < count {
+            let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), chunks.next())
+                .await
+                .expect("no event received")
+                .expect("stream ended")
+                .unwrap();
+            buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+
+            while let Some(end) = buffer.find("\n\n") {
+                let frame: String = buffer.drain(..end + 2).collect();
+                events.extend(
+                    frame
+                        .lines()
+                        .filter_map(|line| line.strip_prefix("data: "))
+                        .map(|data| serde_json::from_str::<Value>(data).unwrap()),
+                );
+            }
+        }
+
+        events
+    }
+
+    #[tokio::test]
+    async fn test_event_streams_follow_jobs_of_the_caller() {
+        let (queue, app) = app_with(tenant_config());
+
+        let mut job = Job::new("email.send".to_string(), json!({}));
+        job.tenant = Some("team-a".to_string());
+        queue.enqueue(&job).await.unwrap();
+
+        let job_stream = open_stream(&app, "team-a", &format!("/v1/jobs/{}/events", job.id)).await;
+        let team_a = open_stream(&app, "team-a", "/v1/events?kind=email.send").await;
+        let team_b = open_stream(&app, "team-b", "/v1/events?kind=email.send").await;
+        let (status, _) = send_as(&app, Some("team-b"), "GET", &format!("/v1/jobs/{}/events", job.id), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // Les événements d'un autre type ne sont pas transmis
+        let mut report = Job::new("report.generate".to_string(), json!({}));
+        report.tenant = Some("team-a".to_string());
+        queue.enqueue(&report).await.unwrap();
+
+        queue.publish_progress(&job, json!({ "percent": 50 })).await.unwrap();
+        queue.modify_job(job.id, Some(JobStatus::Running), |_| Ok(())).await.unwrap();
+        queue.modify_job(job.id, Some(JobStatus::Completed), |_| Ok(())).await.unwrap();
+
+        // Le flux d'un job commence par son état courant et se termine avec lui
+        let events = read_events(job_stream, 4).await;
+        let steps: Vec<(&str, &str)> = events
+            .iter()
+            .map(|event| (event["type"].as_str().unwrap(), event["status"].as_str().unwrap_or_default()))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![("status", "queued"), ("progress", ""), ("status", "running"), ("status", "completed")]
+        );
+
+        let events = read_events(team_a, 3).await;
+        assert!(events.iter().all(|event| event["job_id"] == job.id.to_string()));
+
+        // Les événements d'un autre tenant ne sont pas transmis
+        let mut other = Job::new("email.send".to_string(), json!({}));
+        other.tenant = Some("team-b".to_string());
+        queue.enqueue(&other).await.unwrap();
+        let events = read_events(team_b, 1).await;
+        assert_eq!(events[0]["job_id"], other.id.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_api_keys_authenticate_and_scope_requests() {
+        let (queue, app) = app_with(with_keys(json!([
+            { "name": "reader", "key": "read-key", "scopes": ["jobs:read"] },
+            { "name": "mailer", "key": "mail-key", "scopes": ["jobs:write"], "kinds": ["email.send"] }
+        ])));
+        let email = json!({ "kind": "email.send", "payload": {} });
+
+        let (status, body) = send(&app, "GET", "/v1/queues/stats", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(body["status"], 401);
+        let (status, _) = send_as(&app, Some("wrong-key"), "GET", "/v1/queues/stats", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = send_as(&app, Some("read-key"), "GET", "/v1/queues/stats", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_as(&app, Some("read-key"), "POST", "/v1/jobs", Some(email.clone())).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        // Le header Authorization est équivalent à X-API-Key
+        let request = Request::builder()
+            .method("POST")
+            .uri("/v1/jobs")
+            .header(header::AUTHORIZATION, "Bearer mail-key")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(email.to_string()))
+            .unwrap();
+        let (status, job) = call(&app, request).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let stored = queue.get_job(job["id"].as_str().unwrap().parse().unwrap()).await.unwrap().unwrap();
+        assert_eq!(stored.submitted_by.as_deref(), Some("mailer"));
+
+        let report = json!({ "kind": "report.generate", "payload": {} });
+        let (status, _) = send_as(&app, Some("mail-key"), "POST", "/v1/jobs", Some(report)).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        // Les sondes restent accessibles sans clé
+        let (status, _) = send(&app, "GET", "/health/live", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, body) = send(&app, "GET", "/health/ready", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["components"]["backend"]["status"], "up");
+    }
+
+    #[tokio::test]
+    async fn test_tenants_are_isolated_and_limited() {
+        let mut config = tenant_config();
+        config.tenant_quotas.insert(
+            "team-a".to_string(),
+            TenantQuota { max_queued_jobs: None, submissions_per_minute: Some(2) },
+        );
+        let (_, app) = app_with(config);
+        let email = json!({ "kind": "email.send", "payload": {} });
+
+        let (status, job) = send_as(&app, Some("team-a"), "POST", "/v1/jobs", Some(email.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let uri = format!("/v1/jobs/{}", job["id"].as_str().unwrap());
+
+        let (status, _) = send_as(&app, Some("team-a"), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_as(&app, Some("team-b"), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send_as(&app, Some("team-b"), "DELETE", &uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (_, page) = send_as(&app, Some("team-b"), "GET", "/v1/jobs", None).await;
+        assert_eq!(page["jobs"], json!([]));
+        let (_, page) = send_as(&app, Some("ops"), "GET", "/v1/jobs", None).await;
+        assert_eq!(page["jobs"].as_array().unwrap().len(), 1);
+
+        // Statistiques limitées au tenant, sans compteurs globaux
+        let (_, stats) = send_as(&app, Some("team-b"), "GET", "/v1/queues/stats", None).await;
+        assert_eq!(stats["queue_depth"], 0);
+        assert!(stats.get("dlq_depth").is_none());
+        let (_, stats) = send_as(&app, Some("ops"), "GET", "/v1/queues/stats", None).await;
+        assert_eq!(stats["queue_depth"], 1);
+        assert_eq!(stats["dlq_depth"], 0);
+
+        // La pause concerne tous les tenants
+        let (status, _) = send_as(&app, Some("team-a"), "POST", "/v1/queues/default/pause", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send_as(&app, Some("ops"), "POST", "/v1/queues/default/pause", None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = send_as(&app, Some("team-a"), "POST", "/v1/jobs", Some(email.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, body) = send_as(&app, Some("team-a"), "POST", "/v1/jobs", Some(email.clone())).await;
+        assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(body["code"], "quota_exceeded");
+        let (status, _) = send_as(&app, Some("team-b"), "POST", "/v1/jobs", Some(email)).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_updated_schedule_stays_with_its_tenant() {
+        let (_, app) = app_with(tenant_config());
+        let schedule = json!({
+            "name": "nightly-report",
+            "cron": "0 0 3 * * *",
+            "job": { "kind": "report.build", "payload": {} }
+        });
+
+        let (status, created) = send_as(&app, Some("team-a"), "POST", "/v1/schedules", Some(schedule.clone())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let uri = format!("/v1/schedules/{}", created["id"].as_str().unwrap());
+
+        let mut update = schedule;
+        update["cron"] = json!("0 30 4 * * *");
+        let (status, updated) = send_as(&app, Some("team-a"), "PUT", &uri, Some(update)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["tenant"], "team-a");
+
+        let (status, fetched) = send_as(&app, Some("team-a"), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(fetched["cron"], "0 30 4 * * *");
+        let (status, _) = send_as(&app, Some("team-b"), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_errors_carry_machine_readable_codes() {
+        let (_, app) = app();
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/v1/jobs")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from("{ not json"))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/problem+json");
+
+        let (status, body) = send(&app, "POST", "/v1/jobs", Some(json!({ "payload": {} }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "invalid_body");
+
+        // Toutes les violations sont rapportées, champ par champ
+        let invalid = json!({ "kind": "Not A Kind!", "payload": {}, "max_retries": 200 });
+        let (status, body) = send(&app, "POST", "/v1/jobs", Some(invalid)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "validation_failed");
+        assert_eq!(body["type"], "urn:ironforge:error:validation_failed");
+        let fields: Vec<&str> = body["errors"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|violation| violation["field"].as_str().unwrap())
+            .collect();
+        assert_eq!(fields, vec!["kind", "max_retries"]);
+
+        let (status, body) = send(&app, "GET", "/v1/jobs/not-a-uuid", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_path");
+
+        let (status, body) = send(&app, "GET", "/v1/jobs?colour=blue", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(body["code"], "invalid_query");
+
+        let (status, body) = send(&app, "GET", &format!("/v1/jobs/{}", Uuid::new_v4()), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "job_not_found");
+    }
+
+    #[tokio::test]
+    async fn test_patch_only_modifies_pending_jobs() {
+        let (queue, app) = app();
+
+        let job = Job::new("email.send".to_string(), json!({}));
+        queue.enqueue(&job).await.unwrap();
+        let uri = format!("/v1/jobs/{}", job.id);
+
+        let (status, patched) = send(&app, "PATCH", &uri, Some(json!({
+            "priority": "critical",
+            "metadata": { "team": "growth" }
+        }))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(patched["priority"], "critical");
+        assert_eq!(patched["metadata"]["team"], "growth");
+        assert_eq!(patched["version"], job.version + 1);
+
+        let (status, body) = send(&app, "PATCH", &uri, Some(json!({ "timeout_ms": 0 }))).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["code"], "validation_failed");
+
+        // Un job démarré n'est plus modifiable
+        queue.modify_job(job.id, Some(JobStatus::Running), |_| Ok(())).await.unwrap();
+        let (status, body) = send(&app, "PATCH", &uri, Some(json!({ "priority": "low" }))).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "invalid_job_state");
+        assert_eq!(queue.get_job(job.id).await.unwrap().unwrap().priority, Priority::Critical);
+
+        let (status, _) = send(&app, "PATCH", &format!("/v1/jobs/{}", Uuid::new_v4()), Some(json!({}))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    /// Crée une chaîne de deux jobs avec errback, retourne (jobs, errback)
+    async fn create_chain(app: &Router) -> (Vec<Uuid>, Uuid) {
+        let (status, workflow) = send(app, "POST", "/v1/workflows", Some(json!({
+            "type": "chain",
+            "jobs": [
+                { "kind": "report.fetch", "payload": {} },
+                { "kind": "report.render", "payload": {} }
+            ],
+            "on_failure": { "kind": "report.alert", "payload": {} }
+        }))).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        (
+            serde_json::from_value(workflow["job_ids"].clone()).unwrap(),
+            serde_json::from_value(workflow["errback_id"].clone()).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_delete_chain_member_settles_dependents() {
+        let (queue, app) = app();
+
+        let (job_ids, errback_id) = create_chain(&app).await;
+        let (first, second) = (job_ids[0], job_ids[1]);
+
+        let (status, _) = send(&app, "DELETE", &format!("/v1/jobs/{}", first), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert!(queue.get_job(first).await.unwrap().is_none());
+
+        let dependent = queue.get_job(second).await.unwrap().unwrap();
+        assert!(matches!(dependent.status, JobStatus::Cancelled | JobStatus::Failed));
+
+        // L'errback voit le job supprimé comme annulé
+        let errback = queue.get_job(errback_id).await.unwrap().unwrap();
+        assert_eq!(errback.status, JobStatus::Queued);
+        assert!(errback.metadata["failed_job_ids"].contains(&first.to_string()));
+
+        // Dernier job de la chaîne : aucun dépendant, l'errback est déclenché par la suppression
+        let (job_ids, errback_id) = create_chain(&app).await;
+        let (status, _) = send(&app, "DELETE", &format!("/v1/jobs/{}", job_ids[1]), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let errback = queue.get_job(errback_id).await.unwrap().unwrap();
+        assert_eq!(errback.status, JobStatus::Queued);
+        assert_eq!(errback.metadata["failed_job_ids"], job_ids[1].to_string());
+    }
+
+    #[tokio::test]
+    async fn test_workflow_is_visible_to_its_tenant_only() {
+        let (_, app) = app_with(tenant_config());
+
+        let (status, workflow) = send_as(&app, Some("team-a"), "POST", "/v1/workflows", Some(json!({
+            "type": "group",
+            "jobs": [{ "kind": "report.fetch", "payload": {} }]
+        }))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let uri = format!("/v1/workflows/{}", workflow["id"].as_str().unwrap());
+
+        let (status, _) = send_as(&app, Some("team-a"), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send_as(&app, Some("ops"), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // Même après la suppression de ses jobs, le workflow reste invisible aux autres tenants
+        let job_id = workflow["job_ids"][0].as_str().unwrap();
+        let (status, _) = send_as(&app, Some("team-a"), "DELETE", &format!("/v1/jobs/{}", job_id), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send_as(&app, Some("team-b"), "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_failed_workflow_leaves_no_job() {
+        let (queue, app) = app();
+
+        let (status, _) = send(&app, "POST", "/v1/workflows", Some(json!({
+            "type": "chain",
+            "jobs": [
+                { "kind": "report.fetch", "payload": {} },
+                { "kind": "report.render", "payload": {}, "depends_on": [Uuid::new_v4()] }
+            ],
+            "on_failure": { "kind": "report.alert", "payload": {} }
+        }))).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let page = queue.list_jobs(&JobFilter::default(), None, 10).await.unwrap();
+        assert!(page.jobs.is_empty());
+    }
+}
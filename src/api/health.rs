@@ -0,0 +1,203 @@
+use axum::{extract::State, http::StatusCode, Json};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use utoipa::ToSchema;
+
+use crate::metrics;
+use crate::queue::QueueBackend;
+use crate::scheduler::SCHEDULER_LEASE;
+use crate::worker::REAPER_LEASE;
+
+/// Délai maximum accordé au backend pour répondre à la sonde de readiness
+pub const READINESS_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Latence du backend au-delà de laquelle il est signalé comme dégradé
+pub const SLOW_BACKEND_MS: u64 = 500;
+
+/// État d'un composant, ou de l'instance (le pire de ses composants)
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, PartialOrd, Ord, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Up,
+    /// Fonctionne, mais un composant secondaire est lent ou absent
+    Degraded,
+    /// Ne peut pas servir de requêtes
+    Down,
+}
+
+/// Résultat de la vérification d'un composant
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ComponentHealth {
+    pub status: HealthStatus,
+
+    /// Temps de réponse mesuré (en millisecondes)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+impl ComponentHealth {
+    fn new(status: HealthStatus, detail: impl Into<Option<String>>) -> Self {
+        Self {
+            status,
+            latency_ms: None,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Réponse de `GET /health/ready`
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ReadinessResponse {
+    pub status: HealthStatus,
+    pub version: String,
+    pub components: BTreeMap<String, ComponentHealth>,
+}
+
+impl ReadinessResponse {
+    /// Agrège les composants : l'instance prend l'état du pire d'entre eux
+    pub fn from_components(components: BTreeMap<String, ComponentHealth>) -> Self {
+        let status = components
+            .values()
+            .map(|component| component.status)
+            .max()
+            .unwrap_or(HealthStatus::Up);
+
+        Self {
+            status,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            components,
+        }
+    }
+
+    /// `503` si l'instance ne peut pas servir de requêtes, `200` sinon (même dégradée)
+    pub fn status_code(&self) -> StatusCode {
+        match self.status {
+            HealthStatus::Down => StatusCode::SERVICE_UNAVAILABLE,
+            HealthStatus::Up | HealthStatus::Degraded => StatusCode::OK,
+        }
+    }
+}
+
+/// Sonde de liveness : le processus répond, sans vérifier ses dépendances
+#[utoipa::path(
+    get,
+    path = "/health/live",
+    tag = "health",
+    responses(
+        (status = 200, description = "Process is alive", body = Object),
+    ),
+    security(()),
+)]
+pub async fn live() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "status": "ok" }))
+}
+
+/// Sonde de readiness : connectivité et latence du backend, leaders du
+/// scheduler cron et du reaper, et collecte des métriques
+#[utoipa::path(
+    get,
+    path = "/health/ready",
+    tag = "health",
+    responses(
+        (status = 200, description = "Ready, possibly with degraded components", body = ReadinessResponse),
+        (status = 503, description = "Backend unreachable", body = ReadinessResponse),
+    ),
+    security(()),
+)]
+pub async fn ready<Q: QueueBackend>(
+    State(queue): State<Arc<Q>>,
+) -> (StatusCode, Json<ReadinessResponse>) {
+    let mut components = BTreeMap::new();
+    components.insert("backend".to_string(), check_backend(queue.as_ref()).await);
+    components.insert(
+        "scheduler".to_string(),
+        check_lease(queue.as_ref(), SCHEDULER_LEASE, "scheduler").await,
+    );
+    components.insert("reaper".to_string(), check_lease(queue.as_ref(), REAPER_LEASE, "reaper").await);
+    components.insert("metrics".to_string(), check_metrics());
+
+    let response = ReadinessResponse::from_components(components);
+    if response.status != HealthStatus::Up {
+        tracing::warn!(status = ?response.status, "Readiness check reported unhealthy components");
+    }
+
+    (response.status_code(), Json(response))
+}
+
+async fn check_backend<Q: QueueBackend>(queue: &Q) -> ComponentHealth {
+    let started = Instant::now();
+    let result = tokio::time::timeout(READINESS_TIMEOUT, queue.ping()).await;
+    let latency_ms = started.elapsed().as_millis() as u64;
+
+    let mut health = match result {
+        Ok(Ok(())) if latency_ms > SLOW_BACKEND_MS => ComponentHealth::new(
+            HealthStatus::Degraded,
+            format!("slow response (over {} ms)", SLOW_BACKEND_MS),
+        ),
+        Ok(Ok(())) => ComponentHealth::new(HealthStatus::Up, None),
+        Ok(Err(e)) => ComponentHealth::new(HealthStatus::Down, e.to_string()),
+        Err(_) => ComponentHealth::new(
+            HealthStatus::Down,
+            format!("no response within {} ms", READINESS_TIMEOUT.as_millis()),
+        ),
+    };
+    health.latency_ms = Some(latency_ms);
+
+    health
+}
+
+/// Un bail sans détenteur signale une boucle arrêtée : sans leader, le scheduler
+/// n'enqueue plus les exécutions cron et le reaper ne récupère plus les jobs orphelins
+async fn check_lease<Q: QueueBackend>(queue: &Q, lease: &str, component: &str) -> ComponentHealth {
+    match tokio::time::timeout(READINESS_TIMEOUT, queue.current_leader(lease)).await {
+        Ok(Ok(Some(leader))) => ComponentHealth::new(HealthStatus::Up, format!("leader: {}", leader)),
+        Ok(Ok(None)) => ComponentHealth::new(
+            HealthStatus::Degraded,
+            format!("no {} instance holds the leader lease", component),
+        ),
+        Ok(Err(e)) => ComponentHealth::new(HealthStatus::Degraded, e.to_string()),
+        Err(_) => ComponentHealth::new(HealthStatus::Degraded, "leader lookup timed out".to_string()),
+    }
+}
+
+fn check_metrics() -> ComponentHealth {
+    if metrics::is_installed() {
+        ComponentHealth::new(HealthStatus::Up, None)
+    } else {
+        ComponentHealth::new(
+            HealthStatus::Degraded,
+            "Prometheus recorder is not installed".to_string(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readiness_takes_worst_component() {
+        let component = |status| ComponentHealth::new(status, None);
+
+        let mut components = BTreeMap::new();
+        components.insert("backend".to_string(), component(HealthStatus::Up));
+        components.insert("metrics".to_string(), component(HealthStatus::Degraded));
+        let response = ReadinessResponse::from_components(components.clone());
+        assert_eq!(response.status, HealthStatus::Degraded);
+        assert_eq!(response.status_code(), StatusCode::OK);
+
+        components.insert("backend".to_string(), component(HealthStatus::Down));
+        let response = ReadinessResponse::from_components(components);
+        assert_eq!(response.status, HealthStatus::Down);
+        assert_eq!(response.status_code(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body = serde_json::to_value(&response).unwrap();
+        assert_eq!(body["components"]["backend"]["status"], "down");
+        assert!(body["components"]["metrics"].get("latency_ms").is_none());
+    }
+}
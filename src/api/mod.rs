@@ -1,4 +1,15 @@
+pub mod auth;
+pub mod config;
+pub mod dashboard;
+pub mod error;
 pub mod handlers;
+pub mod health;
+pub mod openapi;
 pub mod routes;
+pub mod v1;
 
-pub use routes::create_router;
+pub use auth::{ApiKey, Caller, Scope};
+pub use config::{ApiConfig, AppState};
+pub use error::{ApiError, ErrorCode};
+pub use openapi::ApiDoc;
+pub use routes::{create_router, create_router_with_config};
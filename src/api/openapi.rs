@@ -0,0 +1,132 @@
+use axum::Router;
+use utoipa::{
+    openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme},
+    Modify, OpenApi,
+};
+use utoipa_swagger_ui::SwaggerUi;
+
+use super::auth::API_KEY_HEADER;
+use super::{handlers, health, v1};
+
+/// Chemin du document OpenAPI
+pub const OPENAPI_PATH: &str = "/openapi.json";
+
+/// Chemin de la documentation interactive (Swagger UI embarqué)
+pub const DOCS_PATH: &str = "/docs";
+
+/// Spécification OpenAPI 3 de l'API, générée depuis les handlers et les modèles
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "IronForge",
+        description = "Distributed task scheduler API. Errors are RFC 7807 `application/problem+json` bodies. \
+                       Unprefixed paths (`/jobs`) remain as deprecated aliases of `/v1`."
+    ),
+    paths(handlers::health, health::live, health::ready),
+    nest((path = "/v1", api = v1::ApiDocV1)),
+    modifiers(&SecuritySchemes),
+    security(("bearer" = []), ("api_key" = [])),
+    tags(
+        (name = "jobs", description = "Job submission and search"),
+        (name = "workflows", description = "Chains, groups and chords"),
+        (name = "schedules", description = "Cron schedules"),
+        (name = "events", description = "Live job events (server-sent events)"),
+        (name = "queues", description = "Statistics, pause and resume"),
+        (name = "dlq", description = "Dead letter queue"),
+        (name = "workers", description = "Worker registry"),
+        (name = "health", description = "Liveness and readiness probes"),
+    )
+)]
+pub struct ApiDoc;
+
+/// Déclare les deux façons de présenter une clé d'API
+struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new(API_KEY_HEADER))),
+        );
+    }
+}
+
+/// Routes du document OpenAPI et de la documentation interactive (sans authentification)
+pub fn docs_router<S: Clone + Send + Sync + 'static>() -> Router<S> {
+    SwaggerUi::new(DOCS_PATH)
+        .url(OPENAPI_PATH, ApiDoc::openapi())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use regex::Regex;
+    use std::collections::BTreeSet;
+
+    /// Routes déclarées hors tests dans un fichier source, au format OpenAPI (`/v1/jobs/{id}`)
+    fn declared_routes(source: &str, prefix: &str) -> BTreeSet<(String, String)> {
+        let source = source.split("#[cfg(test)]").next().unwrap_or_default();
+        let route = Regex::new(r#"\.route\(\s*"([^"]+)",\s*(get|post|put|patch|delete)\("#).unwrap();
+        let param = Regex::new(r":(\w+)").unwrap();
+
+        route
+            .captures_iter(source)
+            .map(|captures| {
+                let path = param.replace_all(&captures[1], "{$1}");
+                (captures[2].to_string(), format!("{}{}", prefix, path))
+            })
+            .collect()
+    }
+
+    fn registered_routes() -> BTreeSet<(String, String)> {
+        let mut routes = declared_routes(include_str!("v1.rs"), v1::PREFIX);
+        routes.extend(declared_routes(include_str!("routes.rs"), ""));
+        routes
+    }
+
+    fn documented_routes() -> BTreeSet<(String, String)> {
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        spec["paths"]
+            .as_object()
+            .unwrap()
+            .iter()
+            .flat_map(|(path, operations)| {
+                operations
+                    .as_object()
+                    .unwrap()
+                    .keys()
+                    .map(move |method| (method.clone(), path.clone()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_every_route_is_documented() {
+        let registered = registered_routes();
+        assert!(registered.len() > 20, "v1.rs could not be parsed");
+        assert_eq!(registered, documented_routes());
+    }
+
+    #[test]
+    fn test_spec_matches_snapshot() {
+        let spec = ApiDoc::openapi().to_pretty_json().unwrap() + "\n";
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/openapi.json");
+
+        if std::env::var_os("UPDATE_OPENAPI").is_some() {
+            std::fs::write(path, &spec).unwrap();
+            return;
+        }
+
+        let snapshot = std::fs::read_to_string(path).unwrap_or_default();
+        assert!(
+            snapshot == spec,
+            "docs/openapi.json is out of date, regenerate it with `UPDATE_OPENAPI=1 cargo test openapi`"
+        );
+    }
+}
@@ -1,30 +1,119 @@
 use axum::{
-    routing::{delete, get, post},
+    extract::Request,
+    http::{header, HeaderValue},
+    middleware::{self, Next},
+    response::Response,
+    routing::get,
     Router,
 };
 use std::sync::Arc;
 use tower_http::trace::TraceLayer;
 
+use super::auth::{authorize, Scope};
+use super::config::{ApiConfig, AppState};
+use super::v1;
 use crate::queue::QueueBackend;
 
-/// Crée le router principal de l'API
+/// Header signalant une route dépréciée
+pub const DEPRECATION_HEADER: &str = "deprecation";
+
+/// Crée le router principal de l'API avec la configuration par défaut
 pub fn create_router<Q: QueueBackend + 'static>(queue: Arc<Q>) -> Router {
+    create_router_with_config(queue, ApiConfig::default())
+}
+
+/// Crée le router principal de l'API
+pub fn create_router_with_config<Q: QueueBackend + 'static>(
+    queue: Arc<Q>,
+    config: ApiConfig,
+) -> Router {
+    let config = Arc::new(config);
+    let state = AppState {
+        queue,
+        config: config.clone(),
+    };
+
+    let v1 = v1::router::<Q>(&config);
+
     Router::new()
-        // Jobs endpoints
-        .route("/jobs", post(super::handlers::create_job::<Q>))
-        .route("/jobs/:id", get(super::handlers::get_job::<Q>))
-        .route("/jobs/:id", delete(super::handlers::delete_job::<Q>))
-        .route("/jobs/:id/retry", post(super::handlers::retry_job::<Q>))
-        
-        // Queue endpoints
-        .route("/queues/stats", get(super::handlers::queue_stats::<Q>))
-        
-        // Health check
+        .nest(v1::PREFIX, v1.clone())
+
+        // Anciennes routes sans préfixe de version, conservées comme alias dépréciés
+        .merge(deprecated_aliases(v1, v1::PREFIX))
+
+        // Health checks (sans authentification)
         .route("/health", get(super::handlers::health))
-        
+        .route("/health/live", get(super::health::live))
+        .route("/health/ready", get(super::health::ready::<Q>))
+
+        // Spécification OpenAPI et documentation interactive (sans authentification)
+        .merge(super::openapi::docs_router())
+
+        // Tableau de bord web embarqué (client de l'API /v1)
+        .merge(super::dashboard::dashboard_router())
+
         // State injection
-        .with_state(queue)
-        
+        .with_state(state)
+
         // Middleware: tracing pour les requêtes HTTP
         .layer(TraceLayer::new_for_http())
 }
+
+/// Exige une clé d'API possédant `scope` pour toutes les routes du router
+pub(crate) fn scoped<S: Clone + Send + Sync + 'static>(
+    router: Router<S>,
+    scope: Scope,
+    config: &Arc<ApiConfig>,
+) -> Router<S> {
+    let config = config.clone();
+
+    router.route_layer(middleware::from_fn(move |req: Request, next: Next| {
+        authorize(config.clone(), scope, req, next)
+    }))
+}
+
+/// Sert les routes d'une version sans son préfixe, en signalant aux clients
+/// (`Deprecation` et `Link: rel="successor-version"`) la route à utiliser
+fn deprecated_aliases<S: Clone + Send + Sync + 'static>(
+    router: Router<S>,
+    prefix: &'static str,
+) -> Router<S> {
+    router.route_layer(middleware::from_fn(move |req: Request, next: Next| async move {
+        let successor = format!("<{}{}>; rel=\"successor-version\"", prefix, req.uri().path());
+        let mut response: Response = next.run(req).await;
+
+        let headers = response.headers_mut();
+        headers.insert(DEPRECATION_HEADER, HeaderValue::from_static("true"));
+        if let Ok(link) = HeaderValue::from_str(&successor) {
+            headers.insert(header::LINK, link);
+        }
+
+        response
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::StatusCode};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_deprecated_alias_headers() {
+        let routes = Router::new().route("/jobs/:id", get(|| async { "ok" }));
+        let app = Router::new()
+            .nest("/v1", routes.clone())
+            .merge(deprecated_aliases(routes, "/v1"));
+
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let legacy = app.clone().oneshot(request("/jobs/42")).await.unwrap();
+        assert_eq!(legacy.status(), StatusCode::OK);
+        assert_eq!(legacy.headers()[DEPRECATION_HEADER], "true");
+        assert_eq!(legacy.headers()[header::LINK], "</v1/jobs/42>; rel=\"successor-version\"");
+
+        let current = app.oneshot(request("/v1/jobs/42")).await.unwrap();
+        assert_eq!(current.status(), StatusCode::OK);
+        assert!(current.headers().get(DEPRECATION_HEADER).is_none());
+    }
+}
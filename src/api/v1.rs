@@ -0,0 +1,103 @@
+use axum::{
+    routing::{delete, get, patch, post, put},
+    Router,
+};
+use std::sync::Arc;
+use utoipa::OpenApi;
+
+use super::auth::Scope;
+use super::config::{ApiConfig, AppState};
+use super::handlers;
+use super::routes::scoped;
+use crate::queue::QueueBackend;
+
+/// Préfixe des routes de la version 1
+pub const PREFIX: &str = "/v1";
+
+/// Opérations de la version 1, montées sous `PREFIX` dans la spécification
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        handlers::create_job,
+        handlers::create_batch,
+        handlers::create_workflow,
+        handlers::list_jobs,
+        handlers::get_batch,
+        handlers::get_job,
+        handlers::patch_job,
+        handlers::job_events,
+        handlers::stream_events,
+        handlers::get_workflow,
+        handlers::list_schedules,
+        handlers::get_schedule,
+        handlers::queue_stats,
+        handlers::list_workers,
+        handlers::get_worker,
+        handlers::retry_job,
+        handlers::list_dlq,
+        handlers::requeue_dlq,
+        handlers::purge_dlq,
+        handlers::delete_job,
+        handlers::create_schedule,
+        handlers::update_schedule,
+        handlers::delete_schedule,
+        handlers::pause_queue,
+        handlers::resume_queue,
+        handlers::pause_kind,
+        handlers::resume_kind,
+    ),
+)]
+pub struct ApiDocV1;
+
+/// Routes de la version 1 de l'API, sans préfixe.
+///
+/// Politique de compatibilité : une version n'évolue que de façon additive
+/// (nouveaux endpoints, champs de réponse, paramètres optionnels). Un changement
+/// incompatible passe par une nouvelle version (`v2.rs`), qui réutilise les
+/// handlers partagés et ne redéfinit que ce qui change.
+pub fn router<Q: QueueBackend + 'static>(config: &Arc<ApiConfig>) -> Router<AppState<Q>> {
+    // Soumission de jobs
+    let write = Router::new()
+        .route("/jobs", post(handlers::create_job::<Q>))
+        .route("/jobs/batch", post(handlers::create_batch::<Q>))
+        .route("/jobs/:id", patch(handlers::patch_job::<Q>))
+        .route("/workflows", post(handlers::create_workflow::<Q>));
+
+    // Consultation
+    let read = Router::new()
+        .route("/jobs", get(handlers::list_jobs::<Q>))
+        .route("/batches/:id", get(handlers::get_batch::<Q>))
+        .route("/jobs/:id", get(handlers::get_job::<Q>))
+        .route("/jobs/:id/events", get(handlers::job_events::<Q>))
+        .route("/events", get(handlers::stream_events::<Q>))
+        .route("/workflows/:id", get(handlers::get_workflow::<Q>))
+        .route("/schedules", get(handlers::list_schedules::<Q>))
+        .route("/schedules/:id", get(handlers::get_schedule::<Q>))
+        .route("/queues/stats", get(handlers::queue_stats::<Q>))
+        .route("/workers", get(handlers::list_workers::<Q>))
+        .route("/workers/:id", get(handlers::get_worker::<Q>));
+
+    // Dead letter queue
+    let dlq = Router::new()
+        .route("/jobs/:id/retry", post(handlers::retry_job::<Q>))
+        .route("/dlq", get(handlers::list_dlq::<Q>))
+        .route("/dlq/requeue", post(handlers::requeue_dlq::<Q>))
+        .route("/dlq/purge", post(handlers::purge_dlq::<Q>));
+
+    // Administration
+    let admin = Router::new()
+        .route("/jobs/:id", delete(handlers::delete_job::<Q>))
+        .route("/schedules", post(handlers::create_schedule::<Q>))
+        .route("/schedules/:id", put(handlers::update_schedule::<Q>))
+        .route("/schedules/:id", delete(handlers::delete_schedule::<Q>))
+        .route("/queues/:name/pause", post(handlers::pause_queue::<Q>))
+        .route("/queues/:name/resume", post(handlers::resume_queue::<Q>))
+        .route("/kinds/:kind/pause", post(handlers::pause_kind::<Q>))
+        .route("/kinds/:kind/resume", post(handlers::resume_kind::<Q>));
+
+    Router::new()
+        .merge(scoped(write, Scope::JobsWrite, config))
+        .merge(scoped(read, Scope::JobsRead, config))
+        .merge(scoped(dlq, Scope::Dlq, config))
+        .merge(scoped(admin, Scope::Admin, config))
+}
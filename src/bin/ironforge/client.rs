@@ -0,0 +1,111 @@
+use reqwest::{header, Method};
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::config::Settings;
+
+/// Préfixe de l'API utilisée par le CLI
+const API_PREFIX: &str = "/v1";
+
+/// Timeout d'une requête
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Client de l'API HTTP
+pub struct Client {
+    http: reqwest::Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl Client {
+    pub fn new(settings: &Settings) -> Result<Self, String> {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("ironforge-cli/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .map_err(|e| format!("cannot create HTTP client: {}", e))?;
+
+        Ok(Self {
+            http,
+            base_url: format!("{}{}", settings.url, API_PREFIX),
+            api_key: settings.api_key.clone(),
+        })
+    }
+
+    pub async fn get(&self, path: &str, query: &[(String, String)]) -> Result<Value, String> {
+        self.send(Method::GET, path, query, None).await
+    }
+
+    pub async fn post(&self, path: &str, query: &[(String, String)], body: &Value) -> Result<Value, String> {
+        self.send(Method::POST, path, query, Some(body)).await
+    }
+
+    pub async fn delete(&self, path: &str) -> Result<Value, String> {
+        self.send(Method::DELETE, path, &[], None).await
+    }
+
+    /// Envoie une requête et retourne le corps JSON (`null` pour une réponse vide)
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(String, String)],
+        body: Option<&Value>,
+    ) -> Result<Value, String> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut request = self.http.request(method, &url).query(query);
+
+        if let Some(key) = &self.api_key {
+            request = request.bearer_auth(key);
+        }
+        if let Some(body) = body {
+            request = request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("request to {} failed: {}", url, e))?;
+        let status = response.status();
+        let text = response
+            .text()
+            .await
+            .map_err(|e| format!("cannot read response from {}: {}", url, e))?;
+
+        if !status.is_success() {
+            return Err(problem_message(status, &text));
+        }
+        if text.is_empty() {
+            return Ok(Value::Null);
+        }
+
+        serde_json::from_str(&text).map_err(|e| format!("invalid JSON response from {}: {}", url, e))
+    }
+}
+
+/// Message d'erreur à partir d'un corps RFC 7807 (`title`, `detail`, `errors`)
+pub fn problem_message(status: reqwest::StatusCode, body: &str) -> String {
+    let Ok(problem) = serde_json::from_str::<Value>(body) else {
+        return format!("{}: {}", status, body.trim());
+    };
+
+    let title = problem["title"].as_str().unwrap_or_else(|| status.canonical_reason().unwrap_or(""));
+    let mut message = format!("{} {}", status.as_u16(), title);
+    if let Some(detail) = problem["detail"].as_str() {
+        message.push_str(&format!(": {}", detail));
+    }
+    if let Some(code) = problem["code"].as_str() {
+        message.push_str(&format!(" [{}]", code));
+    }
+    for violation in problem["errors"].as_array().into_iter().flatten() {
+        message.push_str(&format!(
+            "\n  {}: {}",
+            violation["field"].as_str().unwrap_or("?"),
+            violation["message"].as_str().unwrap_or("invalid")
+        ));
+    }
+
+    message
+}
@@ -0,0 +1,146 @@
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::output::OutputFormat;
+
+/// URL du serveur utilisée sans profil
+pub const DEFAULT_URL: &str = "http://127.0.0.1:3000";
+
+/// Profil utilisé quand ni `--profile` ni `default_profile` n'en désignent un
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Fichier de configuration du CLI (`~/.config/ironforge/config.toml`)
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    /// Profil utilisé par défaut
+    pub default_profile: Option<String>,
+
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// Serveur et identifiants d'un environnement
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub url: Option<String>,
+    pub api_key: Option<String>,
+    pub output: Option<OutputFormat>,
+}
+
+/// Paramètres de connexion effectifs, après application des options
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    pub url: String,
+    pub api_key: Option<String>,
+    pub output: OutputFormat,
+}
+
+/// Valeurs passées en ligne de commande (ou par variables d'environnement)
+#[derive(Debug, Default)]
+pub struct Overrides {
+    pub profile: Option<String>,
+    pub url: Option<String>,
+    pub api_key: Option<String>,
+    pub output: Option<OutputFormat>,
+}
+
+/// Emplacement par défaut du fichier de configuration
+pub fn default_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("ironforge").join("config.toml"))
+}
+
+impl ConfigFile {
+    /// Lit le fichier ; un fichier absent équivaut à une configuration vide,
+    /// sauf s'il a été demandé explicitement
+    pub fn load(path: &Path, required: bool) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content)
+                .map_err(|e| format!("invalid config file {}: {}", path.display(), e)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && !required => Ok(Self::default()),
+            Err(e) => Err(format!("cannot read {}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(content)
+    }
+
+    /// Combine le profil choisi et les options : les options l'emportent
+    pub fn resolve(&self, overrides: Overrides) -> Result<Settings, String> {
+        let name = overrides
+            .profile
+            .as_deref()
+            .or(self.default_profile.as_deref())
+            .unwrap_or(DEFAULT_PROFILE);
+
+        let profile = match self.profiles.get(name) {
+            Some(profile) => profile.clone(),
+            // Un profil nommé explicitement doit exister
+            None if overrides.profile.is_some() || self.default_profile.is_some() => {
+                return Err(format!("unknown profile '{}'", name));
+            }
+            None => Profile::default(),
+        };
+
+        let url = overrides
+            .url
+            .or(profile.url)
+            .unwrap_or_else(|| DEFAULT_URL.to_string());
+
+        Ok(Settings {
+            url: url.trim_end_matches('/').to_string(),
+            api_key: overrides.api_key.or(profile.api_key).filter(|key| !key.is_empty()),
+            output: overrides.output.or(profile.output).unwrap_or_default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_resolution() {
+        let config = ConfigFile::parse(
+            r#"
+            default_profile = "local"
+
+            [profiles.local]
+            url = "http://127.0.0.1:3000/"
+
+            [profiles.prod]
+            url = "https://ironforge.example.com"
+            api_key = "secret"
+            output = "json"
+            "#,
+        )
+        .unwrap();
+
+        let local = config.resolve(Overrides::default()).unwrap();
+        assert_eq!(local.url, "http://127.0.0.1:3000");
+        assert_eq!(local.api_key, None);
+        assert_eq!(local.output, OutputFormat::Table);
+
+        let prod = config
+            .resolve(Overrides {
+                profile: Some("prod".to_string()),
+                output: Some(OutputFormat::Table),
+                ..Default::default()
+            })
+            .unwrap();
+        assert_eq!(prod.api_key.as_deref(), Some("secret"));
+        assert_eq!(prod.output, OutputFormat::Table);
+
+        let missing = config.resolve(Overrides {
+            profile: Some("staging".to_string()),
+            ..Default::default()
+        });
+        assert!(missing.is_err());
+
+        assert_eq!(ConfigFile::default().resolve(Overrides::default()).unwrap().url, DEFAULT_URL);
+        assert!(ConfigFile::parse("[profiles.local]\nurl = 1").is_err());
+    }
+}
@@ -0,0 +1,497 @@
+//! `ironforge` : client en ligne de commande de l'API HTTP d'IronForge
+
+mod client;
+mod config;
+mod output;
+
+use clap::{Args, Parser, Subcommand};
+use serde_json::{json, Map, Value};
+use std::io::Read;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use client::Client;
+use config::{ConfigFile, Overrides, Settings};
+use output::{cell, job_detail, jobs_table, print, OutputFormat, Table};
+
+/// Nombre maximum de pages suivies par `list --all` et par les opérations
+/// groupées sur la DLQ
+const MAX_PAGES: usize = 1000;
+
+#[derive(Debug, Parser)]
+#[command(name = "ironforge", version, about = "Command-line client for the IronForge API")]
+struct Cli {
+    /// Config file (default: ~/.config/ironforge/config.toml)
+    #[arg(long, global = true, env = "IRONFORGE_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Profile of the config file to use
+    #[arg(short, long, global = true, env = "IRONFORGE_PROFILE")]
+    profile: Option<String>,
+
+    /// Server URL, overrides the profile
+    #[arg(long, global = true, env = "IRONFORGE_URL")]
+    url: Option<String>,
+
+    /// API key, overrides the profile
+    #[arg(long, global = true, env = "IRONFORGE_API_KEY", hide_env_values = true)]
+    api_key: Option<String>,
+
+    /// Output format, overrides the profile
+    #[arg(short, long, global = true, value_enum)]
+    output: Option<OutputFormat>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Submit a job (payload read from a file, or stdin with `-`)
+    Submit(SubmitArgs),
+    /// Show a job with its attempts and result
+    Get { id: String },
+    /// Search jobs
+    List(ListArgs),
+    /// Cancel and delete a queued or waiting job
+    Cancel { id: String },
+    /// Requeue a failed or dead-lettered job
+    Retry {
+        id: String,
+        /// Reset the retry counter
+        #[arg(long)]
+        reset_retry_count: bool,
+    },
+    /// Dead letter queue
+    #[command(subcommand)]
+    Dlq(DlqCommand),
+    /// Queue statistics
+    Stats,
+    /// Live workers
+    Workers,
+    /// Cron schedules
+    Schedules,
+}
+
+#[derive(Debug, Args)]
+struct SubmitArgs {
+    /// Job kind (e.g. email.send)
+    kind: String,
+
+    /// JSON payload file, `-` for stdin (default: `{}`)
+    payload: Option<String>,
+
+    #[arg(long)]
+    queue: Option<String>,
+
+    /// critical, high, medium or low
+    #[arg(long)]
+    priority: Option<String>,
+
+    #[arg(long)]
+    max_retries: Option<u32>,
+
+    #[arg(long)]
+    timeout_ms: Option<u64>,
+
+    /// Run no earlier than this RFC 3339 time
+    #[arg(long)]
+    scheduled_for: Option<String>,
+
+    /// Metadata entry, repeatable
+    #[arg(long = "metadata", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    metadata: Vec<(String, String)>,
+
+    #[arg(long)]
+    idempotency_key: Option<String>,
+}
+
+/// Filtres communs à `list` et `dlq`
+#[derive(Debug, Args)]
+struct FilterArgs {
+    #[arg(long)]
+    kind: Option<String>,
+
+    #[arg(long)]
+    queue: Option<String>,
+
+    #[arg(long)]
+    batch: Option<String>,
+
+    /// RFC 3339 timestamp
+    #[arg(long)]
+    created_after: Option<String>,
+
+    /// Metadata filter, repeatable
+    #[arg(long = "metadata", value_name = "KEY=VALUE", value_parser = parse_key_value)]
+    metadata: Vec<(String, String)>,
+}
+
+impl FilterArgs {
+    fn query(&self) -> Vec<(String, String)> {
+        let mut query = Vec::new();
+        for (name, value) in [
+            ("kind", &self.kind),
+            ("queue", &self.queue),
+            ("batch", &self.batch),
+            ("created_after", &self.created_after),
+        ] {
+            if let Some(value) = value {
+                query.push((name.to_string(), value.clone()));
+            }
+        }
+        for (key, value) in &self.metadata {
+            query.push((format!("metadata.{}", key), value.clone()));
+        }
+        query
+    }
+}
+
+#[derive(Debug, Args)]
+struct PageArgs {
+    /// Page size (1 to 500)
+    #[arg(long)]
+    limit: Option<usize>,
+
+    /// Cursor returned by the previous page
+    #[arg(long, conflicts_with = "all")]
+    cursor: Option<String>,
+
+    /// Follow cursors until the last page
+    #[arg(long)]
+    all: bool,
+}
+
+#[derive(Debug, Args)]
+struct ListArgs {
+    /// waiting, queued, running, completed, failed, deadletter or cancelled
+    #[arg(long)]
+    status: Option<String>,
+
+    #[command(flatten)]
+    filters: FilterArgs,
+
+    #[command(flatten)]
+    page: PageArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum DlqCommand {
+    /// List dead-lettered jobs
+    List {
+        #[command(flatten)]
+        filters: FilterArgs,
+
+        #[command(flatten)]
+        page: PageArgs,
+    },
+    /// Requeue dead-lettered jobs by id, or every job matching the filters
+    Requeue {
+        #[command(flatten)]
+        selection: DlqSelection,
+
+        /// Reset the retry counter
+        #[arg(long)]
+        reset_retry_count: bool,
+    },
+    /// Delete dead-lettered jobs by id, or every job matching the filters
+    Purge {
+        #[command(flatten)]
+        selection: DlqSelection,
+
+        /// Confirm purging by filter
+        #[arg(long)]
+        yes: bool,
+    },
+}
+
+#[derive(Debug, Args)]
+struct DlqSelection {
+    /// Job id, repeatable (default: every job matching the filters)
+    #[arg(long = "id")]
+    ids: Vec<String>,
+
+    #[command(flatten)]
+    filters: FilterArgs,
+}
+
+fn parse_key_value(input: &str) -> Result<(String, String), String> {
+    input
+        .split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{}'", input))
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run(cli: Cli) -> Result<(), String> {
+    let settings = load_settings(&cli)?;
+    let client = Client::new(&settings)?;
+    let format = settings.output;
+
+    match cli.command {
+        Command::Submit(args) => submit(&client, format, args).await,
+        Command::Get { id } => {
+            let job = client.get(&format!("/jobs/{}", id), &[]).await?;
+            print(format, &job, job_detail);
+            Ok(())
+        }
+        Command::List(args) => {
+            let mut query = args.filters.query();
+            if let Some(status) = args.status {
+                query.push(("status".to_string(), status));
+            }
+            list_pages(&client, format, "/jobs", query, &args.page).await
+        }
+        Command::Cancel { id } => {
+            client.delete(&format!("/jobs/{}", id)).await?;
+            print(format, &json!({ "job_id": id, "deleted": true }), |_| {
+                format!("Job {} cancelled", id)
+            });
+            Ok(())
+        }
+        Command::Retry { id, reset_retry_count } => {
+            let body = json!({ "reset_retry_count": reset_retry_count });
+            let response = client.post(&format!("/jobs/{}/retry", id), &[], &body).await?;
+            print(format, &response, |response| {
+                format!("Job {} requeued (retry count {})", id, cell(&response["retry_count"]))
+            });
+            Ok(())
+        }
+        Command::Dlq(DlqCommand::List { filters, page }) => {
+            list_pages(&client, format, "/dlq", filters.query(), &page).await
+        }
+        Command::Dlq(DlqCommand::Requeue { selection, reset_retry_count }) => {
+            dlq_bulk(&client, format, "requeue", selection, reset_retry_count).await
+        }
+        Command::Dlq(DlqCommand::Purge { selection, yes }) => {
+            if selection.ids.is_empty() && !yes {
+                return Err("purging by filter deletes every matching job, pass --yes to confirm".to_string());
+            }
+            dlq_bulk(&client, format, "purge", selection, false).await
+        }
+        Command::Stats => {
+            let stats = client.get("/queues/stats", &[]).await?;
+            print(format, &stats, stats_table);
+            Ok(())
+        }
+        Command::Workers => {
+            let workers = client.get("/workers", &[]).await?;
+            print(format, &workers, workers_table);
+            Ok(())
+        }
+        Command::Schedules => {
+            let schedules = client.get("/schedules", &[]).await?;
+            print(format, &schedules, schedules_table);
+            Ok(())
+        }
+    }
+}
+
+/// Profil du fichier de configuration, complété par les options
+fn load_settings(cli: &Cli) -> Result<Settings, String> {
+    let config = match cli.config.clone().or_else(config::default_path) {
+        Some(path) => ConfigFile::load(&path, cli.config.is_some())?,
+        None => ConfigFile::default(),
+    };
+
+    config.resolve(Overrides {
+        profile: cli.profile.clone(),
+        url: cli.url.clone(),
+        api_key: cli.api_key.clone(),
+        output: cli.output,
+    })
+}
+
+async fn submit(client: &Client, format: OutputFormat, args: SubmitArgs) -> Result<(), String> {
+    let payload = match args.payload.as_deref() {
+        None => json!({}),
+        Some(source) => read_payload(source)?,
+    };
+
+    let mut body = Map::new();
+    body.insert("kind".to_string(), json!(args.kind));
+    body.insert("payload".to_string(), payload);
+    for (field, value) in [
+        ("queue", args.queue.map(Value::from)),
+        ("priority", args.priority.map(Value::from)),
+        ("max_retries", args.max_retries.map(Value::from)),
+        ("timeout_ms", args.timeout_ms.map(Value::from)),
+        ("scheduled_for", args.scheduled_for.map(Value::from)),
+        ("idempotency_key", args.idempotency_key.map(Value::from)),
+    ] {
+        if let Some(value) = value {
+            body.insert(field.to_string(), value);
+        }
+    }
+    if !args.metadata.is_empty() {
+        let metadata: Map<String, Value> = args
+            .metadata
+            .into_iter()
+            .map(|(key, value)| (key, Value::from(value)))
+            .collect();
+        body.insert("metadata".to_string(), Value::Object(metadata));
+    }
+
+    let response = client.post("/jobs", &[], &Value::Object(body)).await?;
+    print(format, &response, |response| {
+        format!("Job {} {}", cell(&response["id"]), cell(&response["status"]))
+    });
+    Ok(())
+}
+
+fn read_payload(source: &str) -> Result<Value, String> {
+    let content = if source == "-" {
+        let mut content = String::new();
+        std::io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|e| format!("cannot read payload from stdin: {}", e))?;
+        content
+    } else {
+        std::fs::read_to_string(source).map_err(|e| format!("cannot read {}: {}", source, e))?
+    };
+
+    serde_json::from_str(&content).map_err(|e| format!("payload is not valid JSON: {}", e))
+}
+
+/// Liste paginée de jobs ; `--all` suit les curseurs jusqu'à la dernière page
+async fn list_pages(
+    client: &Client,
+    format: OutputFormat,
+    path: &str,
+    mut query: Vec<(String, String)>,
+    page: &PageArgs,
+) -> Result<(), String> {
+    if let Some(limit) = page.limit {
+        query.push(("limit".to_string(), limit.to_string()));
+    }
+
+    let mut cursor = page.cursor.clone();
+    let mut jobs = Vec::new();
+    let mut next_cursor = Value::Null;
+
+    for _ in 0..MAX_PAGES {
+        let mut page_query = query.clone();
+        if let Some(cursor) = &cursor {
+            page_query.push(("cursor".to_string(), cursor.clone()));
+        }
+
+        let response = client.get(path, &page_query).await?;
+        jobs.extend(response["jobs"].as_array().cloned().unwrap_or_default());
+        next_cursor = response["next_cursor"].clone();
+
+        cursor = next_cursor.as_str().map(str::to_string);
+        if !page.all || cursor.is_none() {
+            break;
+        }
+    }
+
+    let response = json!({ "jobs": jobs, "next_cursor": next_cursor });
+    print(format, &response, |response| {
+        let mut text = jobs_table(response["jobs"].as_array().map(Vec::as_slice).unwrap_or_default());
+        if let Some(cursor) = response["next_cursor"].as_str() {
+            text.push_str(&format!("\n\nMore jobs: --cursor {}", cursor));
+        }
+        text
+    });
+    Ok(())
+}
+
+/// Opération groupée sur la DLQ, relancée tant que le serveur signale `has_more`
+/// et que chaque passe traite au moins un job
+async fn dlq_bulk(
+    client: &Client,
+    format: OutputFormat,
+    operation: &str,
+    selection: DlqSelection,
+    reset_retry_count: bool,
+) -> Result<(), String> {
+    let by_id = !selection.ids.is_empty();
+    let query = selection.filters.query();
+    let mut body = json!({ "reset_retry_count": reset_retry_count });
+    if by_id {
+        body["ids"] = json!(selection.ids);
+    }
+
+    let mut count = 0;
+    let mut job_ids = Vec::new();
+    let mut has_more = false;
+
+    for _ in 0..MAX_PAGES {
+        let response = client.post(&format!("/dlq/{}", operation), &query, &body).await?;
+        let processed = response["count"].as_u64().unwrap_or(0);
+        count += processed;
+        job_ids.extend(response["job_ids"].as_array().cloned().unwrap_or_default());
+        has_more = response["has_more"].as_bool().unwrap_or(false);
+
+        // Une passe sans effet se répéterait à l'identique
+        if by_id || !has_more || processed == 0 {
+            break;
+        }
+    }
+
+    let response = json!({ "count": count, "job_ids": job_ids, "has_more": has_more });
+    print(format, &response, |_| {
+        let done = if operation == "purge" { "purged" } else { "requeued" };
+        let remaining = if has_more { ", more jobs remain" } else { "" };
+        format!("{} jobs {}{}", count, done, remaining)
+    });
+    Ok(())
+}
+
+fn stats_table(stats: &Value) -> String {
+    let mut table = Table::new(vec!["METRIC", "VALUE"]);
+    for field in [
+        "queue_depth", "active_jobs", "delayed_jobs", "parked_jobs", "dlq_depth", "total_jobs",
+        "paused_queues", "paused_kinds",
+    ] {
+        table.row(vec![field.to_string(), cell(&stats[field])]);
+    }
+    for (tenant, depth) in stats["tenant_queue_depths"].as_object().into_iter().flatten() {
+        table.row(vec![format!("queue_depth[{}]", tenant), cell(depth)]);
+    }
+    table.render()
+}
+
+fn workers_table(workers: &Value) -> String {
+    let mut table = Table::new(vec!["ID", "HOST", "VERSION", "KINDS", "BUSY", "LAST HEARTBEAT"]);
+    for worker in workers.as_array().into_iter().flatten() {
+        let busy = worker["current_jobs"].as_array().map_or(0, Vec::len);
+        table.row(vec![
+            cell(&worker["id"]),
+            cell(&worker["hostname"]),
+            cell(&worker["version"]),
+            cell(&worker["kinds"]),
+            format!("{}/{}", busy, cell(&worker["concurrency"])),
+            cell(&worker["last_heartbeat"]),
+        ]);
+    }
+    table.render()
+}
+
+fn schedules_table(schedules: &Value) -> String {
+    let mut table = Table::new(vec!["ID", "NAME", "CRON", "TIMEZONE", "KIND", "ENABLED", "NEXT RUN"]);
+    for schedule in schedules.as_array().into_iter().flatten() {
+        table.row(vec![
+            cell(&schedule["id"]),
+            cell(&schedule["name"]),
+            cell(&schedule["cron"]),
+            cell(&schedule["timezone"]),
+            cell(&schedule["job"]["kind"]),
+            cell(&schedule["enabled"]),
+            cell(&schedule["next_run_at"]),
+        ]);
+    }
+    table.render()
+}
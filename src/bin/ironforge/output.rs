@@ -0,0 +1,149 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+use serde_json::Value;
+use std::io::Write;
+
+/// Format d'affichage des réponses (les descriptions des variantes sont l'aide de `--output`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Human-readable table
+    #[default]
+    Table,
+    /// API response as returned by the server
+    Json,
+}
+
+/// Tableau de texte aligné en colonnes
+pub struct Table {
+    headers: Vec<&'static str>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(headers: Vec<&'static str>) -> Self {
+        Self { headers, rows: Vec::new() }
+    }
+
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    pub fn render(&self) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|header| header.chars().count()).collect();
+        for row in &self.rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |cells: Vec<&str>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect();
+            padded.join("  ").trim_end().to_string()
+        };
+
+        let mut lines = vec![line(self.headers.clone())];
+        lines.extend(self.rows.iter().map(|row| line(row.iter().map(String::as_str).collect())));
+        lines.join("\n")
+    }
+}
+
+/// Texte d'une valeur JSON dans une cellule (`-` si absente)
+pub fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => "-".to_string(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) if items.is_empty() => "-".to_string(),
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join(","),
+        other => other.to_string(),
+    }
+}
+
+/// Affiche la réponse en JSON, ou sous forme de tableau avec `table`
+pub fn print(format: OutputFormat, value: &Value, table: impl FnOnce(&Value) -> String) {
+    let text = match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value).unwrap_or_default(),
+        OutputFormat::Table => table(value),
+    };
+
+    // Sortie fermée par le lecteur (`| head`) : rien de plus à afficher
+    let _ = writeln!(std::io::stdout().lock(), "{}", text);
+}
+
+/// Tableau de jobs (listes de jobs et de la DLQ)
+pub fn jobs_table(jobs: &[Value]) -> String {
+    let mut table = Table::new(vec!["ID", "KIND", "QUEUE", "STATUS", "PRIORITY", "RETRIES", "CREATED", "LAST ERROR"]);
+    for job in jobs {
+        table.row(vec![
+            cell(&job["id"]),
+            cell(&job["kind"]),
+            cell(&job["queue"]),
+            cell(&job["status"]),
+            cell(&job["priority"]),
+            format!("{}/{}", cell(&job["retry_count"]), cell(&job["max_retries"])),
+            cell(&job["created_at"]),
+            cell(&job["last_error"]),
+        ]);
+    }
+    table.render()
+}
+
+/// Détail d'un job : champs principaux, tentatives et résultat
+pub fn job_detail(job: &Value) -> String {
+    let mut fields = Table::new(vec!["FIELD", "VALUE"]);
+    for field in [
+        "id", "kind", "queue", "status", "priority", "retry_count", "max_retries", "timeout_ms",
+        "created_at", "scheduled_for", "tenant", "submitted_by", "version", "last_error",
+    ] {
+        if !job[field].is_null() {
+            fields.row(vec![field.to_string(), cell(&job[field])]);
+        }
+    }
+
+    let mut sections = vec![fields.render()];
+
+    if let Some(attempts) = job["attempts"].as_array().filter(|attempts| !attempts.is_empty()) {
+        let mut table = Table::new(vec!["ATTEMPT", "WORKER", "STARTED", "DURATION MS", "OUTCOME", "ERROR"]);
+        for attempt in attempts {
+            table.row(vec![
+                cell(&attempt["attempt"]),
+                cell(&attempt["worker_id"]),
+                cell(&attempt["started_at"]),
+                cell(&attempt["duration_ms"]),
+                cell(&attempt["outcome"]),
+                cell(&attempt["error"]),
+            ]);
+        }
+        sections.push(table.render());
+    }
+
+    for field in ["payload", "result", "metadata"] {
+        if !job[field].is_null() {
+            let json = serde_json::to_string_pretty(&job[field]).unwrap_or_default();
+            sections.push(format!("{}:\n{}", field.to_uppercase(), json));
+        }
+    }
+
+    sections.join("\n\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_table_aligns_columns() {
+        let mut table = Table::new(vec!["ID", "STATUS"]);
+        table.row(vec!["1".to_string(), cell(&json!("queued"))]);
+        table.row(vec!["12345".to_string(), cell(&Value::Null)]);
+
+        assert_eq!(table.render(), "ID     STATUS\n1      queued\n12345  -");
+        assert_eq!(cell(&json!(["email.send", "report"])), "email.send,report");
+        assert_eq!(cell(&json!(3)), "3");
+    }
+}
@@ -1,5 +1,8 @@
 use axum::{routing::get, Router};
-use iron_forge::{api, metrics, RedisQueueBackend};
+use iron_forge::{
+    api, grpc, metrics, Reaper, ReaperConfig, RedisQueueBackend, Scheduler, SchedulerConfig, WebhookConfig,
+    WebhookDispatcher,
+};
 use std::sync::Arc;
 use tracing::info;
 
@@ -26,8 +29,66 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let backend = RedisQueueBackend::new(&redis_url).await?;
     let queue = Arc::new(backend);
 
-    // Create API router
-    let api_router = api::create_router(queue.clone());
+    // Load API configuration (keys and tenant quotas)
+    let api_config = api::ApiConfig::from_env()?;
+    if api_config.api_keys.is_empty() {
+        tracing::warn!("API_KEYS_FILE is not set, the API is open to anyone who can reach it");
+    } else {
+        info!("🔑 API authentication enabled ({} keys)", api_config.api_keys.len());
+    }
+    if !api_config.tenant_quotas.is_empty() {
+        info!("📏 Tenant quotas loaded ({} tenants)", api_config.tenant_quotas.len());
+    }
+
+    // Start the cron scheduler (only the elected leader enqueues runs)
+    let scheduler_config = SchedulerConfig {
+        tenant_quotas: api_config.tenant_quotas.clone(),
+        ..SchedulerConfig::default()
+    };
+    let scheduler = Scheduler::new(queue.clone(), scheduler_config);
+    tokio::spawn(async move {
+        if let Err(e) = scheduler.run().await {
+            tracing::error!(error = %e, "Scheduler stopped");
+        }
+    });
+    info!("⏰ Cron scheduler started");
+
+    // Start the reaper (recovers running jobs whose worker instance is gone)
+    let reaper = Reaper::new(queue.clone(), ReaperConfig::default());
+    tokio::spawn(async move {
+        if let Err(e) = reaper.run().await {
+            tracing::error!(error = %e, "Reaper stopped");
+        }
+    });
+    info!("🧹 Reaper started");
+
+    // Start the webhook dispatcher (signed completion callbacks with retries)
+    let webhook_config = WebhookConfig::from_env();
+    if webhook_config.secret.is_some() {
+        let dispatcher = WebhookDispatcher::new(queue.clone(), webhook_config)?;
+        tokio::spawn(async move {
+            if let Err(e) = dispatcher.run().await {
+                tracing::error!(error = %e, "Webhook dispatcher stopped");
+            }
+        });
+        info!("🔔 Webhook dispatcher started");
+    } else {
+        tracing::warn!("WEBHOOK_SECRET is not set, webhooks are disabled and callback_url is rejected");
+    }
+
+    // Start the gRPC server (same backend, keys and handlers as the HTTP API)
+    let grpc_addr = std::env::var("GRPC_ADDR")
+        .unwrap_or_else(|_| grpc::DEFAULT_GRPC_ADDR.to_string())
+        .parse()?;
+    let grpc_service = grpc::GrpcService::new(queue.clone(), Arc::new(api_config.clone()));
+    tokio::spawn(async move {
+        if let Err(e) = grpc_service.serve(grpc_addr).await {
+            tracing::error!(error = %e, "gRPC server stopped");
+        }
+    });
+    info!("🛰️  gRPC server listening on {} (ironforge.v1.JobService)", grpc_addr);
+
+    let api_router = api::create_router_with_config(queue.clone(), api_config);
 
     // Create metrics endpoint
     let metrics_router = Router::new().route(
@@ -45,14 +106,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .unwrap_or_else(|_| "127.0.0.1:3000".to_string());
 
     info!("🚀 Server listening on http://{}", addr);
-    info!("📍 API endpoints:");
+    info!("📍 API endpoints (under /v1, unprefixed paths are deprecated aliases):");
     info!("   POST   /jobs           - Create job");
+    info!("   GET    /jobs           - List/search jobs");
+    info!("   POST   /jobs/batch     - Create jobs in batch");
+    info!("   GET    /batches/:id    - Batch progress");
     info!("   GET    /jobs/:id       - Get job");
     info!("   DELETE /jobs/:id       - Delete job");
     info!("   POST   /jobs/:id/retry - Retry job from DLQ");
+    info!("   GET    /jobs/:id/events - Job event stream (SSE)");
+    info!("   GET    /events         - Event stream filtered by kind/queue (SSE)");
+    info!("   GET    /dlq            - List DLQ jobs with failure reasons");
+    info!("   POST   /dlq/requeue|purge - Bulk requeue/purge DLQ jobs");
+    info!("   POST   /workflows      - Create workflow (chain, group, chord)");
+    info!("   GET    /workflows/:id  - Workflow aggregate status");
+    info!("   GET    /schedules      - List schedules (POST to create)");
+    info!("   GET    /schedules/:id  - Get schedule (PUT to update, DELETE to remove)");
     info!("   GET    /queues/stats   - Queue statistics");
-    info!("   GET    /health         - Health check");
+    info!("   POST   /queues/:name/pause|resume - Pause/resume a queue");
+    info!("   POST   /kinds/:kind/pause|resume  - Pause/resume a job kind");
+    info!("   GET    /workers        - List live workers");
+    info!("   GET    /workers/:id    - Get worker");
+    info!("📍 Unversioned endpoints:");
+    info!("   GET    /health/live    - Liveness probe");
+    info!("   GET    /health/ready   - Readiness probe");
     info!("   GET    /metrics        - Prometheus metrics");
+    info!("   GET    /openapi.json   - OpenAPI specification");
+    info!("   GET    /docs           - Interactive API docs");
+    info!("   GET    /dashboard/     - Web dashboard");
 
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     axum::serve(listener, app).await?;
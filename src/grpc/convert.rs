@@ -0,0 +1,330 @@
+use axum::http::StatusCode;
+use chrono::{DateTime, Utc};
+use tonic::{metadata::MetadataValue, Code, Status};
+use uuid::Uuid;
+
+use super::proto;
+use crate::api::{handlers::QueueStatsResponse, ApiError, ErrorCode};
+use crate::models::{
+    AttemptOutcome, CreateJobRequest, DependencyFailurePolicy, Job, JobAttempt, JobEvent,
+    JobEventData, JobStatus, Priority, UniqueOptions, UniquePolicy, UniqueScope,
+};
+
+/// Métadonnée portant le code d'erreur stable (même valeur que `code` en HTTP)
+pub const ERROR_CODE_METADATA: &str = "ironforge-error-code";
+
+/// Convertit une erreur de l'API en statut gRPC
+pub fn status(error: ApiError) -> Status {
+    let code = match (error.status, error.code) {
+        (_, ErrorCode::JobAlreadyExists) => Code::AlreadyExists,
+        (_, ErrorCode::InvalidJobState | ErrorCode::InvalidStatusTransition) => Code::FailedPrecondition,
+        (StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY, _) => Code::InvalidArgument,
+        (StatusCode::UNAUTHORIZED, _) => Code::Unauthenticated,
+        (StatusCode::FORBIDDEN, _) => Code::PermissionDenied,
+        (StatusCode::NOT_FOUND, _) => Code::NotFound,
+        (StatusCode::CONFLICT, _) => Code::Aborted,
+        (StatusCode::TOO_MANY_REQUESTS, _) => Code::ResourceExhausted,
+        (StatusCode::SERVICE_UNAVAILABLE, _) => Code::Unavailable,
+        (StatusCode::GATEWAY_TIMEOUT, _) => Code::DeadlineExceeded,
+        _ => Code::Internal,
+    };
+
+    let mut message = match &error.detail {
+        Some(detail) => format!("{}: {}", error.title, detail),
+        None => error.title.clone(),
+    };
+    for violation in &error.errors {
+        message.push_str(&format!("; {} {}", violation.field, violation.message));
+    }
+
+    let mut status = Status::new(code, message);
+    if let Ok(value) = MetadataValue::try_from(error.code.as_str()) {
+        status.metadata_mut().insert(ERROR_CODE_METADATA, value);
+    }
+    status
+}
+
+/// Identifiant reçu dans une requête gRPC
+pub fn parse_id(id: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(id).map_err(|_| Status::invalid_argument(format!("invalid id: {}", id)))
+}
+
+fn parse_json(field: &str, json: &str) -> Result<serde_json::Value, Status> {
+    if json.is_empty() {
+        return Ok(serde_json::Value::Null);
+    }
+    serde_json::from_str(json).map_err(|e| Status::invalid_argument(format!("invalid {}: {}", field, e)))
+}
+
+fn to_json(value: &serde_json::Value) -> String {
+    value.to_string()
+}
+
+pub fn timestamp(date: DateTime<Utc>) -> prost_types::Timestamp {
+    prost_types::Timestamp {
+        seconds: date.timestamp(),
+        nanos: date.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn parse_timestamp(field: &str, timestamp: prost_types::Timestamp) -> Result<DateTime<Utc>, Status> {
+    u32::try_from(timestamp.nanos)
+        .ok()
+        .and_then(|nanos| DateTime::from_timestamp(timestamp.seconds, nanos))
+        .ok_or_else(|| Status::invalid_argument(format!("invalid {}", field)))
+}
+
+impl TryFrom<proto::CreateJobRequest> for CreateJobRequest {
+    type Error = Status;
+
+    fn try_from(req: proto::CreateJobRequest) -> Result<Self, Status> {
+        let max_retries = req
+            .max_retries
+            .map(|retries| {
+                u8::try_from(retries)
+                    .map_err(|_| Status::invalid_argument(format!("invalid max_retries: {}", retries)))
+            })
+            .transpose()?;
+        let depends_on = req
+            .depends_on
+            .iter()
+            .map(|id| parse_id(id))
+            .collect::<Result<Vec<_>, _>>()?;
+        let scheduled_for = req
+            .scheduled_for
+            .map(|at| parse_timestamp("scheduled_for", at))
+            .transpose()?;
+
+        Ok(CreateJobRequest {
+            payload: parse_json("payload_json", &req.payload_json)?,
+            priority: priority(req.priority()),
+            on_dependency_failure: match req.on_dependency_failure() {
+                proto::DependencyFailurePolicy::Unspecified => None,
+                proto::DependencyFailurePolicy::Cancel => Some(DependencyFailurePolicy::Cancel),
+                proto::DependencyFailurePolicy::Fail => Some(DependencyFailurePolicy::Fail),
+            },
+            unique: req.unique.map(unique_options),
+            kind: req.kind,
+            queue: req.queue,
+            max_retries,
+            timeout_ms: req.timeout_ms,
+            metadata: req.metadata,
+            depends_on,
+            callback_url: req.callback_url,
+            scheduled_for,
+            idempotency_key: req.idempotency_key,
+        })
+    }
+}
+
+fn priority(priority: proto::Priority) -> Option<Priority> {
+    match priority {
+        proto::Priority::Unspecified => None,
+        proto::Priority::Critical => Some(Priority::Critical),
+        proto::Priority::High => Some(Priority::High),
+        proto::Priority::Medium => Some(Priority::Medium),
+        proto::Priority::Low => Some(Priority::Low),
+    }
+}
+
+fn unique_options(options: proto::UniqueOptions) -> UniqueOptions {
+    UniqueOptions {
+        scope: match options.scope() {
+            proto::UniqueScope::Unspecified | proto::UniqueScope::Queued => UniqueScope::Queued,
+            proto::UniqueScope::Active => UniqueScope::Active,
+            proto::UniqueScope::Window => UniqueScope::Window,
+        },
+        on_conflict: match options.on_conflict() {
+            proto::UniquePolicy::Unspecified | proto::UniquePolicy::Drop => UniquePolicy::Drop,
+            proto::UniquePolicy::Merge => UniquePolicy::Merge,
+        },
+        key: options.key,
+        window_secs: options.window_secs,
+    }
+}
+
+impl From<Priority> for proto::Priority {
+    fn from(priority: Priority) -> Self {
+        match priority {
+            Priority::Critical => proto::Priority::Critical,
+            Priority::High => proto::Priority::High,
+            Priority::Medium => proto::Priority::Medium,
+            Priority::Low => proto::Priority::Low,
+        }
+    }
+}
+
+impl From<JobStatus> for proto::JobStatus {
+    fn from(status: JobStatus) -> Self {
+        match status {
+            JobStatus::Waiting => proto::JobStatus::Waiting,
+            JobStatus::Queued => proto::JobStatus::Queued,
+            JobStatus::Running => proto::JobStatus::Running,
+            JobStatus::Completed => proto::JobStatus::Completed,
+            JobStatus::Failed => proto::JobStatus::Failed,
+            JobStatus::DeadLetter => proto::JobStatus::DeadLetter,
+            JobStatus::Cancelled => proto::JobStatus::Cancelled,
+        }
+    }
+}
+
+impl From<UniquePolicy> for proto::UniquePolicy {
+    fn from(policy: UniquePolicy) -> Self {
+        match policy {
+            UniquePolicy::Drop => proto::UniquePolicy::Drop,
+            UniquePolicy::Merge => proto::UniquePolicy::Merge,
+        }
+    }
+}
+
+impl From<AttemptOutcome> for proto::AttemptOutcome {
+    fn from(outcome: AttemptOutcome) -> Self {
+        match outcome {
+            AttemptOutcome::Running => proto::AttemptOutcome::Running,
+            AttemptOutcome::Completed => proto::AttemptOutcome::Completed,
+            AttemptOutcome::Failed => proto::AttemptOutcome::Failed,
+            AttemptOutcome::TimedOut => proto::AttemptOutcome::TimedOut,
+        }
+    }
+}
+
+impl From<JobAttempt> for proto::JobAttempt {
+    fn from(attempt: JobAttempt) -> Self {
+        proto::JobAttempt {
+            attempt: attempt.attempt,
+            worker_id: attempt.worker_id,
+            started_at: Some(timestamp(attempt.started_at)),
+            finished_at: attempt.finished_at.map(timestamp),
+            outcome: proto::AttemptOutcome::from(attempt.outcome).into(),
+            error: attempt.error,
+            duration_ms: attempt.duration_ms,
+        }
+    }
+}
+
+impl From<Job> for proto::Job {
+    fn from(job: Job) -> Self {
+        proto::Job {
+            id: job.id.to_string(),
+            payload_json: to_json(&job.payload),
+            priority: proto::Priority::from(job.priority).into(),
+            status: proto::JobStatus::from(job.status).into(),
+            max_retries: job.max_retries.into(),
+            retry_count: job.retry_count.into(),
+            created_at: Some(timestamp(job.created_at)),
+            scheduled_for: job.scheduled_for.map(timestamp),
+            depends_on: job.depends_on.iter().map(Uuid::to_string).collect(),
+            result_json: job.result.as_ref().map(to_json),
+            workflow_id: job.workflow_id.map(|id| id.to_string()),
+            batch_id: job.batch_id.map(|id| id.to_string()),
+            kind: job.kind,
+            queue: job.queue,
+            timeout_ms: job.timeout_ms,
+            metadata: job.metadata,
+            last_error: job.last_error,
+            callback_url: job.callback_url,
+            submitted_by: job.submitted_by,
+            tenant: job.tenant,
+            attempts: job.attempts.into_iter().map(Into::into).collect(),
+            version: job.version,
+        }
+    }
+}
+
+impl From<JobEvent> for proto::JobEvent {
+    fn from(event: JobEvent) -> Self {
+        let data = match event.data {
+            JobEventData::Status { status, result, error } => {
+                proto::job_event::Data::Status(proto::StatusChange {
+                    status: proto::JobStatus::from(status).into(),
+                    result_json: result.as_ref().map(to_json),
+                    error,
+                })
+            }
+            JobEventData::Progress { progress } => proto::job_event::Data::Progress(proto::Progress {
+                progress_json: to_json(&progress),
+            }),
+        };
+
+        proto::JobEvent {
+            job_id: event.job_id.to_string(),
+            kind: event.kind,
+            queue: event.queue,
+            tenant: event.tenant,
+            timestamp: Some(timestamp(event.timestamp)),
+            data: Some(data),
+        }
+    }
+}
+
+impl From<QueueStatsResponse> for proto::QueueStats {
+    fn from(stats: QueueStatsResponse) -> Self {
+        proto::QueueStats {
+            queue_depth: stats.queue_depth,
+            dlq_depth: stats.dlq_depth,
+            active_jobs: stats.active_jobs,
+            parked_jobs: stats.parked_jobs,
+            delayed_jobs: stats.delayed_jobs,
+            total_jobs: stats.total_jobs,
+            paused_queues: stats.paused_queues,
+            paused_kinds: stats.paused_kinds,
+            tenant_queue_depths: stats.tenant_queue_depths,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_job_request_conversion() {
+        let request = proto::CreateJobRequest {
+            kind: "email.send".to_string(),
+            payload_json: r#"{"to":"a@b.c"}"#.to_string(),
+            priority: proto::Priority::High.into(),
+            max_retries: Some(5),
+            depends_on: vec![Uuid::nil().to_string()],
+            unique: Some(proto::UniqueOptions {
+                key: Some("user-42".to_string()),
+                on_conflict: proto::UniquePolicy::Merge.into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let converted = CreateJobRequest::try_from(request.clone()).unwrap();
+        assert_eq!(converted.payload["to"], "a@b.c");
+        assert_eq!(converted.priority, Some(Priority::High));
+        assert_eq!(converted.max_retries, Some(5));
+        assert_eq!(converted.depends_on, [Uuid::nil()]);
+        assert_eq!(converted.on_dependency_failure, None);
+        let unique = converted.unique.unwrap();
+        assert_eq!(unique.scope, UniqueScope::Queued);
+        assert_eq!(unique.on_conflict, UniquePolicy::Merge);
+
+        let invalid = proto::CreateJobRequest {
+            max_retries: Some(300),
+            ..request.clone()
+        };
+        assert_eq!(CreateJobRequest::try_from(invalid).unwrap_err().code(), Code::InvalidArgument);
+        let invalid = proto::CreateJobRequest {
+            payload_json: "{".to_string(),
+            ..request
+        };
+        assert_eq!(CreateJobRequest::try_from(invalid).unwrap_err().code(), Code::InvalidArgument);
+    }
+
+    #[test]
+    fn test_api_error_to_status() {
+        let not_found = status(ApiError::from(crate::models::IronForgeError::JobNotFound("42".to_string())));
+        assert_eq!(not_found.code(), Code::NotFound);
+        assert_eq!(not_found.metadata().get(ERROR_CODE_METADATA).unwrap(), "job_not_found");
+
+        let state = status(ApiError::new(StatusCode::CONFLICT, "Cannot delete").with_code(ErrorCode::InvalidJobState));
+        assert_eq!(state.code(), Code::FailedPrecondition);
+
+        let quota = status(ApiError::new(StatusCode::TOO_MANY_REQUESTS, "Quota exceeded"));
+        assert_eq!(quota.code(), Code::ResourceExhausted);
+    }
+}
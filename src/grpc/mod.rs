@@ -0,0 +1,12 @@
+// `tonic::Status` est imposé par les services générés, quelle que soit sa taille
+#![allow(clippy::result_large_err)]
+
+mod convert;
+mod service;
+
+/// Types et services générés depuis `proto/ironforge.proto`
+pub mod proto {
+    tonic::include_proto!("ironforge.v1");
+}
+
+pub use service::{GrpcService, DEFAULT_GRPC_ADDR};
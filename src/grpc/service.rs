@@ -0,0 +1,168 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    Json,
+};
+use futures::stream::{BoxStream, StreamExt};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tonic::{Request, Response, Status};
+
+use super::convert::{parse_id, status, timestamp};
+use super::proto::{
+    self,
+    job_service_server::{JobService, JobServiceServer},
+};
+use crate::api::{
+    auth::{authenticate, API_KEY_HEADER},
+    error::{ApiJson, ApiPath},
+    handlers::{self, RetryJobRequest},
+    ApiConfig, Caller, Scope,
+};
+use crate::models::CreateJobRequest;
+use crate::queue::QueueBackend;
+
+/// Adresse d'écoute par défaut du serveur gRPC
+pub const DEFAULT_GRPC_ADDR: &str = "127.0.0.1:50051";
+
+/// Service gRPC `ironforge.v1.JobService`.
+///
+/// Chaque méthode délègue au handler HTTP correspondant : validation, quotas,
+/// idempotence et contrôle d'accès sont identiques sur les deux API.
+pub struct GrpcService<Q: QueueBackend> {
+    queue: Arc<Q>,
+    config: Arc<ApiConfig>,
+}
+
+impl<Q: QueueBackend + 'static> GrpcService<Q> {
+    pub fn new(queue: Arc<Q>, config: Arc<ApiConfig>) -> Self {
+        Self { queue, config }
+    }
+
+    /// Sert l'API gRPC sur `addr` jusqu'à l'arrêt du serveur
+    pub async fn serve(self, addr: SocketAddr) -> Result<(), tonic::transport::Error> {
+        tonic::transport::Server::builder()
+            .add_service(JobServiceServer::new(self))
+            .serve(addr)
+            .await
+    }
+
+    /// Authentifie l'appel avec les clés de l'API HTTP
+    fn caller<T>(&self, request: &Request<T>, scope: Scope) -> Result<Caller, Status> {
+        let metadata = request.metadata();
+        let token = metadata
+            .get("authorization")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .or_else(|| metadata.get(API_KEY_HEADER).and_then(|value| value.to_str().ok()));
+
+        authenticate(&self.config, token, scope).map_err(status)
+    }
+}
+
+#[tonic::async_trait]
+impl<Q: QueueBackend + 'static> JobService for GrpcService<Q> {
+    async fn create_job(
+        &self,
+        request: Request<proto::CreateJobRequest>,
+    ) -> Result<Response<proto::CreateJobResponse>, Status> {
+        let caller = self.caller(&request, Scope::JobsWrite)?;
+        let req = CreateJobRequest::try_from(request.into_inner())?;
+
+        let (code, Json(created)) = handlers::create_job(
+            State(self.queue.clone()),
+            State(self.config.clone()),
+            caller,
+            HeaderMap::new(),
+            ApiJson(req),
+        )
+        .await
+        .map_err(status)?;
+
+        Ok(Response::new(proto::CreateJobResponse {
+            id: created.id.to_string(),
+            status: proto::JobStatus::from(created.status).into(),
+            created_at: Some(timestamp(created.created_at)),
+            created: code == StatusCode::CREATED,
+            deduplicated: created
+                .deduplicated
+                .map(proto::UniquePolicy::from)
+                .unwrap_or_default()
+                .into(),
+        }))
+    }
+
+    async fn get_job(&self, request: Request<proto::GetJobRequest>) -> Result<Response<proto::Job>, Status> {
+        let caller = self.caller(&request, Scope::JobsRead)?;
+        let job_id = parse_id(&request.get_ref().id)?;
+
+        let Json(job) = handlers::get_job(State(self.queue.clone()), caller, ApiPath(job_id))
+            .await
+            .map_err(status)?;
+
+        Ok(Response::new(job.into()))
+    }
+
+    async fn delete_job(
+        &self,
+        request: Request<proto::DeleteJobRequest>,
+    ) -> Result<Response<proto::DeleteJobResponse>, Status> {
+        let caller = self.caller(&request, Scope::Admin)?;
+        let job_id = parse_id(&request.get_ref().id)?;
+
+        handlers::delete_job(State(self.queue.clone()), caller, ApiPath(job_id))
+            .await
+            .map_err(status)?;
+
+        Ok(Response::new(proto::DeleteJobResponse {}))
+    }
+
+    async fn retry_job(
+        &self,
+        request: Request<proto::RetryJobRequest>,
+    ) -> Result<Response<proto::RetryJobResponse>, Status> {
+        let caller = self.caller(&request, Scope::Dlq)?;
+        let job_id = parse_id(&request.get_ref().id)?;
+        let req = RetryJobRequest {
+            reset_retry_count: request.get_ref().reset_retry_count,
+        };
+
+        let (_, Json(body)) = handlers::retry_job(State(self.queue.clone()), caller, ApiPath(job_id), ApiJson(req))
+            .await
+            .map_err(status)?;
+
+        Ok(Response::new(proto::RetryJobResponse {
+            id: job_id.to_string(),
+            retry_count: body["retry_count"].as_u64().unwrap_or_default() as u32,
+        }))
+    }
+
+    async fn get_stats(
+        &self,
+        request: Request<proto::GetStatsRequest>,
+    ) -> Result<Response<proto::QueueStats>, Status> {
+        let caller = self.caller(&request, Scope::JobsRead)?;
+
+        let Json(stats) = handlers::queue_stats(State(self.queue.clone()), caller)
+            .await
+            .map_err(status)?;
+
+        Ok(Response::new(stats.into()))
+    }
+
+    type WatchJobStream = BoxStream<'static, Result<proto::JobEvent, Status>>;
+
+    async fn watch_job(
+        &self,
+        request: Request<proto::WatchJobRequest>,
+    ) -> Result<Response<Self::WatchJobStream>, Status> {
+        let caller = self.caller(&request, Scope::JobsRead)?;
+        let job_id = parse_id(&request.get_ref().id)?;
+
+        let events = handlers::job_event_stream(self.queue.as_ref(), &caller, job_id)
+            .await
+            .map_err(status)?;
+
+        Ok(Response::new(events.map(|event| Ok(event.into())).boxed()))
+    }
+}
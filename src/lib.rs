@@ -1,9 +1,14 @@
 pub mod models;
 pub mod queue;
 pub mod worker;
+pub mod scheduler;
+pub mod webhook;
 pub mod api;
 pub mod metrics;
+pub mod grpc;
 
 pub use models::{Job, Priority, JobStatus, CreateJobRequest, CreateJobResponse};
 pub use queue::{QueueBackend, RedisQueueBackend, QueueStats};
-pub use worker::{JobHandler, Executor, ExecutorConfig};
+pub use worker::{JobHandler, Executor, ExecutorConfig, ProgressReporter, Reaper, ReaperConfig};
+pub use scheduler::{Scheduler, SchedulerConfig};
+pub use webhook::{WebhookConfig, WebhookDispatcher};
@@ -1,9 +1,18 @@
 use metrics::{counter, gauge, histogram, describe_counter, describe_gauge, describe_histogram};
 use metrics_exporter_prometheus::{Matcher, PrometheusBuilder, PrometheusHandle};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 
 use crate::models::Priority;
 
+/// Positionné une fois le recorder Prometheus installé
+static RECORDER_INSTALLED: AtomicBool = AtomicBool::new(false);
+
+/// Indique si les métriques sont collectées (recorder installé par `init_metrics`)
+pub fn is_installed() -> bool {
+    RECORDER_INSTALLED.load(Ordering::Relaxed)
+}
+
 /// Initialise les métriques Prometheus
 pub fn init_metrics() -> PrometheusHandle {
     // Descriptions des métriques
@@ -63,9 +72,12 @@ pub fn init_metrics() -> PrometheusHandle {
         )
         .unwrap();
 
-    builder
+    let handle = builder
         .install_recorder()
-        .expect("Failed to install Prometheus recorder")
+        .expect("Failed to install Prometheus recorder");
+    RECORDER_INSTALLED.store(true, Ordering::Relaxed);
+
+    handle
 }
 
 /// Enregistre une soumission de job
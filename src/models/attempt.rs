@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+use super::job::Job;
+
+/// Nombre maximum de tentatives conservées dans l'historique d'un job
+pub const MAX_ATTEMPTS_HISTORY: usize = 20;
+
+/// Issue d'une tentative d'exécution
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptOutcome {
+    /// Tentative en cours (ou interrompue par l'arrêt du worker)
+    Running,
+    Completed,
+    Failed,
+    TimedOut,
+}
+
+/// Trace d'une tentative d'exécution d'un job par un worker
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct JobAttempt {
+    /// Numéro de la tentative (à partir de 1)
+    pub attempt: u32,
+
+    /// Instance et worker ayant exécuté la tentative (`{instance}/{worker}`)
+    pub worker_id: String,
+
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub outcome: AttemptOutcome,
+    pub error: Option<String>,
+
+    /// Durée d'exécution du handler en millisecondes
+    pub duration_ms: Option<u64>,
+}
+
+impl Job {
+    /// Ouvre une nouvelle tentative, en ne conservant que les plus récentes
+    pub fn start_attempt(&mut self, worker_id: String, now: DateTime<Utc>) {
+        let attempt = self.attempts.last().map_or(1, |last| last.attempt + 1);
+
+        self.attempts.push(JobAttempt {
+            attempt,
+            worker_id,
+            started_at: now,
+            finished_at: None,
+            outcome: AttemptOutcome::Running,
+            error: None,
+            duration_ms: None,
+        });
+
+        if self.attempts.len() > MAX_ATTEMPTS_HISTORY {
+            let excess = self.attempts.len() - MAX_ATTEMPTS_HISTORY;
+            self.attempts.drain(..excess);
+        }
+    }
+
+    /// Reporte sur ce job (relu depuis le backend) les champs mis à jour par
+    /// son exécution : tentatives, compteur de retries, résultat et dernière erreur
+    pub fn absorb_execution(&mut self, executed: &Job) {
+        self.attempts = executed.attempts.clone();
+        self.retry_count = executed.retry_count;
+        self.result = executed.result.clone();
+        self.last_error = executed.last_error.clone();
+    }
+
+    /// Clôt la tentative en cours avec son issue
+    pub fn finish_attempt(&mut self, outcome: AttemptOutcome, error: Option<String>, now: DateTime<Utc>) {
+        let Some(current) = self.attempts.last_mut() else {
+            return;
+        };
+
+        let elapsed = (now - current.started_at).num_milliseconds().max(0) as u64;
+        current.finished_at = Some(now);
+        current.outcome = outcome;
+        current.error = error;
+        current.duration_ms = Some(elapsed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_attempts_are_numbered_and_bounded() {
+        let mut job = Job::new("test".to_string(), serde_json::json!({}));
+        let start = Utc::now();
+
+        job.start_attempt("host/0".to_string(), start);
+        job.finish_attempt(
+            AttemptOutcome::Failed,
+            Some("boom".to_string()),
+            start + Duration::milliseconds(250),
+        );
+
+        let first = &job.attempts[0];
+        assert_eq!(first.attempt, 1);
+        assert_eq!(first.outcome, AttemptOutcome::Failed);
+        assert_eq!(first.error.as_deref(), Some("boom"));
+        assert_eq!(first.duration_ms, Some(250));
+
+        for _ in 0..MAX_ATTEMPTS_HISTORY + 5 {
+            job.start_attempt("host/1".to_string(), start);
+        }
+
+        assert_eq!(job.attempts.len(), MAX_ATTEMPTS_HISTORY);
+        assert_eq!(job.attempts.last().unwrap().attempt, MAX_ATTEMPTS_HISTORY as u32 + 6);
+        assert_eq!(job.attempts.last().unwrap().outcome, AttemptOutcome::Running);
+    }
+}
@@ -20,6 +20,16 @@ pub enum IronForgeError {
         to: crate::models::JobStatus,
     },
     
+    #[error("Job cannot be modified in status {0:?}")]
+    InvalidJobState(crate::models::JobStatus),
+    
+    #[error("Job {job_id} was modified concurrently (expected version {expected}, found {actual})")]
+    VersionConflict {
+        job_id: String,
+        expected: u64,
+        actual: u64,
+    },
+    
     #[error("Timeout exceeded")]
     Timeout,
     
@@ -28,6 +38,29 @@ pub enum IronForgeError {
     
     #[error("Worker error: {0}")]
     Worker(String),
+    
+    /// Échec définitif signalé par un handler : le job passe en Failed sans retry
+    #[error("Permanent failure: {0}")]
+    PermanentFailure(String),
+    
+    #[error("Invalid schedule: {0}")]
+    InvalidSchedule(String),
+    
+    #[error("Invalid query: {0}")]
+    InvalidQuery(String),
+    
+    #[error("Idempotency key reused with a different payload: {0}")]
+    IdempotencyKeyConflict(String),
+    
+    #[error("Invalid configuration: {0}")]
+    Config(String),
+    
+    #[error("Quota exceeded: {0}")]
+    QuotaExceeded(String),
+    
+    /// Le bail de leader est détenu par une autre instance
+    #[error("Leadership lost: {0}")]
+    LeadershipLost(String),
 }
 
 pub type Result<T> = std::result::Result<T, IronForgeError>;
@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+use super::job::{Job, JobStatus};
+
+/// Contenu d'un événement de job
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum JobEventData {
+    /// Nouvel état du job (avec son résultat ou sa dernière erreur)
+    Status {
+        status: JobStatus,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        result: Option<serde_json::Value>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        error: Option<String>,
+    },
+    /// Avancement signalé par le handler
+    Progress { progress: serde_json::Value },
+}
+
+/// Événement publié à chaque écriture d'un job ou avancement signalé
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct JobEvent {
+    pub job_id: Uuid,
+    pub kind: String,
+    pub queue: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+
+    #[serde(flatten)]
+    pub data: JobEventData,
+
+    pub timestamp: DateTime<Utc>,
+}
+
+impl JobEvent {
+    /// Événement d'état reflétant le job tel qu'il vient d'être écrit
+    pub fn status(job: &Job) -> Self {
+        let error = match job.status {
+            JobStatus::Failed | JobStatus::DeadLetter => job.last_error.clone(),
+            _ => None,
+        };
+
+        Self::new(
+            job,
+            JobEventData::Status {
+                status: job.status,
+                result: job.result.clone(),
+                error,
+            },
+        )
+    }
+
+    /// Événement d'avancement
+    pub fn progress(job: &Job, progress: serde_json::Value) -> Self {
+        Self::new(job, JobEventData::Progress { progress })
+    }
+
+    fn new(job: &Job, data: JobEventData) -> Self {
+        Self {
+            job_id: job.id,
+            kind: job.kind.clone(),
+            queue: job.queue.clone(),
+            tenant: job.tenant.clone(),
+            data,
+            timestamp: Utc::now(),
+        }
+    }
+
+    /// Nom de l'événement SSE ("status" ou "progress")
+    pub fn name(&self) -> &'static str {
+        match self.data {
+            JobEventData::Status { .. } => "status",
+            JobEventData::Progress { .. } => "progress",
+        }
+    }
+
+    /// Indique si le job ne produira plus d'événement
+    pub fn is_final(&self) -> bool {
+        matches!(&self.data, JobEventData::Status { status, .. } if status.is_terminal())
+    }
+}
+
+/// Critères de sélection des événements d'un flux
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    pub job_id: Option<Uuid>,
+    pub kind: Option<String>,
+    pub queue: Option<String>,
+}
+
+impl EventFilter {
+    /// Indique si l'événement satisfait tous les critères
+    pub fn matches(&self, event: &JobEvent) -> bool {
+        self.job_id.is_none_or(|job_id| event.job_id == job_id)
+            && self.kind.as_ref().is_none_or(|kind| &event.kind == kind)
+            && self.queue.as_ref().is_none_or(|queue| &event.queue == queue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_serialization_and_filter() {
+        let mut job = Job::new("report.generate".to_string(), serde_json::json!({}));
+        job.status = JobStatus::Completed;
+        job.result = Some(serde_json::json!({ "rows": 3 }));
+
+        let event = JobEvent::status(&job);
+        assert_eq!(event.name(), "status");
+        assert!(event.is_final());
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "status");
+        assert_eq!(json["status"], "completed");
+        assert_eq!(json["result"]["rows"], 3);
+        assert!(json.get("error").is_none());
+        assert_eq!(serde_json::from_value::<JobEvent>(json).unwrap(), event);
+
+        let progress = JobEvent::progress(&job, serde_json::json!(50));
+        assert!(!progress.is_final());
+
+        let filter = EventFilter {
+            kind: Some("report.generate".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&progress));
+
+        let filter = EventFilter {
+            queue: Some("other".to_string()),
+            ..Default::default()
+        };
+        assert!(!filter.matches(&progress));
+    }
+}
@@ -1,10 +1,24 @@
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use utoipa::ToSchema;
+
+use super::unique::{UniqueLock, UniqueOptions, UniquePolicy};
+use super::webhook::{validate_callback_url, WebhookDelivery};
+use super::attempt::JobAttempt;
+use super::error::{IronForgeError, Result};
+
+/// Nom de la queue utilisée quand aucune n'est précisée
+pub const DEFAULT_QUEUE: &str = "default";
+
+fn default_queue() -> String {
+    DEFAULT_QUEUE.to_string()
+}
 
 /// Niveaux de priorité pour les jobs
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum Priority {
     Critical,
@@ -26,18 +40,122 @@ impl Priority {
 }
 
 /// États possibles d'un job
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum JobStatus {
+    /// En attente de la complétion de ses dépendances
+    Waiting,
     Queued,
     Running,
     Completed,
     Failed,
     DeadLetter,
+    /// Annulé (ex: une dépendance a échoué)
+    Cancelled,
+}
+
+impl JobStatus {
+    /// Tous les statuts possibles
+    pub const ALL: [JobStatus; 7] = [
+        JobStatus::Waiting,
+        JobStatus::Queued,
+        JobStatus::Running,
+        JobStatus::Completed,
+        JobStatus::Failed,
+        JobStatus::DeadLetter,
+        JobStatus::Cancelled,
+    ];
+
+    /// Nom du statut tel que sérialisé (ex: "deadletter")
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Waiting => "waiting",
+            JobStatus::Queued => "queued",
+            JobStatus::Running => "running",
+            JobStatus::Completed => "completed",
+            JobStatus::Failed => "failed",
+            JobStatus::DeadLetter => "deadletter",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    /// Indique si le job ne changera plus d'état de lui-même
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            JobStatus::Completed | JobStatus::Failed | JobStatus::DeadLetter | JobStatus::Cancelled
+        )
+    }
+
+    /// Indique si un job peut passer de ce statut à `next`.
+    ///
+    /// Failed est atteint par un échec définitif (`PermanentFailure` du handler)
+    /// ou par l'échec d'une dépendance ; DeadLetter par l'épuisement des retries.
+    /// Un job Running ne peut pas être annulé : son handler n'est pas interruptible.
+    pub fn can_transition_to(&self, next: JobStatus) -> bool {
+        use JobStatus::*;
+
+        matches!(
+            (self, next),
+            (Waiting, Queued | Cancelled | Failed)
+                | (Queued, Running | Cancelled)
+                | (Running, Completed | Queued | Failed | DeadLetter)
+                | (Failed | DeadLetter, Queued)
+        )
+    }
+}
+
+/// Comportement d'un job dépendant quand un parent échoue
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DependencyFailurePolicy {
+    /// Le job dépendant passe en Cancelled
+    #[default]
+    Cancel,
+    /// Le job dépendant passe en Failed
+    Fail,
+}
+
+impl DependencyFailurePolicy {
+    /// Statut appliqué au job dépendant
+    pub fn status(&self) -> JobStatus {
+        match self {
+            DependencyFailurePolicy::Cancel => JobStatus::Cancelled,
+            DependencyFailurePolicy::Fail => JobStatus::Failed,
+        }
+    }
+}
+
+/// État des dépendances d'un job
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyState {
+    /// Tous les parents sont complétés
+    Ready,
+    /// Au moins un parent n'est pas encore terminé
+    Pending,
+    /// Au moins un parent a échoué (ou n'existe plus)
+    Failed,
+}
+
+impl DependencyState {
+    /// Évalue l'état à partir du statut des parents (`None` = parent introuvable)
+    pub fn evaluate(parents: &[Option<JobStatus>]) -> Self {
+        let mut state = DependencyState::Ready;
+
+        for parent in parents {
+            match parent {
+                Some(JobStatus::Completed) => {}
+                Some(status) if !status.is_terminal() => state = DependencyState::Pending,
+                _ => return DependencyState::Failed,
+            }
+        }
+
+        state
+    }
 }
 
 /// Structure principale d'un job
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Job {
     /// Identifiant unique du job
     pub id: Uuid,
@@ -45,6 +163,10 @@ pub struct Job {
     /// Type de job (ex: "email.send", "report.generate")
     pub kind: String,
     
+    /// Queue logique du job (ex: "default", "emails")
+    #[serde(default = "default_queue")]
+    pub queue: String,
+    
     /// Données JSON arbitraires du job
     pub payload: serde_json::Value,
     
@@ -71,6 +193,63 @@ pub struct Job {
     
     /// Métadonnées personnalisées (tags, etc.)
     pub metadata: HashMap<String, String>,
+    
+    /// Jobs qui doivent être complétés avant l'exécution de celui-ci
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    
+    /// Comportement si une dépendance échoue
+    #[serde(default)]
+    pub on_dependency_failure: DependencyFailurePolicy,
+    
+    /// Résultats des dépendances (dans l'ordre de `depends_on`), renseignés au déblocage
+    #[serde(default)]
+    pub dependency_results: Vec<serde_json::Value>,
+    
+    /// Résultat retourné par le handler
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    
+    /// Raison du dernier échec (erreur du handler ou timeout)
+    #[serde(default)]
+    pub last_error: Option<String>,
+    
+    /// Workflow auquel appartient le job
+    #[serde(default)]
+    pub workflow_id: Option<Uuid>,
+    
+    /// Lot de soumission auquel appartient le job
+    #[serde(default)]
+    pub batch_id: Option<Uuid>,
+    
+    /// Verrou d'unicité (doublons ignorés ou fusionnés)
+    #[serde(default)]
+    pub unique: Option<UniqueLock>,
+    
+    /// URL notifiée quand le job se termine (completed, failed, deadletter)
+    #[serde(default)]
+    pub callback_url: Option<String>,
+    
+    /// Historique des livraisons du webhook de fin de job
+    #[serde(default)]
+    pub webhook_deliveries: Vec<WebhookDelivery>,
+    
+    /// Nom de la clé d'API qui a soumis le job
+    #[serde(default)]
+    pub submitted_by: Option<String>,
+    
+    /// Tenant propriétaire du job (dérivé de la clé d'API)
+    #[serde(default)]
+    pub tenant: Option<String>,
+    
+    /// Historique des tentatives d'exécution (les plus récentes seulement)
+    #[serde(default)]
+    pub attempts: Vec<JobAttempt>,
+    
+    /// Version du job, incrémentée à chaque écriture conditionnelle
+    /// (contrôle de concurrence optimiste)
+    #[serde(default)]
+    pub version: u64,
 }
 
 impl Job {
@@ -79,6 +258,7 @@ impl Job {
         Self {
             id: Uuid::new_v4(),
             kind,
+            queue: default_queue(),
             payload,
             priority: Priority::Medium,
             status: JobStatus::Queued,
@@ -88,9 +268,40 @@ impl Job {
             scheduled_for: None,
             timeout_ms: 30_000, // 30 secondes par défaut
             metadata: HashMap::new(),
+            depends_on: Vec::new(),
+            on_dependency_failure: DependencyFailurePolicy::default(),
+            dependency_results: Vec::new(),
+            result: None,
+            last_error: None,
+            workflow_id: None,
+            batch_id: None,
+            unique: None,
+            callback_url: None,
+            webhook_deliveries: Vec::new(),
+            submitted_by: None,
+            tenant: None,
+            attempts: Vec::new(),
+            version: 0,
         }
     }
     
+    /// Fait passer le job dans le statut `to` et retourne le statut précédent,
+    /// ou `InvalidStatusTransition` si la transition n'est pas autorisée
+    pub fn transition(&mut self, to: JobStatus) -> Result<JobStatus> {
+        let from = self.status;
+        if !from.can_transition_to(to) {
+            return Err(IronForgeError::InvalidStatusTransition { from, to });
+        }
+
+        self.status = to;
+        Ok(from)
+    }
+    
+    /// Indique si le job doit encore attendre sa date d'exécution
+    pub fn is_delayed(&self, now: DateTime<Utc>) -> bool {
+        self.scheduled_for.is_some_and(|scheduled_for| scheduled_for > now)
+    }
+    
     /// Calcule le délai de backoff exponentiel pour un retry
     pub fn calculate_backoff_delay(&self) -> u64 {
         const BASE_DELAY_MS: u64 = 1000; // 1 seconde
@@ -111,11 +322,14 @@ impl Job {
 }
 
 /// Payload de création d'un job via l'API
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct CreateJobRequest {
     pub kind: String,
     pub payload: serde_json::Value,
     
+    #[serde(default)]
+    pub queue: Option<String>,
+    
     #[serde(default)]
     pub priority: Option<Priority>,
     
@@ -127,14 +341,167 @@ pub struct CreateJobRequest {
     
     #[serde(default)]
     pub metadata: HashMap<String, String>,
+    
+    #[serde(default)]
+    pub depends_on: Vec<Uuid>,
+    
+    #[serde(default)]
+    pub on_dependency_failure: Option<DependencyFailurePolicy>,
+    
+    #[serde(default)]
+    pub unique: Option<UniqueOptions>,
+    
+    /// URL notifiée (POST signé) quand le job se termine
+    #[serde(default)]
+    pub callback_url: Option<String>,
+    
+    /// Date d'exécution au plus tôt (job différé)
+    #[serde(default)]
+    pub scheduled_for: Option<DateTime<Utc>>,
+    
+    /// Clé d'idempotence (alternative au header `Idempotency-Key`)
+    #[serde(default, skip_serializing)]
+    pub idempotency_key: Option<String>,
+}
+
+impl CreateJobRequest {
+    /// Vérifie la cohérence de la requête
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if self.kind.trim().is_empty() {
+            return Err("kind must not be empty".to_string());
+        }
+
+        if self.timeout_ms == Some(0) {
+            return Err("timeout_ms must be greater than 0".to_string());
+        }
+
+        if self.queue.as_deref().is_some_and(|queue| queue.trim().is_empty()) {
+            return Err("queue must not be empty".to_string());
+        }
+
+        if let Some(unique) = &self.unique {
+            unique.validate()?;
+        }
+
+        if let Some(url) = &self.callback_url {
+            validate_callback_url(url)?;
+        }
+
+        Ok(())
+    }
+
+    /// Empreinte du contenu de la requête (hors clé d'idempotence),
+    /// indépendante de l'ordre des champs
+    pub fn fingerprint(&self) -> String {
+        // serde_json::Value trie les clés des objets : sérialisation canonique
+        let canonical = serde_json::to_value(self)
+            .map(|value| value.to_string())
+            .unwrap_or_default();
+
+        hex::encode(Sha256::digest(canonical.as_bytes()))
+    }
+
+    /// Construit le job correspondant à la requête
+    pub fn into_job(self) -> Job {
+        let unique = self
+            .unique
+            .map(|options| options.lock_for(&self.kind, &self.payload));
+        let mut job = Job::new(self.kind, self.payload);
+        job.unique = unique;
+
+        // Appliquer les paramètres optionnels
+        if let Some(queue) = self.queue {
+            job.queue = queue;
+        }
+
+        if let Some(priority) = self.priority {
+            job.priority = priority;
+        }
+
+        if let Some(max_retries) = self.max_retries {
+            job.max_retries = max_retries;
+        }
+
+        if let Some(timeout_ms) = self.timeout_ms {
+            job.timeout_ms = timeout_ms;
+        }
+
+        if let Some(policy) = self.on_dependency_failure {
+            job.on_dependency_failure = policy;
+        }
+
+        // Ajouter les métadonnées et les dépendances
+        job.metadata = self.metadata;
+        job.depends_on = self.depends_on;
+        job.callback_url = self.callback_url;
+        job.scheduled_for = self.scheduled_for;
+
+        job
+    }
+}
+
+/// Modification d'un job en attente (`PATCH /jobs/:id`) : les champs absents
+/// restent inchangés
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateJobRequest {
+    #[serde(default)]
+    pub priority: Option<Priority>,
+    
+    /// Nouvelle date d'exécution au plus tôt (une date passée rend le job exécutable)
+    #[serde(default)]
+    pub scheduled_for: Option<DateTime<Utc>>,
+    
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    
+    /// Métadonnées ajoutées ou remplacées (`null` supprime la clé)
+    #[serde(default)]
+    #[schema(value_type = HashMap<String, Option<String>>)]
+    pub metadata: HashMap<String, Option<String>>,
+}
+
+impl UpdateJobRequest {
+    /// Applique la modification à un job Queued ou Waiting (`InvalidJobState`
+    /// sinon : un job démarré ou terminé n'est plus modifiable)
+    pub fn apply(&self, job: &mut Job) -> Result<()> {
+        if !matches!(job.status, JobStatus::Queued | JobStatus::Waiting) {
+            return Err(IronForgeError::InvalidJobState(job.status));
+        }
+
+        if let Some(priority) = self.priority {
+            job.priority = priority;
+        }
+
+        if let Some(scheduled_for) = self.scheduled_for {
+            job.scheduled_for = Some(scheduled_for);
+        }
+
+        if let Some(timeout_ms) = self.timeout_ms {
+            job.timeout_ms = timeout_ms;
+        }
+
+        for (key, value) in &self.metadata {
+            match value {
+                Some(value) => job.metadata.insert(key.clone(), value.clone()),
+                None => job.metadata.remove(key),
+            };
+        }
+
+        Ok(())
+    }
 }
 
 /// Réponse de création d'un job
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, ToSchema)]
 pub struct CreateJobResponse {
     pub id: Uuid,
     pub status: JobStatus,
     pub created_at: DateTime<Utc>,
+    
+    /// Renseigné quand la soumission était un doublon d'un job unique existant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deduplicated: Option<UniquePolicy>,
 }
 
 #[cfg(test)]
@@ -157,6 +524,7 @@ mod tests {
         );
         
         assert_eq!(job.kind, "test.job");
+        assert_eq!(job.queue, DEFAULT_QUEUE);
         assert_eq!(job.status, JobStatus::Queued);
         assert_eq!(job.retry_count, 0);
         assert_eq!(job.priority, Priority::Medium);
@@ -178,4 +546,120 @@ mod tests {
         job.retry_count = 10;
         assert_eq!(job.calculate_backoff_delay(), 300_000); // Max: 5min
     }
+
+    #[test]
+    fn test_status_transitions() {
+        let mut job = Job::new("test".to_string(), serde_json::json!({}));
+
+        assert_eq!(job.transition(JobStatus::Running).unwrap(), JobStatus::Queued);
+        assert_eq!(job.transition(JobStatus::DeadLetter).unwrap(), JobStatus::Running);
+        assert_eq!(job.transition(JobStatus::Queued).unwrap(), JobStatus::DeadLetter);
+
+        // Un job déjà en queue ne peut pas y être remis une seconde fois
+        assert!(matches!(
+            job.transition(JobStatus::Queued),
+            Err(IronForgeError::InvalidStatusTransition { from: JobStatus::Queued, to: JobStatus::Queued })
+        ));
+        assert_eq!(job.status, JobStatus::Queued);
+
+        // Un échec définitif mène en Failed ; un job en cours n'est pas annulable
+        assert!(JobStatus::Running.can_transition_to(JobStatus::Failed));
+        assert!(!JobStatus::Running.can_transition_to(JobStatus::Cancelled));
+        assert!(JobStatus::Failed.can_transition_to(JobStatus::Queued));
+
+        for status in JobStatus::ALL {
+            assert!(!JobStatus::Completed.can_transition_to(status));
+            assert!(!JobStatus::Cancelled.can_transition_to(status));
+        }
+    }
+
+    #[test]
+    fn test_update_request_applies_to_pending_jobs_only() {
+        let mut job = Job::new("test".to_string(), serde_json::json!({}));
+        job.metadata.insert("team".to_string(), "growth".to_string());
+        job.metadata.insert("stale".to_string(), "yes".to_string());
+
+        let req: UpdateJobRequest = serde_json::from_value(serde_json::json!({
+            "priority": "critical",
+            "scheduled_for": "2030-01-01T00:00:00Z",
+            "metadata": { "team": "billing", "stale": null }
+        }))
+        .unwrap();
+        req.apply(&mut job).unwrap();
+
+        assert_eq!(job.priority, Priority::Critical);
+        assert!(job.is_delayed(Utc::now()));
+        assert_eq!(job.metadata.get("team").map(String::as_str), Some("billing"));
+        assert!(!job.metadata.contains_key("stale"));
+
+        job.status = JobStatus::Running;
+        assert!(matches!(req.apply(&mut job), Err(IronForgeError::InvalidJobState(JobStatus::Running))));
+
+        assert!(serde_json::from_value::<UpdateJobRequest>(serde_json::json!({ "kind": "other" })).is_err());
+    }
+
+    #[test]
+    fn test_create_request_validation() {
+        let req: CreateJobRequest =
+            serde_json::from_value(serde_json::json!({"kind": "email.send", "payload": {}})).unwrap();
+        assert!(req.validate().is_ok());
+
+        let mut invalid = req.clone();
+        invalid.kind = "  ".to_string();
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = req;
+        invalid.timeout_ms = Some(0);
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_key_and_field_order() {
+        let a: CreateJobRequest = serde_json::from_str(
+            r#"{"kind": "k", "payload": {"a": 1, "b": 2}, "metadata": {"x": "1", "y": "2"}, "idempotency_key": "abc"}"#,
+        )
+        .unwrap();
+        let b: CreateJobRequest = serde_json::from_str(
+            r#"{"metadata": {"y": "2", "x": "1"}, "payload": {"b": 2, "a": 1}, "kind": "k"}"#,
+        )
+        .unwrap();
+        assert_eq!(a.fingerprint(), b.fingerprint());
+
+        let c: CreateJobRequest =
+            serde_json::from_str(r#"{"kind": "k", "payload": {"a": 2}}"#).unwrap();
+        assert_ne!(a.fingerprint(), c.fingerprint());
+    }
+
+    #[test]
+    fn test_status_names_match_serde() {
+        for status in JobStatus::ALL {
+            assert_eq!(
+                serde_json::to_value(status).unwrap(),
+                serde_json::Value::String(status.as_str().to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn test_dependency_state() {
+        use JobStatus::*;
+
+        assert_eq!(DependencyState::evaluate(&[]), DependencyState::Ready);
+        assert_eq!(
+            DependencyState::evaluate(&[Some(Completed), Some(Completed)]),
+            DependencyState::Ready
+        );
+        assert_eq!(
+            DependencyState::evaluate(&[Some(Completed), Some(Running)]),
+            DependencyState::Pending
+        );
+        assert_eq!(
+            DependencyState::evaluate(&[Some(Waiting), Some(DeadLetter)]),
+            DependencyState::Failed
+        );
+        assert_eq!(
+            DependencyState::evaluate(&[Some(Queued), None]),
+            DependencyState::Failed
+        );
+    }
 }
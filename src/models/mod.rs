@@ -1,5 +1,33 @@
 mod job;
 mod error;
+mod worker;
+mod workflow;
+mod schedule;
+mod query;
+mod unique;
+mod event;
+mod webhook;
+mod tenant;
+mod validation;
+mod attempt;
 
-pub use job::{Job, Priority, JobStatus, CreateJobRequest, CreateJobResponse};
+pub use job::{
+    Job, Priority, JobStatus, CreateJobRequest, CreateJobResponse, UpdateJobRequest,
+    DependencyFailurePolicy, DependencyState, DEFAULT_QUEUE,
+};
+pub use worker::WorkerInfo;
+pub use workflow::{
+    Workflow, WorkflowKind, WorkflowStatus, WorkflowPlan, CreateWorkflowRequest,
+};
+pub use schedule::{Schedule, ScheduleRequest, RunPlan, OverlapPolicy, CatchUpPolicy};
+pub use query::{JobFilter, JobCursor, JobPage, DEFAULT_PAGE_LIMIT, MAX_PAGE_LIMIT};
+pub use unique::{UniqueOptions, UniqueLock, UniqueScope, UniquePolicy};
+pub use event::{JobEvent, JobEventData, EventFilter};
+pub use webhook::{
+    WebhookNotification, WebhookDelivery, sign_webhook, validate_callback_url, is_internal_address,
+    WEBHOOK_STATUSES,
+};
+pub use tenant::{TenantQuota, validate_tenant};
+pub use validation::{ValidationRules, FieldViolation, DEFAULT_KIND_PATTERN};
+pub use attempt::{JobAttempt, AttemptOutcome, MAX_ATTEMPTS_HISTORY};
 pub use error::{IronForgeError, Result};
@@ -0,0 +1,127 @@
+use serde::Serialize;
+use chrono::{DateTime, Utc};
+use std::fmt;
+use std::str::FromStr;
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+use super::error::IronForgeError;
+use super::job::{Job, JobStatus};
+
+/// Nombre de jobs retournés par défaut par page
+pub const DEFAULT_PAGE_LIMIT: usize = 50;
+
+/// Nombre maximum de jobs retournés par page
+pub const MAX_PAGE_LIMIT: usize = 500;
+
+/// Critères de recherche des jobs
+#[derive(Debug, Clone, Default)]
+pub struct JobFilter {
+    pub status: Option<JobStatus>,
+    pub kind: Option<String>,
+    pub queue: Option<String>,
+    /// Paires clé/valeur que les métadonnées doivent contenir
+    pub metadata: Vec<(String, String)>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub batch_id: Option<Uuid>,
+    pub tenant: Option<String>,
+}
+
+impl JobFilter {
+    /// Indique si le job satisfait tous les critères
+    pub fn matches(&self, job: &Job) -> bool {
+        self.status.is_none_or(|status| job.status == status)
+            && self.kind.as_ref().is_none_or(|kind| &job.kind == kind)
+            && self.queue.as_ref().is_none_or(|queue| &job.queue == queue)
+            && self.created_after.is_none_or(|after| job.created_at >= after)
+            && self.batch_id.is_none_or(|batch_id| job.batch_id == Some(batch_id))
+            && self.tenant.as_ref().is_none_or(|tenant| job.tenant.as_ref() == Some(tenant))
+            && self
+                .metadata
+                .iter()
+                .all(|(key, value)| job.metadata.get(key) == Some(value))
+    }
+}
+
+/// Position dans une liste de jobs triée du plus récent au plus ancien.
+///
+/// L'ordre (date de création, id) ne change jamais pour un job donné, ce qui
+/// garde la pagination stable quand des jobs sont créés ou changent d'état.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JobCursor {
+    pub created_at_ms: i64,
+    pub job_id: Uuid,
+}
+
+impl JobCursor {
+    pub fn from_job(job: &Job) -> Self {
+        Self {
+            created_at_ms: job.created_at.timestamp_millis(),
+            job_id: job.id,
+        }
+    }
+}
+
+impl fmt::Display for JobCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}_{}", self.created_at_ms, self.job_id.simple())
+    }
+}
+
+impl FromStr for JobCursor {
+    type Err = IronForgeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || IronForgeError::InvalidQuery(format!("invalid cursor: {}", s));
+
+        let (created_at_ms, job_id) = s.split_once('_').ok_or_else(invalid)?;
+
+        Ok(Self {
+            created_at_ms: created_at_ms.parse().map_err(|_| invalid())?,
+            job_id: Uuid::parse_str(job_id).map_err(|_| invalid())?,
+        })
+    }
+}
+
+/// Page de résultats d'une recherche de jobs
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct JobPage {
+    pub jobs: Vec<Job>,
+    /// Curseur à passer pour obtenir la page suivante (`None` = fin de liste)
+    pub next_cursor: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let job = Job::new("test".to_string(), serde_json::json!({}));
+        let cursor = JobCursor::from_job(&job);
+
+        assert_eq!(cursor.to_string().parse::<JobCursor>().unwrap(), cursor);
+        assert!("garbage".parse::<JobCursor>().is_err());
+        assert!("12_not-a-uuid".parse::<JobCursor>().is_err());
+    }
+
+    #[test]
+    fn test_filter_matches() {
+        let mut job = Job::new("email.send".to_string(), serde_json::json!({}));
+        job.metadata.insert("tenant".to_string(), "acme".to_string());
+
+        let mut filter = JobFilter {
+            kind: Some("email.send".to_string()),
+            metadata: vec![("tenant".to_string(), "acme".to_string())],
+            ..Default::default()
+        };
+        assert!(filter.matches(&job));
+
+        filter.status = Some(JobStatus::Failed);
+        assert!(!filter.matches(&job));
+
+        filter.status = None;
+        filter.metadata = vec![("tenant".to_string(), "other".to_string())];
+        assert!(!filter.matches(&job));
+    }
+}
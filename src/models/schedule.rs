@@ -0,0 +1,318 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use std::str::FromStr;
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+use super::error::{IronForgeError, Result};
+use super::job::{CreateJobRequest, Job};
+
+/// Nombre maximum d'exécutions rattrapées en une fois (politique `all`)
+pub const MAX_CATCH_UP_RUNS: usize = 100;
+
+/// Retard au-delà duquel une exécution est considérée comme manquée (en secondes)
+pub const MISFIRE_GRACE_SECS: i64 = 60;
+
+/// Comportement quand l'exécution précédente n'est pas terminée
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OverlapPolicy {
+    /// N'enqueue pas de nouveau job tant que le précédent est en queue ou en cours
+    #[default]
+    Skip,
+    /// Enqueue toujours un nouveau job
+    Allow,
+}
+
+/// Traitement des exécutions manquées (ex: pendant une indisponibilité)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CatchUpPolicy {
+    /// Ignore les exécutions manquées
+    #[default]
+    Skip,
+    /// Regroupe les exécutions manquées en une seule
+    Once,
+    /// Rejoue chaque exécution manquée (dans la limite de `MAX_CATCH_UP_RUNS`)
+    All,
+}
+
+fn default_timezone() -> String {
+    "UTC".to_string()
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Planification récurrente d'un job
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Schedule {
+    pub id: Uuid,
+    pub name: String,
+
+    /// Expression cron (5 champs, ou 6 avec les secondes)
+    pub cron: String,
+
+    /// Fuseau horaire IANA de l'expression (ex: "Europe/Paris")
+    pub timezone: String,
+
+    /// Modèle du job à enqueue à chaque exécution
+    pub job: CreateJobRequest,
+
+    pub overlap: OverlapPolicy,
+    pub catch_up: CatchUpPolicy,
+    pub enabled: bool,
+
+    pub created_at: DateTime<Utc>,
+
+    /// Dernière exécution enqueue
+    pub last_run_at: Option<DateTime<Utc>>,
+
+    /// Dernier job créé par la planification
+    pub last_job_id: Option<Uuid>,
+
+    /// Prochaine exécution prévue
+    pub next_run_at: Option<DateTime<Utc>>,
+
+    /// Tenant propriétaire, hérité par les jobs créés
+    #[serde(default)]
+    pub tenant: Option<String>,
+}
+
+/// Exécutions à enqueue lors d'une évaluation de la planification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunPlan {
+    pub runs: Vec<DateTime<Utc>>,
+    pub next_run_at: Option<DateTime<Utc>>,
+}
+
+/// Payload de création / mise à jour d'une planification
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ScheduleRequest {
+    pub name: String,
+    pub cron: String,
+
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+
+    pub job: CreateJobRequest,
+
+    #[serde(default)]
+    pub overlap: OverlapPolicy,
+
+    #[serde(default)]
+    pub catch_up: CatchUpPolicy,
+
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+impl Schedule {
+    /// Crée une planification à partir d'une requête validée
+    pub fn from_request(req: ScheduleRequest) -> Result<Self> {
+        let mut schedule = Self {
+            id: Uuid::new_v4(),
+            name: req.name,
+            cron: req.cron,
+            timezone: req.timezone,
+            job: req.job,
+            overlap: req.overlap,
+            catch_up: req.catch_up,
+            enabled: req.enabled,
+            created_at: Utc::now(),
+            last_run_at: None,
+            last_job_id: None,
+            next_run_at: None,
+            tenant: None,
+        };
+
+        schedule.next_run_at = schedule.next_after(Utc::now())?;
+        Ok(schedule)
+    }
+
+    /// Job à enqueue pour une exécution, rattaché au tenant de la planification
+    pub fn new_job(&self) -> Job {
+        let mut job = self.job.clone().into_job();
+        job.assign_tenant(self.tenant.clone());
+        job
+    }
+
+    /// Applique une mise à jour en conservant l'historique d'exécution
+    pub fn update(&mut self, req: ScheduleRequest) -> Result<()> {
+        let mut updated = Self::from_request(req)?;
+        updated.id = self.id;
+        updated.created_at = self.created_at;
+        updated.last_run_at = self.last_run_at;
+        updated.last_job_id = self.last_job_id;
+        updated.tenant = self.tenant.clone();
+
+        *self = updated;
+        Ok(())
+    }
+
+    /// Prochaine exécution strictement après `after`
+    pub fn next_after(&self, after: DateTime<Utc>) -> Result<Option<DateTime<Utc>>> {
+        let cron = parse_cron(&self.cron)?;
+        let tz = parse_timezone(&self.timezone)?;
+
+        Ok(cron
+            .after(&after.with_timezone(&tz))
+            .next()
+            .map(|next| next.with_timezone(&Utc)))
+    }
+
+    /// Calcule les exécutions à enqueue à `now` selon la politique de rattrapage
+    ///
+    /// Les occurrences sont parcourues à rebours depuis `now` et le parcours
+    /// s'arrête dès que la politique est satisfaite : une longue indisponibilité
+    /// ne coûte pas une itération par occurrence manquée.
+    pub fn plan_runs(&self, now: DateTime<Utc>) -> Result<RunPlan> {
+        let Some(first) = self.next_run_at else {
+            return Ok(RunPlan { runs: Vec::new(), next_run_at: self.next_after(now)? });
+        };
+        if first > now {
+            return Ok(RunPlan { runs: Vec::new(), next_run_at: Some(first) });
+        }
+
+        let (from, limit) = match self.catch_up {
+            // Seules les occurrences dans le délai de grâce sont enqueue
+            CatchUpPolicy::Skip => (first.max(now - Duration::seconds(MISFIRE_GRACE_SECS)), MAX_CATCH_UP_RUNS),
+            CatchUpPolicy::Once => (first, 1),
+            CatchUpPolicy::All => (first, MAX_CATCH_UP_RUNS),
+        };
+
+        Ok(RunPlan {
+            runs: self.last_occurrences(from, now, limit)?,
+            next_run_at: self.next_after(now)?,
+        })
+    }
+
+    /// Au plus `limit` dernières occurrences dans `[from, now]`, par ordre chronologique
+    fn last_occurrences(
+        &self,
+        from: DateTime<Utc>,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Result<Vec<DateTime<Utc>>> {
+        let cron = parse_cron(&self.cron)?;
+        let tz = parse_timezone(&self.timezone)?;
+
+        // Départ juste après `now` pour inclure une occurrence à `now` exactement
+        let start = (now + Duration::seconds(1)).with_timezone(&tz);
+        let mut runs: Vec<DateTime<Utc>> = cron
+            .after(&start)
+            .rev()
+            .map(|occurrence| occurrence.with_timezone(&Utc))
+            .skip_while(|occurrence| *occurrence > now)
+            .take_while(|occurrence| *occurrence >= from)
+            .take(limit)
+            .collect();
+
+        runs.reverse();
+        Ok(runs)
+    }
+}
+
+/// Parse une expression cron, en acceptant le format standard à 5 champs
+fn parse_cron(expression: &str) -> Result<cron::Schedule> {
+    let expression = if expression.split_whitespace().count() == 5 {
+        format!("0 {}", expression)
+    } else {
+        expression.to_string()
+    };
+
+    cron::Schedule::from_str(&expression)
+        .map_err(|e| IronForgeError::InvalidSchedule(format!("invalid cron expression: {}", e)))
+}
+
+fn parse_timezone(timezone: &str) -> Result<Tz> {
+    Tz::from_str(timezone)
+        .map_err(|_| IronForgeError::InvalidSchedule(format!("unknown timezone: {}", timezone)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn schedule(cron: &str, catch_up: CatchUpPolicy) -> Schedule {
+        let req: ScheduleRequest = serde_json::from_value(serde_json::json!({
+            "name": "test",
+            "cron": cron,
+            "job": { "kind": "test.job", "payload": {} },
+            "catch_up": catch_up,
+        }))
+        .unwrap();
+
+        Schedule::from_request(req).unwrap()
+    }
+
+    #[test]
+    fn test_invalid_cron_and_timezone_rejected() {
+        let mut s = schedule("*/5 * * * *", CatchUpPolicy::Skip);
+        assert!(s.next_after(Utc::now()).unwrap().is_some());
+
+        s.cron = "not a cron".to_string();
+        assert!(s.next_after(Utc::now()).is_err());
+
+        s.cron = "*/5 * * * *".to_string();
+        s.timezone = "Mars/Olympus".to_string();
+        assert!(s.next_after(Utc::now()).is_err());
+    }
+
+    #[test]
+    fn test_timezone_applied() {
+        let mut s = schedule("0 9 * * *", CatchUpPolicy::Skip);
+        s.timezone = "Europe/Paris".to_string();
+
+        let after = Utc.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap();
+        let next = s.next_after(after).unwrap().unwrap();
+        assert_eq!(next, Utc.with_ymd_and_hms(2026, 1, 15, 8, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_catch_up_policies() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 30).unwrap();
+        let three_hours_ago = Utc.with_ymd_and_hms(2026, 1, 15, 9, 0, 0).unwrap();
+
+        let mut s = schedule("0 * * * *", CatchUpPolicy::Skip);
+        s.next_run_at = Some(three_hours_ago);
+
+        // 9h, 10h, 11h manquées, 12h à l'heure
+        let plan = s.plan_runs(now).unwrap();
+        assert_eq!(plan.runs, vec![Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap()]);
+        assert_eq!(plan.next_run_at, Some(Utc.with_ymd_and_hms(2026, 1, 15, 13, 0, 0).unwrap()));
+
+        s.catch_up = CatchUpPolicy::Once;
+        assert_eq!(s.plan_runs(now).unwrap().runs.len(), 1);
+
+        s.catch_up = CatchUpPolicy::All;
+        assert_eq!(s.plan_runs(now).unwrap().runs.len(), 4);
+
+        // Indisponibilité sans exécution à l'heure : `skip` n'enqueue rien
+        let late = Utc.with_ymd_and_hms(2026, 1, 15, 12, 30, 0).unwrap();
+        s.catch_up = CatchUpPolicy::Skip;
+        assert!(s.plan_runs(late).unwrap().runs.is_empty());
+        s.catch_up = CatchUpPolicy::Once;
+        assert_eq!(s.plan_runs(late).unwrap().runs, vec![Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap()]);
+    }
+
+    #[test]
+    fn test_long_outage_catch_up_is_bounded() {
+        let now = Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 30).unwrap();
+        let mut s = schedule("* * * * *", CatchUpPolicy::All);
+        s.next_run_at = Some(Utc.with_ymd_and_hms(2021, 1, 15, 12, 0, 0).unwrap());
+
+        // Cinq ans de minutes manquées : seules les dernières sont calculées
+        let plan = s.plan_runs(now).unwrap();
+        assert_eq!(plan.runs.len(), MAX_CATCH_UP_RUNS);
+        assert_eq!(plan.runs.last(), Some(&Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap()));
+        assert!(plan.runs.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(plan.next_run_at, Some(Utc.with_ymd_and_hms(2026, 1, 15, 12, 1, 0).unwrap()));
+
+        s.catch_up = CatchUpPolicy::Once;
+        assert_eq!(s.plan_runs(now).unwrap().runs, vec![Utc.with_ymd_and_hms(2026, 1, 15, 12, 0, 0).unwrap()]);
+    }
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use super::job::Job;
+
+/// Quotas d'un tenant (absent = illimité)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TenantQuota {
+    /// Nombre maximum de jobs en attente d'exécution dans la queue du tenant
+    #[serde(default)]
+    pub max_queued_jobs: Option<u64>,
+
+    /// Nombre maximum de jobs soumis par minute
+    #[serde(default)]
+    pub submissions_per_minute: Option<u64>,
+}
+
+/// Vérifie qu'un nom de tenant peut servir de préfixe de clés
+/// (lettres, chiffres, `-`, `_` et `.`)
+pub fn validate_tenant(tenant: &str) -> std::result::Result<(), String> {
+    let valid = !tenant.is_empty()
+        && tenant
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("invalid tenant name: {:?}", tenant))
+    }
+}
+
+impl Job {
+    /// Rattache le job à un tenant : son verrou d'unicité est isolé de ceux
+    /// des autres tenants
+    pub fn assign_tenant(&mut self, tenant: Option<String>) {
+        if let (Some(tenant), Some(unique)) = (&tenant, &mut self.unique) {
+            unique.key = format!("{}/{}", tenant, unique.key);
+        }
+        self.tenant = tenant;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CreateJobRequest;
+
+    #[test]
+    fn test_assign_tenant_isolates_unique_lock() {
+        let request: CreateJobRequest = serde_json::from_value(serde_json::json!({
+            "kind": "report.generate",
+            "payload": {},
+            "unique": { "key": "daily" }
+        }))
+        .unwrap();
+
+        let mut shared = request.clone().into_job();
+        shared.assign_tenant(None);
+        let mut team = request.into_job();
+        team.assign_tenant(Some("team-a".to_string()));
+
+        assert_eq!(team.tenant.as_deref(), Some("team-a"));
+        assert_eq!(team.unique.unwrap().key, "team-a/report.generate:daily");
+        assert_eq!(shared.unique.unwrap().key, "report.generate:daily");
+
+        assert!(validate_tenant("team-a.eu_1").is_ok());
+        assert!(validate_tenant("team:a").is_err());
+        assert!(validate_tenant("").is_err());
+    }
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use utoipa::ToSchema;
+
+use super::job::{Job, JobStatus};
+
+/// Période pendant laquelle un job unique bloque les doublons
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum UniqueScope {
+    /// Tant que le job n'a pas démarré (waiting ou queued)
+    #[default]
+    Queued,
+    /// Tant que le job n'est pas terminé (waiting, queued ou running)
+    Active,
+    /// Pendant `window_secs` secondes après la soumission, quel que soit le statut
+    Window,
+}
+
+impl UniqueScope {
+    /// Indique si un job dans ce statut bloque encore les doublons
+    pub fn holds(&self, status: JobStatus) -> bool {
+        match self {
+            UniqueScope::Queued => matches!(status, JobStatus::Waiting | JobStatus::Queued),
+            UniqueScope::Active => !status.is_terminal(),
+            UniqueScope::Window => true,
+        }
+    }
+}
+
+/// Traitement d'une soumission quand un job équivalent existe déjà
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum UniquePolicy {
+    /// La nouvelle soumission est ignorée
+    #[default]
+    Drop,
+    /// La nouvelle soumission est fusionnée dans le job existant
+    /// (priorité la plus haute, métadonnées manquantes ajoutées)
+    Merge,
+}
+
+/// Options d'unicité d'un job soumis via l'API
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UniqueOptions {
+    /// Clé d'unicité (par défaut : empreinte du payload)
+    #[serde(default)]
+    pub key: Option<String>,
+
+    #[serde(default)]
+    pub scope: UniqueScope,
+
+    /// Durée de la fenêtre (scope `window`)
+    #[serde(default)]
+    pub window_secs: Option<u64>,
+
+    #[serde(default)]
+    pub on_conflict: UniquePolicy,
+}
+
+impl UniqueOptions {
+    /// Vérifie la cohérence des options
+    pub fn validate(&self) -> std::result::Result<(), String> {
+        if self.key.as_deref().is_some_and(|key| key.trim().is_empty()) {
+            return Err("unique.key must not be empty".to_string());
+        }
+
+        match (self.scope, self.window_secs) {
+            (UniqueScope::Window, None | Some(0)) => {
+                Err("unique.window_secs must be greater than 0 for the window scope".to_string())
+            }
+            (UniqueScope::Queued | UniqueScope::Active, Some(_)) => {
+                Err("unique.window_secs is only allowed with the window scope".to_string())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Construit le verrou d'unicité d'un job (clé = type + clé choisie ou empreinte du payload)
+    pub fn lock_for(&self, kind: &str, payload: &serde_json::Value) -> UniqueLock {
+        let key = self
+            .key
+            .clone()
+            .unwrap_or_else(|| hex::encode(Sha256::digest(payload.to_string().as_bytes())));
+
+        UniqueLock {
+            key: format!("{}:{}", kind, key),
+            scope: self.scope,
+            window_secs: self.window_secs,
+            on_conflict: self.on_conflict,
+        }
+    }
+}
+
+/// Verrou d'unicité porté par un job
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct UniqueLock {
+    /// Clé complète ("{kind}:{key}")
+    pub key: String,
+    pub scope: UniqueScope,
+    pub window_secs: Option<u64>,
+    pub on_conflict: UniquePolicy,
+}
+
+impl Job {
+    /// Fusionne un doublon dans ce job : la priorité la plus haute est retenue
+    /// et les métadonnées absentes sont ajoutées (les valeurs existantes sont
+    /// conservées pour garder les index cohérents)
+    pub fn merge_duplicate(&mut self, duplicate: &Job) {
+        if duplicate.priority.to_score() < self.priority.to_score() {
+            self.priority = duplicate.priority;
+        }
+
+        for (key, value) in &duplicate.metadata {
+            self.metadata
+                .entry(key.clone())
+                .or_insert_with(|| value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Priority;
+
+    fn options(json: serde_json::Value) -> UniqueOptions {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_lock_key_from_key_or_payload_hash() {
+        let by_key = options(serde_json::json!({ "key": "user-42" }));
+        let lock = by_key.lock_for("reindex", &serde_json::json!({ "user": 42 }));
+        assert_eq!(lock.key, "reindex:user-42");
+        assert_eq!(lock.scope, UniqueScope::Queued);
+
+        let by_payload = options(serde_json::json!({}));
+        let a = by_payload.lock_for("reindex", &serde_json::json!({ "user": 42, "full": true }));
+        let b = by_payload.lock_for("reindex", &serde_json::json!({ "full": true, "user": 42 }));
+        let c = by_payload.lock_for("reindex", &serde_json::json!({ "user": 43 }));
+        assert_eq!(a.key, b.key);
+        assert_ne!(a.key, c.key);
+
+        assert!(options(serde_json::json!({ "scope": "window" })).validate().is_err());
+        assert!(options(serde_json::json!({ "scope": "window", "window_secs": 60 })).validate().is_ok());
+        assert!(options(serde_json::json!({ "window_secs": 60 })).validate().is_err());
+    }
+
+    #[test]
+    fn test_scope_and_merge() {
+        assert!(UniqueScope::Queued.holds(JobStatus::Queued));
+        assert!(!UniqueScope::Queued.holds(JobStatus::Running));
+        assert!(UniqueScope::Active.holds(JobStatus::Running));
+        assert!(!UniqueScope::Active.holds(JobStatus::Completed));
+
+        let mut existing = Job::new("reindex".to_string(), serde_json::json!({}));
+        existing.metadata.insert("source".to_string(), "first".to_string());
+
+        let mut duplicate = existing.clone();
+        duplicate.priority = Priority::Critical;
+        duplicate.metadata.insert("source".to_string(), "second".to_string());
+        duplicate.metadata.insert("trigger".to_string(), "event".to_string());
+
+        existing.merge_duplicate(&duplicate);
+        assert_eq!(existing.priority, Priority::Critical);
+        assert_eq!(existing.metadata["source"], "first");
+        assert_eq!(existing.metadata["trigger"], "event");
+    }
+}
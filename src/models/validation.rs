@@ -0,0 +1,230 @@
+use regex::Regex;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::error::{IronForgeError, Result};
+use super::job::{CreateJobRequest, Job, UpdateJobRequest};
+
+/// Motif par défaut des types de jobs (ex: "email.send", "billing:invoice-v2")
+pub const DEFAULT_KIND_PATTERN: &str = r"^[A-Za-z0-9][A-Za-z0-9_.:-]{0,127}$";
+
+/// Règle de validation non respectée par un champ de la requête
+#[derive(Debug, Clone, Serialize, PartialEq, Eq, ToSchema)]
+pub struct FieldViolation {
+    /// Chemin du champ (ex: "payload", "metadata.team")
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldViolation {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Règles appliquées aux jobs soumis via l'API
+#[derive(Debug, Clone)]
+pub struct ValidationRules {
+    pub kind_pattern: Regex,
+
+    /// Taille maximale du payload sérialisé en JSON (en octets)
+    pub max_payload_bytes: usize,
+
+    pub min_timeout_ms: u64,
+    pub max_timeout_ms: u64,
+    pub max_retries: u8,
+
+    pub max_metadata_entries: usize,
+    pub max_metadata_key_len: usize,
+    pub max_metadata_value_len: usize,
+
+    /// Accepte `callback_url` : les webhooks ne partent que signés, donc
+    /// seulement quand un secret est configuré (`WEBHOOK_SECRET`)
+    pub callbacks_enabled: bool,
+}
+
+impl Default for ValidationRules {
+    fn default() -> Self {
+        Self {
+            kind_pattern: Regex::new(DEFAULT_KIND_PATTERN).expect("default kind pattern is valid"),
+            max_payload_bytes: 1024 * 1024,
+            min_timeout_ms: 1,
+            max_timeout_ms: 24 * 3600 * 1000,
+            max_retries: 25,
+            max_metadata_entries: 32,
+            max_metadata_key_len: 64,
+            max_metadata_value_len: 1024,
+            callbacks_enabled: false,
+        }
+    }
+}
+
+impl ValidationRules {
+    /// Remplace le motif des types de jobs
+    pub fn with_kind_pattern(mut self, pattern: &str) -> Result<Self> {
+        self.kind_pattern = Regex::new(pattern)
+            .map_err(|e| IronForgeError::Config(format!("invalid kind pattern {}: {}", pattern, e)))?;
+        Ok(self)
+    }
+
+    /// Vérifie la requête et retourne toutes les règles non respectées
+    pub fn check(&self, req: &CreateJobRequest) -> Vec<FieldViolation> {
+        let mut violations = Vec::new();
+
+        if !self.kind_pattern.is_match(&req.kind) {
+            violations.push(FieldViolation::new(
+                "kind",
+                format!("must match {}", self.kind_pattern.as_str()),
+            ));
+        }
+
+        let payload_bytes = serde_json::to_string(&req.payload).map(|json| json.len()).unwrap_or(0);
+        if payload_bytes > self.max_payload_bytes {
+            violations.push(FieldViolation::new(
+                "payload",
+                format!("is {} bytes, at most {} allowed", payload_bytes, self.max_payload_bytes),
+            ));
+        }
+
+        if let Some(timeout_ms) = req.timeout_ms {
+            if !(self.min_timeout_ms..=self.max_timeout_ms).contains(&timeout_ms) {
+                violations.push(FieldViolation::new(
+                    "timeout_ms",
+                    format!("must be between {} and {}", self.min_timeout_ms, self.max_timeout_ms),
+                ));
+            }
+        }
+
+        if req.max_retries.is_some_and(|retries| retries > self.max_retries) {
+            violations.push(FieldViolation::new(
+                "max_retries",
+                format!("must be at most {}", self.max_retries),
+            ));
+        }
+
+        if req.metadata.len() > self.max_metadata_entries {
+            violations.push(FieldViolation::new(
+                "metadata",
+                format!("has {} entries, at most {} allowed", req.metadata.len(), self.max_metadata_entries),
+            ));
+        }
+
+        if req.callback_url.is_some() && !self.callbacks_enabled {
+            violations.push(FieldViolation::new(
+                "callback_url",
+                "webhooks are disabled on this server (WEBHOOK_SECRET is not set)",
+            ));
+        }
+
+        for (key, value) in &req.metadata {
+            if key.is_empty() || key.len() > self.max_metadata_key_len {
+                violations.push(FieldViolation::new(
+                    format!("metadata.{}", key),
+                    format!("key must be 1 to {} bytes", self.max_metadata_key_len),
+                ));
+            }
+            if value.len() > self.max_metadata_value_len {
+                violations.push(FieldViolation::new(
+                    format!("metadata.{}", key),
+                    format!("value must be at most {} bytes", self.max_metadata_value_len),
+                ));
+            }
+        }
+
+        violations
+    }
+
+    /// Vérifie la modification d'un job, métadonnées résultantes comprises
+    pub fn check_update(&self, req: &UpdateJobRequest, job: &Job) -> Vec<FieldViolation> {
+        let mut violations = Vec::new();
+
+        if let Some(timeout_ms) = req.timeout_ms {
+            if !(self.min_timeout_ms..=self.max_timeout_ms).contains(&timeout_ms) {
+                violations.push(FieldViolation::new(
+                    "timeout_ms",
+                    format!("must be between {} and {}", self.min_timeout_ms, self.max_timeout_ms),
+                ));
+            }
+        }
+
+        let mut metadata = job.metadata.clone();
+        for (key, value) in &req.metadata {
+            let Some(value) = value else {
+                metadata.remove(key);
+                continue;
+            };
+
+            if key.is_empty() || key.len() > self.max_metadata_key_len {
+                violations.push(FieldViolation::new(
+                    format!("metadata.{}", key),
+                    format!("key must be 1 to {} bytes", self.max_metadata_key_len),
+                ));
+            }
+            if value.len() > self.max_metadata_value_len {
+                violations.push(FieldViolation::new(
+                    format!("metadata.{}", key),
+                    format!("value must be at most {} bytes", self.max_metadata_value_len),
+                ));
+            }
+            metadata.insert(key.clone(), value.clone());
+        }
+
+        if metadata.len() > self.max_metadata_entries {
+            violations.push(FieldViolation::new(
+                "metadata",
+                format!("would have {} entries, at most {} allowed", metadata.len(), self.max_metadata_entries),
+            ));
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(json: serde_json::Value) -> CreateJobRequest {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn test_validation_rules() {
+        let rules = ValidationRules::default();
+
+        let valid = request(serde_json::json!({
+            "kind": "email.send",
+            "payload": { "to": "a@b.c" },
+            "timeout_ms": 5000,
+            "max_retries": 5,
+            "metadata": { "team": "growth" }
+        }));
+        assert!(rules.check(&valid).is_empty());
+
+        let invalid = request(serde_json::json!({
+            "kind": "email send!",
+            "payload": { "blob": "x".repeat(2 * 1024 * 1024) },
+            "timeout_ms": u64::MAX,
+            "max_retries": 200,
+            "metadata": { "": "empty key" }
+        }));
+        let fields: Vec<String> = rules.check(&invalid).into_iter().map(|v| v.field).collect();
+        assert_eq!(fields, ["kind", "payload", "timeout_ms", "max_retries", "metadata."]);
+
+        let callback = request(serde_json::json!({
+            "kind": "email.send",
+            "payload": {},
+            "callback_url": "https://example.com/hooks"
+        }));
+        assert_eq!(rules.check(&callback)[0].field, "callback_url");
+        let with_webhooks = ValidationRules { callbacks_enabled: true, ..ValidationRules::default() };
+        assert!(with_webhooks.check(&callback).is_empty());
+
+        let strict = ValidationRules::default().with_kind_pattern(r"^billing\.").unwrap();
+        assert_eq!(strict.check(&valid)[0].field, "kind");
+        assert!(ValidationRules::default().with_kind_pattern("(").is_err());
+    }
+}
@@ -0,0 +1,192 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use url::{Host, Url};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+use super::job::{Job, JobStatus};
+
+/// Statuts qui déclenchent l'envoi du webhook de fin de job
+pub const WEBHOOK_STATUSES: [JobStatus; 3] =
+    [JobStatus::Completed, JobStatus::Failed, JobStatus::DeadLetter];
+
+/// Notification envoyée à l'URL de callback d'un job
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookNotification {
+    /// Type d'événement (ex: "job.completed", "job.deadletter")
+    pub event: String,
+    pub job_id: Uuid,
+    pub kind: String,
+    pub queue: String,
+    pub status: JobStatus,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Numéro de la tentative de livraison (à partir de 1)
+    pub attempt: u32,
+    pub timestamp: DateTime<Utc>,
+}
+
+impl WebhookNotification {
+    pub fn new(job: &Job, attempt: u32) -> Self {
+        Self {
+            event: format!("job.{}", job.status.as_str()),
+            job_id: job.id,
+            kind: job.kind.clone(),
+            queue: job.queue.clone(),
+            status: job.status,
+            result: job.result.clone(),
+            error: job.last_error.clone(),
+            attempt,
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Trace d'une tentative de livraison d'un webhook
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookDelivery {
+    pub attempt: u32,
+
+    /// Statut du job notifié
+    pub status: JobStatus,
+
+    pub attempted_at: DateTime<Utc>,
+
+    /// Code HTTP reçu (absent si la requête n'a pas abouti)
+    pub response_status: Option<u16>,
+
+    pub error: Option<String>,
+    pub success: bool,
+
+    /// Prochaine tentative prévue (absent après un succès ou la dernière tentative)
+    pub next_attempt_at: Option<DateTime<Utc>>,
+}
+
+/// Signature HMAC-SHA256 (hex) de `"{timestamp}.{body}"`, envoyée dans le
+/// header `X-IronForge-Signature` sous la forme `sha256=<hex>`
+pub fn sign_webhook(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+        .expect("HMAC accepts keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// Vérifie qu'une URL de callback est en http(s) et ne vise pas le réseau
+/// interne (loopback, réseaux privés RFC 1918, lien local dont les métadonnées
+/// cloud en 169.254.169.254...). Un nom de domaine est contrôlé à la résolution
+/// par le dispatcher.
+pub fn validate_callback_url(raw: &str) -> std::result::Result<(), String> {
+    let url = Url::parse(raw).map_err(|e| format!("callback_url is invalid: {}", e))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err("callback_url must be an http(s) URL".to_string());
+    }
+
+    let internal = match url.host() {
+        None => return Err("callback_url must have a host".to_string()),
+        Some(Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            domain == "localhost" || domain.ends_with(".localhost")
+        }
+        Some(Host::Ipv4(ip)) => is_internal_address(IpAddr::V4(ip)),
+        Some(Host::Ipv6(ip)) => is_internal_address(IpAddr::V6(ip)),
+    };
+
+    if internal {
+        Err("callback_url must not target a loopback, private or link-local address".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Indique si une adresse n'est pas joignable publiquement (les webhooks ne
+/// doivent pas servir à atteindre le réseau interne)
+pub fn is_internal_address(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_internal_ipv4(mapped),
+            None => is_internal_ipv6(ip),
+        },
+    }
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [first, second, ..] = ip.octets();
+
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // 0.0.0.0/8 et l'espace partagé des opérateurs 100.64.0.0/10
+        || first == 0
+        || (first == 100 && (second & 0xc0) == 64)
+}
+
+fn is_internal_ipv6(ip: Ipv6Addr) -> bool {
+    let first_segment = ip.segments()[0];
+
+    ip.is_loopback()
+        || ip.is_unspecified()
+        // Adresses uniques locales (fc00::/7) et lien local (fe80::/10)
+        || (first_segment & 0xfe00) == 0xfc00
+        || (first_segment & 0xffc0) == 0xfe80
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_webhook() {
+        // Vecteur calculé avec `openssl dgst -sha256 -hmac secret`
+        let signature = sign_webhook("secret", 1700000000, br#"{"a":1}"#);
+        assert_eq!(
+            signature,
+            "49f24e537407743fa4a0242bb63b94b9a47ee99cbbe071ccd8a22550ae411686"
+        );
+        assert_ne!(signature, sign_webhook("other", 1700000000, br#"{"a":1}"#));
+        assert_ne!(signature, sign_webhook("secret", 1700000001, br#"{"a":1}"#));
+
+        let mut job = Job::new("email.send".to_string(), serde_json::json!({}));
+        job.status = JobStatus::DeadLetter;
+        job.last_error = Some("smtp timeout".to_string());
+        let notification = WebhookNotification::new(&job, 1);
+        assert_eq!(notification.event, "job.deadletter");
+        assert_eq!(notification.error.as_deref(), Some("smtp timeout"));
+    }
+
+    #[test]
+    fn test_callback_url_rejects_internal_targets() {
+        assert!(validate_callback_url("https://example.com/hooks").is_ok());
+        assert!(validate_callback_url("http://203.0.113.7:8080/hooks").is_ok());
+
+        for url in [
+            "ftp://example.com/hooks",
+            "http://localhost:3000/hooks",
+            "http://127.0.0.1/hooks",
+            "http://169.254.169.254/latest/meta-data",
+            "http://10.0.0.5/hooks",
+            "http://172.16.3.4/hooks",
+            "http://192.168.1.10/hooks",
+            "http://0.0.0.0/hooks",
+            "http://[::1]/hooks",
+            "http://[::ffff:127.0.0.1]/hooks",
+            "http://[fd00::1]/hooks",
+            "http://2130706433/hooks",
+        ] {
+            assert!(validate_callback_url(url).is_err(), "{} should be rejected", url);
+        }
+    }
+}
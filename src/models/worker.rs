@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+/// Informations publiées par un worker dans le registre
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkerInfo {
+    /// Identifiant unique de l'instance (ex: "host-1a2b3c4d")
+    pub id: String,
+
+    /// Nom de la machine hébergeant le worker
+    pub hostname: String,
+
+    /// Version d'IronForge utilisée par le worker
+    pub version: String,
+
+    /// Types de jobs traités (vide = tous)
+    pub kinds: Vec<String>,
+
+    /// Nombre de workers concurrents de l'instance
+    pub concurrency: usize,
+
+    /// Jobs en cours d'exécution
+    pub current_jobs: Vec<Uuid>,
+
+    /// Date de démarrage de l'instance
+    pub started_at: DateTime<Utc>,
+
+    /// Date du dernier heartbeat
+    pub last_heartbeat: DateTime<Utc>,
+}
+
+impl WorkerInfo {
+    /// Crée la fiche d'un worker qui démarre
+    pub fn new(id: String, kinds: Vec<String>, concurrency: usize) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id,
+            hostname: local_hostname(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            kinds,
+            concurrency,
+            current_jobs: Vec::new(),
+            started_at: now,
+            last_heartbeat: now,
+        }
+    }
+
+    /// Génère un identifiant d'instance unique (hostname + suffixe aléatoire)
+    pub fn generate_id() -> String {
+        let suffix = Uuid::new_v4().simple().to_string();
+        format!("{}-{}", local_hostname(), &suffix[..8])
+    }
+}
+
+/// Nom de la machine locale ("unknown" si indisponible)
+fn local_hostname() -> String {
+    hostname::get()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
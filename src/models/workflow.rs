@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+use super::job::{CreateJobRequest, Job, JobStatus};
+
+/// Types de workflows
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkflowKind {
+    /// Exécution séquentielle, chaque job reçoit le résultat du précédent
+    Chain,
+    /// Exécution en parallèle
+    Group,
+    /// Groupe suivi d'un callback qui reçoit tous les résultats
+    Chord,
+}
+
+/// Statut agrégé d'un workflow
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkflowStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+impl WorkflowStatus {
+    /// Calcule le statut agrégé à partir du statut des jobs du workflow
+    pub fn aggregate(statuses: &[JobStatus]) -> Self {
+        if statuses.iter().any(|s| s.is_terminal() && *s != JobStatus::Completed) {
+            return WorkflowStatus::Failed;
+        }
+
+        if statuses.iter().all(|s| *s == JobStatus::Completed) {
+            return WorkflowStatus::Completed;
+        }
+
+        if statuses
+            .iter()
+            .any(|s| matches!(s, JobStatus::Running | JobStatus::Completed))
+        {
+            WorkflowStatus::Running
+        } else {
+            WorkflowStatus::Pending
+        }
+    }
+}
+
+/// Workflow enregistré dans le backend
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Workflow {
+    pub id: Uuid,
+    pub kind: WorkflowKind,
+
+    /// Jobs du workflow dans l'ordre de soumission (callback inclus)
+    pub job_ids: Vec<Uuid>,
+
+    /// Callback d'un chord
+    pub callback_id: Option<Uuid>,
+
+    /// Job exécuté si le workflow échoue
+    pub errback_id: Option<Uuid>,
+
+    /// Tenant qui a soumis le workflow (et tous ses jobs)
+    #[serde(default)]
+    pub tenant: Option<String>,
+
+    pub created_at: DateTime<Utc>,
+}
+
+/// Payload de création d'un workflow via l'API
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum CreateWorkflowRequest {
+    Chain {
+        jobs: Vec<CreateJobRequest>,
+        #[serde(default)]
+        on_failure: Option<CreateJobRequest>,
+    },
+    Group {
+        jobs: Vec<CreateJobRequest>,
+        #[serde(default)]
+        on_failure: Option<CreateJobRequest>,
+    },
+    Chord {
+        jobs: Vec<CreateJobRequest>,
+        callback: Box<CreateJobRequest>,
+        #[serde(default)]
+        on_failure: Option<CreateJobRequest>,
+    },
+}
+
+/// Workflow prêt à être soumis : sa définition, ses jobs câblés et l'errback
+#[derive(Debug, Clone)]
+pub struct WorkflowPlan {
+    pub workflow: Workflow,
+    pub jobs: Vec<Job>,
+    pub errback: Option<Job>,
+}
+
+impl CreateWorkflowRequest {
+    /// Requêtes de tous les jobs du workflow (callback et errback compris)
+    pub fn job_requests(&self) -> impl Iterator<Item = &CreateJobRequest> {
+        let (jobs, callback, on_failure) = match self {
+            CreateWorkflowRequest::Chain { jobs, on_failure }
+            | CreateWorkflowRequest::Group { jobs, on_failure } => (jobs, None, on_failure),
+            CreateWorkflowRequest::Chord { jobs, callback, on_failure } => {
+                (jobs, Some(callback.as_ref()), on_failure)
+            }
+        };
+        jobs.iter().chain(callback).chain(on_failure.as_ref())
+    }
+
+    /// Construit les jobs du workflow et câble leurs dépendances
+    pub fn into_plan(self) -> WorkflowPlan {
+        let id = Uuid::new_v4();
+        let new_job = |req: CreateJobRequest| {
+            let mut job = req.into_job();
+            job.workflow_id = Some(id);
+            job
+        };
+
+        let (kind, requests, callback, on_failure) = match self {
+            CreateWorkflowRequest::Chain { jobs, on_failure } => {
+                (WorkflowKind::Chain, jobs, None, on_failure)
+            }
+            CreateWorkflowRequest::Group { jobs, on_failure } => {
+                (WorkflowKind::Group, jobs, None, on_failure)
+            }
+            CreateWorkflowRequest::Chord { jobs, callback, on_failure } => {
+                (WorkflowKind::Chord, jobs, Some(*callback), on_failure)
+            }
+        };
+
+        let mut jobs: Vec<Job> = requests.into_iter().map(new_job).collect();
+
+        if kind == WorkflowKind::Chain {
+            for i in 1..jobs.len() {
+                let previous = jobs[i - 1].id;
+                jobs[i].depends_on.push(previous);
+            }
+        }
+
+        let callback_id = callback.map(|req| {
+            let mut callback = new_job(req);
+            callback.depends_on.extend(jobs.iter().map(|job| job.id));
+            let callback_id = callback.id;
+            jobs.push(callback);
+            callback_id
+        });
+
+        let errback = on_failure.map(new_job);
+
+        WorkflowPlan {
+            workflow: Workflow {
+                id,
+                kind,
+                job_ids: jobs.iter().map(|job| job.id).collect(),
+                callback_id,
+                errback_id: errback.as_ref().map(|job| job.id),
+                tenant: None,
+                created_at: Utc::now(),
+            },
+            jobs,
+            errback,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(kind: &str) -> CreateJobRequest {
+        serde_json::from_value(serde_json::json!({ "kind": kind, "payload": {} })).unwrap()
+    }
+
+    #[test]
+    fn test_chain_plan_links_each_job_to_previous() {
+        let plan = CreateWorkflowRequest::Chain {
+            jobs: vec![request("a"), request("b"), request("c")],
+            on_failure: Some(request("cleanup")),
+        }
+        .into_plan();
+
+        assert_eq!(plan.workflow.kind, WorkflowKind::Chain);
+        assert!(plan.jobs[0].depends_on.is_empty());
+        assert_eq!(plan.jobs[1].depends_on, vec![plan.jobs[0].id]);
+        assert_eq!(plan.jobs[2].depends_on, vec![plan.jobs[1].id]);
+        assert_eq!(plan.workflow.errback_id, plan.errback.as_ref().map(|j| j.id));
+        assert!(plan.jobs.iter().all(|j| j.workflow_id == Some(plan.workflow.id)));
+    }
+
+    #[test]
+    fn test_chord_callback_depends_on_whole_group() {
+        let plan = CreateWorkflowRequest::Chord {
+            jobs: vec![request("a"), request("b")],
+            callback: Box::new(request("sum")),
+            on_failure: None,
+        }
+        .into_plan();
+
+        let callback = plan.jobs.last().unwrap();
+        assert_eq!(plan.workflow.callback_id, Some(callback.id));
+        assert_eq!(callback.depends_on, vec![plan.jobs[0].id, plan.jobs[1].id]);
+        assert!(plan.jobs[0].depends_on.is_empty());
+        assert!(plan.jobs[1].depends_on.is_empty());
+    }
+
+    #[test]
+    fn test_workflow_status_aggregate() {
+        use JobStatus::*;
+
+        assert_eq!(WorkflowStatus::aggregate(&[Queued, Waiting]), WorkflowStatus::Pending);
+        assert_eq!(WorkflowStatus::aggregate(&[Completed, Waiting]), WorkflowStatus::Running);
+        assert_eq!(WorkflowStatus::aggregate(&[Completed, Completed]), WorkflowStatus::Completed);
+        assert_eq!(WorkflowStatus::aggregate(&[Completed, Cancelled]), WorkflowStatus::Failed);
+    }
+}
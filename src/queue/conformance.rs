@@ -0,0 +1,829 @@
+use std::future::Future;
+use futures::StreamExt;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde_json::json;
+use uuid::Uuid;
+use crate::models::{
+    CreateWorkflowRequest, DependencyFailurePolicy, IronForgeError, Job, JobCursor, JobEventData, JobFilter, JobStatus,
+    Priority, Schedule, ScheduleRequest, TenantQuota, UniqueLock, WebhookDelivery, UniquePolicy, UniqueScope, WorkerInfo,
+};
+use super::{MemoryQueueBackend, PauseTarget, QueueBackend, RedisQueueBackend};
+
+/// Date de création fixe des jobs des scénarios (millisecondes : 100), pour
+/// des scores et un ordre de pagination connus d'avance
+const BASE_MS: i64 = 1_700_000_000_100;
+
+/// Suite de conformité : chaque scénario reçoit un backend vide et vérifie
+/// que le backend en mémoire des tests suit les mêmes règles que Redis
+async fn run_suite<Q, F, Fut>(backend: F)
+where
+    Q: QueueBackend,
+    F: Fn() -> Fut,
+    Fut: Future<Output = Q>,
+{
+    backend_answers_ping(&backend().await).await;
+    dequeue_follows_priority_then_age(&backend().await).await;
+    tenants_are_served_in_turn(&backend().await).await;
+    tenant_quotas_reject_extra_submissions(&backend().await).await;
+    list_jobs_pages_through_the_index(&backend().await).await;
+    batches_are_enqueued_and_indexed(&backend().await).await;
+    idempotency_keys_replay_or_conflict(&backend().await).await;
+    unique_locks_follow_their_holder(&backend().await).await;
+    deleted_jobs_leave_every_index(&backend().await).await;
+    transitions_are_compare_and_set(&backend().await).await;
+    modify_job_replays_on_version_conflict(&backend().await).await;
+    dead_letters_are_counted_once(&backend().await).await;
+    job_writes_publish_events(&backend().await).await;
+    webhooks_are_claimed_once_when_due(&backend().await).await;
+    delayed_jobs_wait_for_their_date(&backend().await).await;
+    dependents_follow_their_parents(&backend().await).await;
+    failed_workflow_queues_its_errback(&backend().await).await;
+    workflows_are_created_all_or_nothing(&backend().await).await;
+    parked_jobs_return_on_resume(&backend().await).await;
+    locks_and_leases_have_a_single_holder(&backend().await).await;
+    workers_and_schedules_are_registered(&backend().await).await;
+    schedule_runs_keep_the_latest_definition(&backend().await).await;
+}
+
+// Plusieurs threads : les scénarios attendent des écritures depuis des closures synchrones
+#[tokio::test(flavor = "multi_thread")]
+async fn test_memory_backend_conformance() {
+    run_suite(|| async { MemoryQueueBackend::new() }).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+#[ignore] // Nécessite Redis : la base de REDIS_URL (par défaut la 15) est vidée
+async fn test_redis_backend_conformance() {
+    run_suite(redis_backend).await;
+}
+
+/// Backend Redis sur une base vidée au préalable
+async fn redis_backend() -> RedisQueueBackend {
+    let url = std::env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379/15".to_string());
+    let client = redis::Client::open(url.as_str()).expect("Invalid REDIS_URL");
+    let mut conn = client
+        .get_multiplexed_async_connection()
+        .await
+        .expect("Failed to connect to Redis");
+    let _: () = redis::cmd("FLUSHDB").query_async(&mut conn).await.expect("Failed to flush Redis");
+
+    RedisQueueBackend::new(&url).await.expect("Failed to connect to Redis")
+}
+
+fn created_at(offset_ms: i64) -> DateTime<Utc> {
+    Utc.timestamp_millis_opt(BASE_MS + offset_ms).unwrap()
+}
+
+fn job(kind: &str, offset_ms: i64) -> Job {
+    let mut job = Job::new(kind.to_string(), json!({}));
+    job.created_at = created_at(offset_ms);
+    job
+}
+
+fn unique_job(key: &str, scope: UniqueScope, offset_ms: i64) -> Job {
+    let mut job = job("conformance.unique", offset_ms);
+    job.unique = Some(UniqueLock {
+        key: format!("conformance.unique:{}", key),
+        scope,
+        window_secs: (scope == UniqueScope::Window).then_some(60),
+        on_conflict: UniquePolicy::Merge,
+    });
+    job
+}
+
+async fn status_of<Q: QueueBackend>(queue: &Q, job_id: Uuid) -> Option<JobStatus> {
+    queue.get_job(job_id).await.unwrap().map(|job| job.status)
+}
+
+/// Démarre un job en queue, comme le ferait un worker
+async fn start<Q: QueueBackend>(queue: &Q, mut job: Job) -> Job {
+    let from = job.transition(JobStatus::Running).unwrap();
+    queue.transition_job(&mut job, from).await.unwrap();
+    job
+}
+
+/// Attend une écriture depuis une closure synchrone (ex: écriture concurrente
+/// glissée entre la lecture et l'écriture de `modify_job`)
+fn block_on<T>(future: impl Future<Output = T>) -> T {
+    tokio::task::block_in_place(|| tokio::runtime::Handle::current().block_on(future))
+}
+
+async fn backend_answers_ping<Q: QueueBackend>(queue: &Q) {
+    queue.ping().await.unwrap();
+}
+
+async fn dequeue_follows_priority_then_age<Q: QueueBackend>(queue: &Q) {
+    let mut jobs = Vec::new();
+    for (offset, priority) in [Priority::Low, Priority::Critical, Priority::Medium, Priority::High, Priority::Medium]
+        .into_iter()
+        .enumerate()
+    {
+        let mut job = job("conformance.dequeue", offset as i64);
+        job.priority = priority;
+        queue.enqueue(&job).await.unwrap();
+        jobs.push(job.id);
+    }
+
+    let mut dequeued = Vec::new();
+    for _ in 0..jobs.len() {
+        dequeued.push(queue.dequeue(1).await.unwrap().expect("queue drained early").id);
+    }
+    assert_eq!(dequeued, vec![jobs[1], jobs[3], jobs[2], jobs[4], jobs[0]]);
+    assert_eq!(queue.get_stats().await.unwrap().queue_depth, 0);
+}
+
+async fn tenants_are_served_in_turn<Q: QueueBackend>(queue: &Q) {
+    let mut jobs = Vec::new();
+    for (offset, tenant) in ["busy", "busy", "busy", "quiet"].into_iter().enumerate() {
+        let mut job = job("conformance.tenant", offset as i64);
+        job.assign_tenant(Some(tenant.to_string()));
+        jobs.push(job);
+    }
+    queue.enqueue_batch(&jobs).await.unwrap();
+    queue.enqueue(&job("conformance.tenant", 4)).await.unwrap();
+
+    let stats = queue.get_stats().await.unwrap();
+    assert_eq!(stats.queue_depth, 5);
+    assert_eq!(stats.tenant_queue_depths.get("busy"), Some(&3));
+    assert_eq!(stats.tenant_queue_depths.get("quiet"), Some(&1));
+
+    // Queue principale, puis chaque tenant à tour de rôle, par ordre alphabétique
+    let mut served = Vec::new();
+    for _ in 0..5 {
+        served.push(queue.dequeue(1).await.unwrap().expect("queue drained early").tenant);
+    }
+    let tenant = |name: &str| Some(name.to_string());
+    assert_eq!(served, vec![None, tenant("busy"), tenant("quiet"), tenant("busy"), tenant("busy")]);
+
+    // Un tenant vidé reste connu, avec une profondeur nulle
+    assert_eq!(queue.get_stats().await.unwrap().tenant_queue_depths.get("quiet"), Some(&0));
+
+    let filter = JobFilter { tenant: Some("quiet".to_string()), ..Default::default() };
+    let page = queue.list_jobs(&filter, None, 10).await.unwrap();
+    assert_eq!(page.jobs.iter().map(|job| job.id).collect::<Vec<_>>(), vec![jobs[3].id]);
+}
+
+async fn tenant_quotas_reject_extra_submissions<Q: QueueBackend>(queue: &Q) {
+    let per_minute = TenantQuota { max_queued_jobs: None, submissions_per_minute: Some(2) };
+    let error = queue.reserve_submissions("team-a", &per_minute, 3).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::QuotaExceeded(_)));
+    // Une réservation refusée n'est pas décomptée
+    queue.reserve_submissions("team-a", &per_minute, 2).await.unwrap();
+    queue.reserve_submissions("team-b", &per_minute, 2).await.unwrap();
+
+    // Les soumissions non enqueue sont rendues, sans descendre sous zéro
+    queue.release_submissions("team-a", &per_minute, 1).await.unwrap();
+    queue.reserve_submissions("team-a", &per_minute, 1).await.unwrap();
+    let error = queue.reserve_submissions("team-a", &per_minute, 1).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::QuotaExceeded(_)));
+    queue.release_submissions("team-b", &per_minute, 5).await.unwrap();
+    queue.reserve_submissions("team-b", &per_minute, 2).await.unwrap();
+    let error = queue.reserve_submissions("team-b", &per_minute, 1).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::QuotaExceeded(_)));
+
+    let max_queued = TenantQuota { max_queued_jobs: Some(2), submissions_per_minute: None };
+    let mut job = job("conformance.quota", 0);
+    job.assign_tenant(Some("team-c".to_string()));
+    queue.enqueue(&job).await.unwrap();
+    queue.reserve_submissions("team-c", &max_queued, 1).await.unwrap();
+    let error = queue.reserve_submissions("team-c", &max_queued, 2).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::QuotaExceeded(_)));
+}
+
+async fn list_jobs_pages_through_the_index<Q: QueueBackend>(queue: &Q) {
+    // Trois jobs créés à la même milliseconde : le curseur les départage par id
+    let mut jobs: Vec<Job> = [0, 1, 1, 1, 2].into_iter().map(|offset| job("conformance.list", offset)).collect();
+    jobs[4].status = JobStatus::Completed;
+    jobs[4].metadata.insert("tenant".to_string(), "acme".to_string());
+    for job in &jobs {
+        queue.update_job(job).await.unwrap();
+    }
+    queue.update_job(&job("conformance.other", 3)).await.unwrap();
+
+    let mut expected: Vec<(i64, Uuid)> =
+        jobs.iter().map(|job| (job.created_at.timestamp_millis(), job.id)).collect();
+    expected.sort_by(|a, b| b.cmp(a));
+    let expected: Vec<Uuid> = expected.into_iter().map(|(_, id)| id).collect();
+
+    let filter = JobFilter { kind: Some("conformance.list".to_string()), ..Default::default() };
+    let mut listed = Vec::new();
+    let mut cursor: Option<JobCursor> = None;
+    loop {
+        let page = queue.list_jobs(&filter, cursor, 2).await.unwrap();
+        assert!(page.jobs.len() <= 2);
+        listed.extend(page.jobs.iter().map(|job| job.id));
+        match page.next_cursor {
+            Some(next) => cursor = Some(next.parse().unwrap()),
+            None => break,
+        }
+    }
+    assert_eq!(listed, expected);
+
+    // Une page complète porte un curseur, même si rien ne suit
+    let page = queue.list_jobs(&filter, None, jobs.len()).await.unwrap();
+    let cursor = page.next_cursor.expect("full page without cursor").parse().unwrap();
+    let page = queue.list_jobs(&filter, Some(cursor), jobs.len()).await.unwrap();
+    assert!(page.jobs.is_empty());
+    assert!(page.next_cursor.is_none());
+
+    let by_status = JobFilter { status: Some(JobStatus::Completed), ..Default::default() };
+    let by_metadata = JobFilter { metadata: vec![("tenant".to_string(), "acme".to_string())], ..Default::default() };
+    let since = JobFilter { created_after: Some(created_at(2)), ..Default::default() };
+    for filter in [by_status, by_metadata] {
+        let page = queue.list_jobs(&filter, None, 10).await.unwrap();
+        assert_eq!(page.jobs.iter().map(|job| job.id).collect::<Vec<_>>(), vec![jobs[4].id]);
+    }
+    let page = queue.list_jobs(&since, None, 10).await.unwrap();
+    assert_eq!(page.jobs.len(), 2);
+}
+
+async fn batches_are_enqueued_and_indexed<Q: QueueBackend>(queue: &Q) {
+    let batch_id = Uuid::new_v4();
+    let mut jobs = Vec::new();
+    for (offset, priority) in [(0, Priority::Low), (1, Priority::High)] {
+        let mut job = job("conformance.batch", offset);
+        job.priority = priority;
+        job.batch_id = Some(batch_id);
+        jobs.push(job);
+    }
+    queue.enqueue_batch(&jobs).await.unwrap();
+    queue.enqueue_batch(&[]).await.unwrap();
+    queue.enqueue(&job("conformance.batch", 2)).await.unwrap();
+
+    let filter = JobFilter { batch_id: Some(batch_id), ..Default::default() };
+    let page = queue.list_jobs(&filter, None, 10).await.unwrap();
+    assert_eq!(page.jobs.iter().map(|job| job.id).collect::<Vec<_>>(), vec![jobs[1].id, jobs[0].id]);
+
+    assert_eq!(queue.get_stats().await.unwrap().queue_depth, 3);
+    assert_eq!(queue.dequeue(1).await.unwrap().unwrap().id, jobs[1].id);
+}
+
+async fn idempotency_keys_replay_or_conflict<Q: QueueBackend>(queue: &Q) {
+    let (original, retry) = (Uuid::new_v4(), Uuid::new_v4());
+    queue.claim_idempotency_key("order-42", "fp-a", original, 60).await.unwrap();
+
+    let replay = queue.claim_idempotency_key("order-42", "fp-a", retry, 60).await.unwrap_err();
+    assert!(matches!(replay, IronForgeError::JobAlreadyExists(id) if id == original.to_string()));
+    let conflict = queue.claim_idempotency_key("order-42", "fp-b", retry, 60).await.unwrap_err();
+    assert!(matches!(conflict, IronForgeError::IdempotencyKeyConflict(_)));
+
+    queue.release_idempotency_key("order-42").await.unwrap();
+    queue.claim_idempotency_key("order-42", "fp-b", retry, 60).await.unwrap();
+}
+
+async fn unique_locks_follow_their_holder<Q: QueueBackend>(queue: &Q) {
+    // Le verrou `queued` est libéré à l'écriture d'un statut qui ne le retient plus
+    let mut holder = unique_job("report", UniqueScope::Queued, 0);
+    holder.priority = Priority::Low;
+    assert_eq!(queue.claim_unique(&holder).await.unwrap(), None);
+    queue.enqueue(&holder).await.unwrap();
+    queue.enqueue(&job("conformance.other", 1)).await.unwrap();
+
+    let mut duplicate = unique_job("report", UniqueScope::Queued, 2);
+    duplicate.priority = Priority::Critical;
+    duplicate.metadata.insert("source".to_string(), "retry".to_string());
+    assert_eq!(queue.claim_unique(&duplicate).await.unwrap(), Some(holder.id));
+
+    // La fusion retient la priorité la plus haute et recalcule le score
+    let merged = queue.merge_unique(holder.id, &duplicate).await.unwrap().unwrap();
+    assert_eq!(merged.priority, Priority::Critical);
+    assert_eq!(merged.metadata["source"], "retry");
+    assert!(queue.merge_unique(Uuid::new_v4(), &duplicate).await.unwrap().is_none());
+    let mut running = queue.dequeue(1).await.unwrap().unwrap();
+    assert_eq!(running.id, holder.id);
+
+    running.status = JobStatus::Running;
+    queue.update_job(&running).await.unwrap();
+    assert_eq!(queue.claim_unique(&duplicate).await.unwrap(), None);
+    queue.release_unique(&holder).await.unwrap();
+    assert_eq!(queue.claim_unique(&unique_job("report", UniqueScope::Queued, 3)).await.unwrap(), Some(duplicate.id));
+    queue.release_unique(&duplicate).await.unwrap();
+
+    // Un détenteur enregistré qui ne retient plus le verrou est remplacé ;
+    // un détenteur pas encore enregistré bloque
+    let mut finished = unique_job("sync", UniqueScope::Active, 0);
+    finished.status = JobStatus::Completed;
+    queue.update_job(&finished).await.unwrap();
+    assert_eq!(queue.claim_unique(&finished).await.unwrap(), None);
+    let replacing = unique_job("sync", UniqueScope::Active, 1);
+    assert_eq!(queue.claim_unique(&replacing).await.unwrap(), None);
+    let late = unique_job("sync", UniqueScope::Active, 2);
+    assert_eq!(queue.claim_unique(&late).await.unwrap(), Some(replacing.id));
+
+    // Une fenêtre bloque quel que soit le statut du détenteur
+    let mut window = unique_job("digest", UniqueScope::Window, 0);
+    assert_eq!(queue.claim_unique(&window).await.unwrap(), None);
+    window.status = JobStatus::Completed;
+    queue.update_job(&window).await.unwrap();
+    let next = unique_job("digest", UniqueScope::Window, 1);
+    assert_eq!(queue.claim_unique(&next).await.unwrap(), Some(window.id));
+
+    // La suppression du détenteur libère le verrou
+    let deleted = unique_job("cleanup", UniqueScope::Queued, 0);
+    queue.claim_unique(&deleted).await.unwrap();
+    queue.enqueue(&deleted).await.unwrap();
+    queue.delete_job(deleted.id).await.unwrap();
+    assert_eq!(queue.claim_unique(&unique_job("cleanup", UniqueScope::Queued, 1)).await.unwrap(), None);
+}
+
+async fn deleted_jobs_leave_every_index<Q: QueueBackend>(queue: &Q) {
+    let mut job = job("conformance.delete", 0);
+    job.metadata.insert("order".to_string(), "42".to_string());
+    queue.enqueue(&job).await.unwrap();
+    queue.delete_job(job.id).await.unwrap();
+
+    assert!(queue.get_job(job.id).await.unwrap().is_none());
+    for filter in [
+        JobFilter::default(),
+        JobFilter { status: Some(JobStatus::Queued), ..Default::default() },
+        JobFilter { metadata: vec![("order".to_string(), "42".to_string())], ..Default::default() },
+    ] {
+        assert!(queue.list_jobs(&filter, None, 10).await.unwrap().jobs.is_empty());
+    }
+    assert_eq!(queue.get_stats().await.unwrap().queue_depth, 0);
+    assert!(queue.dequeue(1).await.unwrap().is_none());
+}
+
+async fn transitions_are_compare_and_set<Q: QueueBackend>(queue: &Q) {
+    let queued = job("conformance.transition", 0);
+    queue.enqueue(&queued).await.unwrap();
+
+    // Transition interdite : rien n'est écrit
+    let mut skipped = queued.clone();
+    skipped.status = JobStatus::Completed;
+    let error = queue.transition_job(&mut skipped, JobStatus::Queued).await.unwrap_err();
+    assert!(matches!(
+        error,
+        IronForgeError::InvalidStatusTransition { from: JobStatus::Queued, to: JobStatus::Completed }
+    ));
+    assert_eq!(status_of(queue, queued.id).await, Some(JobStatus::Queued));
+
+    // Deux workers partent de la même version : le second perd la course
+    let dequeued = queue.dequeue(1).await.unwrap().unwrap();
+    let running = start(queue, dequeued.clone()).await;
+    assert_eq!(running.version, 1);
+    let mut late = dequeued;
+    let from = late.transition(JobStatus::Running).unwrap();
+    let error = queue.transition_job(&mut late, from).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::VersionConflict { expected: 0, actual: 1, .. }));
+    assert_eq!(late.version, 0);
+
+    // Un retour en Queued replace le job dans sa queue d'exécution
+    let mut retried = running;
+    let from = retried.transition(JobStatus::Queued).unwrap();
+    queue.transition_job(&mut retried, from).await.unwrap();
+    assert_eq!(queue.dequeue(1).await.unwrap().unwrap().id, queued.id);
+
+    let mut missing = job("conformance.transition", 1);
+    let from = missing.transition(JobStatus::Running).unwrap();
+    let error = queue.transition_job(&mut missing, from).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::JobNotFound(_)));
+}
+
+async fn modify_job_replays_on_version_conflict<Q: QueueBackend>(queue: &Q) {
+    let job = job("conformance.modify", 0);
+    queue.enqueue(&job).await.unwrap();
+
+    // Une écriture concurrente a lieu entre la première lecture et l'écriture
+    let mut attempts = 0;
+    let written = queue
+        .modify_job(job.id, None, |latest| {
+            attempts += 1;
+            if attempts == 1 {
+                let mut concurrent = latest.clone();
+                concurrent.metadata.insert("owner".to_string(), "ops".to_string());
+                block_on(queue.update_job_if_version(&mut concurrent)).unwrap();
+            }
+            latest.priority = Priority::High;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(attempts, 2);
+    assert_eq!(written.priority, Priority::High);
+    assert_eq!(written.metadata["owner"], "ops");
+    assert_eq!(written.version, 2);
+    assert_eq!(queue.get_job(job.id).await.unwrap().unwrap().version, 2);
+
+    let mut stale = job.clone();
+    let error = queue.update_job_if_version(&mut stale).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::VersionConflict { expected: 0, actual: 2, .. }));
+    assert_eq!(stale.version, 0);
+
+    // La transition rejouée doit rester permise depuis le statut relu
+    let mut attempts = 0;
+    let cancelled = queue
+        .modify_job(job.id, Some(JobStatus::Cancelled), |latest| {
+            attempts += 1;
+            if attempts == 1 {
+                let mut started = latest.clone();
+                let from = started.transition(JobStatus::Running).unwrap();
+                block_on(queue.transition_job(&mut started, from)).unwrap();
+            }
+            Ok(())
+        })
+        .await;
+    assert!(matches!(
+        cancelled,
+        Err(IronForgeError::InvalidStatusTransition { from: JobStatus::Running, to: JobStatus::Cancelled })
+    ));
+    assert_eq!(status_of(queue, job.id).await, Some(JobStatus::Running));
+
+    let error = queue.modify_job(Uuid::new_v4(), None, |_| Ok(())).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::JobNotFound(_)));
+}
+
+async fn dead_letters_are_counted_once<Q: QueueBackend>(queue: &Q) {
+    let job = job("conformance.dlq", 0);
+    queue.enqueue(&job).await.unwrap();
+    let mut job = start(queue, job).await;
+    job.last_error = Some("boom".to_string());
+    queue.move_to_dlq(&job).await.unwrap();
+
+    // Un second passage part d'un état périmé et n'est pas appliqué
+    let error = queue.move_to_dlq(&job).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::InvalidStatusTransition { from: JobStatus::DeadLetter, .. }));
+    assert_eq!(queue.get_stats().await.unwrap().dlq_depth, 1);
+
+    let filter = JobFilter { status: Some(JobStatus::DeadLetter), ..Default::default() };
+    let page = queue.list_jobs(&filter, None, 10).await.unwrap();
+    assert_eq!(page.jobs[0].last_error.as_deref(), Some("boom"));
+
+    // Une fois réinjecté, le job quitte la DLQ
+    job.status = JobStatus::Queued;
+    queue.enqueue(&job).await.unwrap();
+    assert_eq!(queue.get_stats().await.unwrap().dlq_depth, 0);
+    assert!(queue.list_jobs(&filter, None, 10).await.unwrap().jobs.is_empty());
+}
+
+async fn job_writes_publish_events<Q: QueueBackend>(queue: &Q) {
+    let mut events = queue.subscribe_events().await.unwrap();
+
+    let mut job = job("conformance.events", 0);
+    queue.enqueue(&job).await.unwrap();
+    queue.publish_progress(&job, json!({ "percent": 50 })).await.unwrap();
+    job.status = JobStatus::Completed;
+    job.result = Some(json!({ "rows": 3 }));
+    queue.update_job(&job).await.unwrap();
+
+    let mut received = Vec::new();
+    for _ in 0..3 {
+        let event = tokio::time::timeout(std::time::Duration::from_secs(5), events.next())
+            .await
+            .expect("no event received")
+            .expect("event stream ended");
+        assert_eq!(event.job_id, job.id);
+        received.push(event.data);
+    }
+    assert_eq!(
+        received,
+        vec![
+            JobEventData::Status { status: JobStatus::Queued, result: None, error: None },
+            JobEventData::Progress { progress: json!({ "percent": 50 }) },
+            JobEventData::Status { status: JobStatus::Completed, result: Some(json!({ "rows": 3 })), error: None },
+        ]
+    );
+}
+
+async fn webhooks_are_claimed_once_when_due<Q: QueueBackend>(queue: &Q) {
+    let mut job = job("conformance.webhook", 0);
+    job.callback_url = Some("https://hooks.example.com/jobs".to_string());
+    job.status = JobStatus::Running;
+    queue.update_job(&job).await.unwrap();
+    assert!(queue.claim_due_webhooks(Utc::now(), 10, 60).await.unwrap().is_empty());
+
+    // La fin du job planifie la première livraison, réservée une seule fois
+    job.status = JobStatus::Completed;
+    queue.update_job(&job).await.unwrap();
+    let now = Utc::now();
+    assert_eq!(queue.claim_due_webhooks(now, 10, 60).await.unwrap(), vec![(job.id, 1)]);
+    assert!(queue.claim_due_webhooks(now, 10, 60).await.unwrap().is_empty());
+
+    // Une livraison non terminée à la fin du bail est reprise
+    let expired = now + Duration::seconds(61);
+    assert_eq!(queue.claim_due_webhooks(expired, 10, 60).await.unwrap(), vec![(job.id, 1)]);
+
+    // Un échec termine la tentative et planifie la suivante
+    let retry_at = now + Duration::seconds(120);
+    queue.complete_webhook(job.id, 1, Some(retry_at)).await.unwrap();
+    assert!(queue.claim_due_webhooks(now + Duration::seconds(90), 10, 60).await.unwrap().is_empty());
+    assert_eq!(queue.claim_due_webhooks(retry_at, 10, 60).await.unwrap(), vec![(job.id, 2)]);
+    queue.complete_webhook(job.id, 2, None).await.unwrap();
+    assert!(queue.claim_due_webhooks(retry_at + Duration::hours(1), 10, 60).await.unwrap().is_empty());
+
+    let delivery = WebhookDelivery {
+        attempt: 1,
+        status: JobStatus::Completed,
+        attempted_at: Utc::now(),
+        response_status: Some(500),
+        error: None,
+        success: false,
+        next_attempt_at: Some(retry_at),
+    };
+    queue.record_webhook_delivery(job.id, &delivery).await.unwrap();
+    let stored = queue.get_job(job.id).await.unwrap().unwrap();
+    assert_eq!(stored.status, JobStatus::Completed);
+    assert_eq!(stored.webhook_deliveries.len(), 1);
+    assert_eq!(stored.webhook_deliveries[0].response_status, Some(500));
+}
+
+async fn delayed_jobs_wait_for_their_date<Q: QueueBackend>(queue: &Q) {
+    let now = Utc::now();
+    let mut later = job("conformance.delayed", 0);
+    later.scheduled_for = Some(now + Duration::hours(1));
+    queue.enqueue(&later).await.unwrap();
+    let mut due = job("conformance.delayed", 1);
+    due.scheduled_for = Some(now - Duration::seconds(1));
+    queue.enqueue(&due).await.unwrap();
+
+    let stats = queue.get_stats().await.unwrap();
+    assert_eq!((stats.queue_depth, stats.delayed_jobs), (1, 1));
+    assert_eq!(queue.dequeue(1).await.unwrap().unwrap().id, due.id);
+    assert!(queue.dequeue(1).await.unwrap().is_none());
+    assert_eq!(queue.promote_delayed(now, 10).await.unwrap(), 0);
+
+    // Avancer la date d'exécution suffit : le job est promu à échéance, une seule fois
+    queue
+        .modify_job(later.id, None, |job| {
+            job.scheduled_for = Some(now + Duration::minutes(1));
+            Ok(())
+        })
+        .await
+        .unwrap();
+    assert_eq!(queue.promote_delayed(now + Duration::minutes(2), 10).await.unwrap(), 1);
+    assert_eq!(queue.promote_delayed(now + Duration::minutes(2), 10).await.unwrap(), 0);
+    assert_eq!(queue.get_stats().await.unwrap().delayed_jobs, 0);
+    assert_eq!(queue.dequeue(1).await.unwrap().unwrap().id, later.id);
+
+    // Différer un job en queue le retire de sa queue d'exécution
+    let soon = job("conformance.delayed", 2);
+    queue.enqueue(&soon).await.unwrap();
+    queue
+        .modify_job(soon.id, None, |job| {
+            job.scheduled_for = Some(now + Duration::hours(1));
+            Ok(())
+        })
+        .await
+        .unwrap();
+    let stats = queue.get_stats().await.unwrap();
+    assert_eq!((stats.queue_depth, stats.delayed_jobs), (0, 1));
+
+    queue.delete_job(soon.id).await.unwrap();
+    assert_eq!(queue.get_stats().await.unwrap().delayed_jobs, 0);
+    assert_eq!(queue.promote_delayed(now + Duration::hours(2), 10).await.unwrap(), 0);
+
+    // Un job remis en queue avec un backoff attend parmi les jobs différés
+    let retried = job("conformance.delayed", 3);
+    queue.enqueue(&retried).await.unwrap();
+    start(queue, queue.dequeue(1).await.unwrap().unwrap()).await;
+    queue
+        .modify_job(retried.id, Some(JobStatus::Queued), |job| {
+            job.scheduled_for = Some(now + Duration::minutes(1));
+            Ok(())
+        })
+        .await
+        .unwrap();
+    let stats = queue.get_stats().await.unwrap();
+    assert_eq!((stats.queue_depth, stats.delayed_jobs), (0, 1));
+    assert_eq!(queue.promote_delayed(now + Duration::minutes(2), 10).await.unwrap(), 1);
+    assert_eq!(queue.dequeue(1).await.unwrap().unwrap().id, retried.id);
+}
+
+async fn dependents_follow_their_parents<Q: QueueBackend>(queue: &Q) {
+    let parent = job("conformance.parent", 0);
+    queue.enqueue(&parent).await.unwrap();
+
+    let mut child = job("conformance.child", 1);
+    child.depends_on = vec![parent.id];
+    assert_eq!(queue.enqueue_with_dependencies(&child).await.unwrap(), JobStatus::Waiting);
+
+    let mut orphan = job("conformance.child", 2);
+    orphan.depends_on = vec![Uuid::new_v4()];
+    let error = queue.enqueue_with_dependencies(&orphan).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::JobNotFound(_)));
+
+    // Le dépendant reçoit le résultat de son parent
+    let mut completed = queue.get_job(parent.id).await.unwrap().unwrap();
+    completed.status = JobStatus::Completed;
+    completed.result = Some(json!({ "rows": 3 }));
+    queue.update_job(&completed).await.unwrap();
+    queue.resolve_dependents(parent.id).await.unwrap();
+
+    let child = queue.get_job(child.id).await.unwrap().unwrap();
+    assert_eq!(child.status, JobStatus::Queued);
+    assert_eq!(child.dependency_results, vec![json!({ "rows": 3 })]);
+
+    // L'échec se propage selon la politique de chaque dépendant
+    let failing = job("conformance.parent", 3);
+    queue.enqueue(&failing).await.unwrap();
+    let mut failed_child = job("conformance.child", 4);
+    failed_child.depends_on = vec![failing.id];
+    failed_child.on_dependency_failure = DependencyFailurePolicy::Fail;
+    queue.enqueue_with_dependencies(&failed_child).await.unwrap();
+    let mut grandchild = job("conformance.child", 5);
+    grandchild.depends_on = vec![failed_child.id];
+    queue.enqueue_with_dependencies(&grandchild).await.unwrap();
+
+    let failing = start(queue, failing).await;
+    queue.move_to_dlq(&failing).await.unwrap();
+    queue.resolve_dependents(failing.id).await.unwrap();
+    assert_eq!(status_of(queue, failed_child.id).await, Some(JobStatus::Failed));
+    assert_eq!(status_of(queue, grandchild.id).await, Some(JobStatus::Cancelled));
+    assert_eq!(queue.get_stats().await.unwrap().dlq_depth, 1);
+}
+
+async fn failed_workflow_queues_its_errback<Q: QueueBackend>(queue: &Q) {
+    let request: CreateWorkflowRequest = serde_json::from_value(json!({
+        "type": "chain",
+        "jobs": [
+            { "kind": "conformance.fetch", "payload": {} },
+            { "kind": "conformance.render", "payload": {} }
+        ],
+        "on_failure": { "kind": "conformance.alert", "payload": {} }
+    }))
+    .unwrap();
+    let plan = request.into_plan();
+    let (first, second) = (plan.jobs[0].id, plan.jobs[1].id);
+    let errback_id = plan.workflow.errback_id.unwrap();
+    queue.create_workflow(&plan).await.unwrap();
+
+    let workflow = queue.get_workflow(plan.workflow.id).await.unwrap().unwrap();
+    assert_eq!(workflow.job_ids, vec![first, second]);
+    assert_eq!(status_of(queue, first).await, Some(JobStatus::Queued));
+    assert_eq!(status_of(queue, second).await, Some(JobStatus::Waiting));
+    assert_eq!(status_of(queue, errback_id).await, Some(JobStatus::Waiting));
+
+    let first_job = queue.dequeue(1).await.unwrap().unwrap();
+    assert_eq!(first_job.id, first);
+    let first_job = start(queue, first_job).await;
+    queue.move_to_dlq(&first_job).await.unwrap();
+    queue.resolve_dependents(first).await.unwrap();
+
+    assert_eq!(status_of(queue, second).await, Some(JobStatus::Cancelled));
+    let errback = queue.get_job(errback_id).await.unwrap().unwrap();
+    assert_eq!(errback.status, JobStatus::Queued);
+    assert_eq!(errback.metadata["failed_job_ids"], first.to_string());
+    assert_eq!(queue.dequeue(1).await.unwrap().unwrap().id, errback_id);
+}
+
+async fn workflows_are_created_all_or_nothing<Q: QueueBackend>(queue: &Q) {
+    let chain = |external: Uuid| -> CreateWorkflowRequest {
+        serde_json::from_value(json!({
+            "type": "chain",
+            "jobs": [
+                { "kind": "conformance.fetch", "payload": {} },
+                { "kind": "conformance.render", "payload": {}, "depends_on": [external] }
+            ],
+            "on_failure": { "kind": "conformance.alert", "payload": {} }
+        }))
+        .unwrap()
+    };
+
+    let plan = chain(Uuid::new_v4()).into_plan();
+    let error = queue.create_workflow(&plan).await.unwrap_err();
+    assert!(matches!(error, IronForgeError::JobNotFound(_)));
+    assert!(queue.get_workflow(plan.workflow.id).await.unwrap().is_none());
+    assert!(queue.list_jobs(&JobFilter::default(), None, 10).await.unwrap().jobs.is_empty());
+    assert_eq!(queue.get_stats().await.unwrap().queue_depth, 0);
+
+    // Un parent extérieur déjà terminé ne bloque que sur les parents internes
+    let mut parent = job("conformance.parent", 0);
+    parent.status = JobStatus::Completed;
+    queue.update_job(&parent).await.unwrap();
+    let plan = chain(parent.id).into_plan();
+    let (first, second) = (plan.jobs[0].id, plan.jobs[1].id);
+    queue.create_workflow(&plan).await.unwrap();
+    assert_eq!(status_of(queue, first).await, Some(JobStatus::Queued));
+    assert_eq!(status_of(queue, second).await, Some(JobStatus::Waiting));
+
+    let first_job = start(queue, queue.dequeue(1).await.unwrap().unwrap()).await;
+    assert_eq!(first_job.id, first);
+    queue.modify_job(first, Some(JobStatus::Completed), |_| Ok(())).await.unwrap();
+    queue.resolve_dependents(first).await.unwrap();
+    assert_eq!(status_of(queue, second).await, Some(JobStatus::Queued));
+}
+
+async fn parked_jobs_return_on_resume<Q: QueueBackend>(queue: &Q) {
+    let emails = PauseTarget::Queue("emails".to_string());
+    queue.pause(&emails).await.unwrap();
+    queue.pause(&PauseTarget::Kind("conformance.report".to_string())).await.unwrap();
+    assert_eq!(queue.paused_targets().await.unwrap().queues, vec!["emails".to_string()]);
+
+    let mut job = job("conformance.email", 0);
+    job.queue = "emails".to_string();
+    queue.enqueue(&job).await.unwrap();
+    let dequeued = queue.dequeue(1).await.unwrap().unwrap();
+    queue.park_job(&dequeued).await.unwrap();
+    assert_eq!(queue.get_stats().await.unwrap().parked_jobs, 1);
+
+    assert_eq!(queue.resume(&emails).await.unwrap(), 1);
+    let paused = queue.paused_targets().await.unwrap();
+    assert!(paused.queues.is_empty());
+    assert_eq!(paused.kinds, vec!["conformance.report".to_string()]);
+    assert_eq!(queue.get_stats().await.unwrap().parked_jobs, 0);
+    assert_eq!(queue.dequeue(1).await.unwrap().unwrap().id, job.id);
+}
+
+async fn locks_and_leases_have_a_single_holder<Q: QueueBackend>(queue: &Q) {
+    let job_id = Uuid::new_v4();
+    assert!(queue.acquire_lock(job_id, 30).await.unwrap());
+    assert!(!queue.acquire_lock(job_id, 30).await.unwrap());
+    assert_eq!(queue.get_stats().await.unwrap().active_jobs, 1);
+    queue.release_lock(job_id).await.unwrap();
+    assert!(queue.acquire_lock(job_id, 30).await.unwrap());
+    queue.release_lock(job_id).await.unwrap();
+
+    assert!(queue.try_acquire_leadership("conformance", "instance-a", 30).await.unwrap());
+    assert!(!queue.try_acquire_leadership("conformance", "instance-b", 30).await.unwrap());
+    assert!(queue.try_acquire_leadership("conformance", "instance-a", 30).await.unwrap());
+    assert_eq!(queue.current_leader("conformance").await.unwrap().as_deref(), Some("instance-a"));
+    assert!(queue.current_leader("other").await.unwrap().is_none());
+}
+
+async fn workers_and_schedules_are_registered<Q: QueueBackend>(queue: &Q) {
+    let worker = WorkerInfo::new("instance-a/1".to_string(), vec!["conformance.job".to_string()], 2);
+    queue.register_worker(&worker, 30).await.unwrap();
+    let workers = queue.list_workers().await.unwrap();
+    assert_eq!(workers.iter().map(|w| w.id.as_str()).collect::<Vec<_>>(), vec!["instance-a/1"]);
+    queue.unregister_worker(&worker.id).await.unwrap();
+    assert!(queue.get_worker(&worker.id).await.unwrap().is_none());
+    assert!(queue.list_workers().await.unwrap().is_empty());
+
+    let mut schedules = Vec::new();
+    for (offset, name) in [(1, "second"), (0, "first")] {
+        let request: ScheduleRequest = serde_json::from_value(json!({
+            "name": name,
+            "cron": "0 0 3 * * *",
+            "job": { "kind": "conformance.report", "payload": {} }
+        }))
+        .unwrap();
+        let mut schedule = Schedule::from_request(request).unwrap();
+        schedule.created_at = created_at(offset);
+        queue.save_schedule(&schedule).await.unwrap();
+        schedules.push(schedule);
+    }
+
+    let listed: Vec<String> = queue.list_schedules().await.unwrap().into_iter().map(|s| s.name).collect();
+    assert_eq!(listed, vec!["first".to_string(), "second".to_string()]);
+    assert_eq!(queue.get_schedule(schedules[0].id).await.unwrap().unwrap().name, "second");
+    assert!(queue.delete_schedule(schedules[0].id).await.unwrap());
+    assert!(!queue.delete_schedule(schedules[0].id).await.unwrap());
+    assert!(queue.get_schedule(schedules[0].id).await.unwrap().is_none());
+}
+
+async fn schedule_runs_keep_the_latest_definition<Q: QueueBackend>(queue: &Q) {
+    let request: ScheduleRequest = serde_json::from_value(json!({
+        "name": "nightly",
+        "cron": "0 0 3 * * *",
+        "job": { "kind": "conformance.report", "payload": {} }
+    }))
+    .unwrap();
+    let mut schedule = Schedule::from_request(request).unwrap();
+    queue.save_schedule(&schedule).await.unwrap();
+    let lease = "conformance.scheduler";
+    assert!(queue.try_acquire_leadership(lease, "instance-a", 30).await.unwrap());
+
+    // Le scheduler enregistre une exécution pendant que la définition change
+    let mut ran = schedule.clone();
+    ran.last_run_at = Some(created_at(0));
+    ran.last_job_id = Some(Uuid::new_v4());
+    ran.next_run_at = Some(created_at(60_000));
+    schedule.name = "renamed".to_string();
+    queue.save_schedule(&schedule).await.unwrap();
+    assert!(queue.record_schedule_run(&ran, lease, "instance-a").await.unwrap());
+
+    let stored = queue.get_schedule(schedule.id).await.unwrap().unwrap();
+    assert_eq!(stored.name, "renamed");
+    assert_eq!(stored.next_run_at, ran.next_run_at);
+    assert_eq!((stored.last_run_at, stored.last_job_id), (ran.last_run_at, ran.last_job_id));
+
+    // Une instance qui n'a pas le bail n'enregistre rien
+    let mut stale = ran.clone();
+    stale.last_job_id = Some(Uuid::new_v4());
+    assert!(matches!(
+        queue.record_schedule_run(&stale, lease, "instance-b").await,
+        Err(IronForgeError::LeadershipLost(_))
+    ));
+    let stored = queue.get_schedule(schedule.id).await.unwrap().unwrap();
+    assert_eq!(stored.last_job_id, ran.last_job_id);
+
+    // Une nouvelle définition fixe la prochaine exécution, l'historique reste
+    schedule.next_run_at = Some(created_at(120_000));
+    queue.save_schedule(&schedule).await.unwrap();
+    let listed = queue.list_schedules().await.unwrap();
+    assert_eq!(listed[0].next_run_at, schedule.next_run_at);
+    assert_eq!(listed[0].last_job_id, ran.last_job_id);
+
+    // Après la dernière occurrence, il n'y a plus de prochaine exécution
+    ran.next_run_at = None;
+    ran.last_job_id = None;
+    assert!(queue.record_schedule_run(&ran, lease, "instance-a").await.unwrap());
+    let stored = queue.get_schedule(schedule.id).await.unwrap().unwrap();
+    assert_eq!(stored.next_run_at, None);
+    assert!(stored.last_job_id.is_some());
+
+    assert!(queue.delete_schedule(schedule.id).await.unwrap());
+    assert!(!queue.record_schedule_run(&ran, lease, "instance-a").await.unwrap());
+    assert!(queue.get_schedule(schedule.id).await.unwrap().is_none());
+}
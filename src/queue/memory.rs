@@ -0,0 +1,860 @@
+use async_trait::async_trait;
+use futures::stream::{self, BoxStream, StreamExt};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+use crate::models::{
+    DependencyState, IronForgeError, Job, JobCursor, JobEvent, JobFilter, JobPage, JobStatus, Result,
+    Schedule, TenantQuota, UniqueScope, WebhookDelivery, WorkerInfo, Workflow, WorkflowPlan, WorkflowStatus,
+    WEBHOOK_STATUSES,
+};
+use chrono::{DateTime, Utc};
+use super::traits::{QueueBackend, QueueStats, PauseTarget, PausedTargets};
+
+/// Backend en mémoire pour les tests : mêmes règles que le backend Redis
+/// (dépendances, workflows, pauses, bails de leader, événements), sans expiration.
+#[derive(Default)]
+pub struct MemoryQueueBackend {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    jobs: HashMap<Uuid, Job>,
+    /// Jobs prêts à être exécutés, avec leur score
+    ready: HashMap<Uuid, i64>,
+    /// Tenants ayant eu des jobs en queue (leur queue d'exécution existe)
+    tenants: BTreeSet<String>,
+    /// Dernière queue servie par un dequeue (`None` : la queue principale)
+    last_served: Option<Option<String>>,
+    parked: HashMap<Uuid, i64>,
+    /// Jobs différés, par date d'exécution
+    delayed: HashMap<Uuid, i64>,
+    locks: HashSet<Uuid>,
+    dependents: HashMap<Uuid, HashSet<Uuid>>,
+    dependencies_resolved: HashSet<Uuid>,
+    errbacks_settled: HashSet<Uuid>,
+    workflows: HashMap<Uuid, Workflow>,
+    workers: HashMap<String, WorkerInfo>,
+    paused: PausedTargets,
+    schedules: HashMap<Uuid, Schedule>,
+    /// Avancement enregistré par le scheduler, prioritaire sur la définition
+    schedule_runs: HashMap<Uuid, ScheduleRun>,
+    leaders: HashMap<String, String>,
+    /// Réservations des clés d'idempotence : `empreinte:job_id`
+    idempotency_keys: HashMap<String, String>,
+    /// Soumissions comptées par tenant (sans fenêtre de temps)
+    submissions: HashMap<String, u64>,
+    /// Détenteur de chaque verrou d'unicité
+    unique: HashMap<String, Uuid>,
+    /// Livraisons de webhooks planifiées (job, tentative), avec leur échéance en ms
+    webhooks: HashMap<(Uuid, u32), i64>,
+    events: Events,
+}
+
+#[derive(Default)]
+struct ScheduleRun {
+    last_run_at: Option<DateTime<Utc>>,
+    last_job_id: Option<Uuid>,
+}
+
+/// Canal des événements de jobs, publiés à chaque écriture
+struct Events(broadcast::Sender<JobEvent>);
+
+impl Default for Events {
+    fn default() -> Self {
+        Self(broadcast::channel(1024).0)
+    }
+}
+
+impl State {
+    /// Enregistre le job, en libérant son verrou d'unicité si son statut ne le retient plus
+    fn store(&mut self, job: &Job) {
+        if let Some(unique) = &job.unique {
+            if unique.scope != UniqueScope::Window
+                && !unique.scope.holds(job.status)
+                && self.unique.get(&unique.key) == Some(&job.id)
+            {
+                self.unique.remove(&unique.key);
+            }
+        }
+        self.jobs.insert(job.id, job.clone());
+        // Aucun abonné : l'événement est perdu, comme avec PUBLISH
+        let _ = self.events.0.send(JobEvent::status(job));
+    }
+
+    /// Place le job dans sa queue d'exécution (celle de son tenant, sinon la principale),
+    /// ou parmi les jobs différés s'il n'est pas encore exécutable
+    fn push_ready(&mut self, job: &Job) {
+        match job.scheduled_for {
+            Some(scheduled_for) if job.is_delayed(Utc::now()) => {
+                self.delayed.insert(job.id, scheduled_for.timestamp_millis());
+            }
+            _ => {
+                self.ready.insert(job.id, job.calculate_redis_score());
+            }
+        }
+        if let Some(tenant) = &job.tenant {
+            self.tenants.insert(tenant.clone());
+        }
+    }
+
+    /// Planification telle que vue par le scheduler (définition et avancement)
+    fn schedule(&self, schedule: &Schedule) -> Schedule {
+        let mut schedule = schedule.clone();
+        if let Some(run) = self.schedule_runs.get(&schedule.id) {
+            schedule.last_run_at = run.last_run_at.or(schedule.last_run_at);
+            schedule.last_job_id = run.last_job_id.or(schedule.last_job_id);
+        }
+        schedule
+    }
+
+    /// Vérifie que le job stocké est encore à la version de `job`
+    fn check_version(&self, job: &Job) -> Result<()> {
+        match self.jobs.get(&job.id).map(|stored| stored.version) {
+            None => Err(IronForgeError::JobNotFound(job.id.to_string())),
+            Some(actual) if actual != job.version => Err(IronForgeError::VersionConflict {
+                job_id: job.id.to_string(),
+                expected: job.version,
+                actual,
+            }),
+            Some(_) => Ok(()),
+        }
+    }
+
+    /// Programme la première livraison du webhook d'un job terminé
+    fn schedule_first_webhook(&mut self, job: &Job) {
+        if job.callback_url.is_some() && WEBHOOK_STATUSES.contains(&job.status) {
+            self.webhooks.insert((job.id, 1), Utc::now().timestamp_millis());
+        }
+    }
+}
+
+impl MemoryQueueBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap()
+    }
+
+    /// Réévalue les dépendances d'un job en Waiting et le débloque ou l'annule
+    async fn settle_dependencies(&self, job: &Job) -> Result<JobStatus> {
+        let parents: Vec<Option<Job>> = {
+            let state = self.state();
+            job.depends_on.iter().map(|id| state.jobs.get(id).cloned()).collect()
+        };
+        let statuses: Vec<Option<JobStatus>> = parents
+            .iter()
+            .map(|parent| parent.as_ref().map(|p| p.status))
+            .collect();
+
+        let next_status = match DependencyState::evaluate(&statuses) {
+            DependencyState::Pending => return Ok(JobStatus::Waiting),
+            DependencyState::Ready => JobStatus::Queued,
+            DependencyState::Failed => job.on_dependency_failure.status(),
+        };
+
+        if !self.state().dependencies_resolved.insert(job.id) {
+            return Ok(next_status);
+        }
+
+        let dependency_results: Vec<serde_json::Value> = match next_status {
+            JobStatus::Queued => parents
+                .into_iter()
+                .map(|parent| parent.and_then(|p| p.result).unwrap_or(serde_json::Value::Null))
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        self.modify_job(job.id, Some(next_status), |settled| {
+            settled.dependency_results = dependency_results.clone();
+            Ok(())
+        })
+        .await?;
+
+        if next_status != JobStatus::Queued {
+            self.resolve_dependents(job.id).await?;
+        }
+
+        Ok(next_status)
+    }
+
+    /// Déclenche l'errback d'un workflow en échec, ou l'annule s'il a réussi
+    async fn settle_workflow(&self, workflow_id: Uuid) -> Result<()> {
+        let (errback_id, statuses, failed_jobs) = {
+            let state = self.state();
+            let Some(workflow) = state.workflows.get(&workflow_id) else {
+                return Ok(());
+            };
+            let Some(errback_id) = workflow.errback_id else {
+                return Ok(());
+            };
+            let statuses: Vec<JobStatus> = workflow
+                .job_ids
+                .iter()
+                .map(|id| state.jobs.get(id).map_or(JobStatus::Cancelled, |job| job.status))
+                .collect();
+            let failed_jobs: Vec<String> = workflow
+                .job_ids
+                .iter()
+                .zip(&statuses)
+                .filter(|(_, status)| status.is_terminal() && **status != JobStatus::Completed)
+                .map(|(id, _)| id.to_string())
+                .collect();
+            (errback_id, statuses, failed_jobs)
+        };
+
+        let aggregate = WorkflowStatus::aggregate(&statuses);
+        if !matches!(aggregate, WorkflowStatus::Failed | WorkflowStatus::Completed)
+            || !self.state().errbacks_settled.insert(workflow_id)
+        {
+            return Ok(());
+        }
+
+        let settled = if aggregate == WorkflowStatus::Failed {
+            let failed_job_ids = failed_jobs.join(",");
+            self.modify_job(errback_id, Some(JobStatus::Queued), |errback| {
+                errback.metadata.insert("failed_job_ids".to_string(), failed_job_ids.clone());
+                Ok(())
+            })
+            .await
+        } else {
+            self.modify_job(errback_id, Some(JobStatus::Cancelled), |_| Ok(())).await
+        };
+
+        match settled {
+            Ok(_) | Err(IronForgeError::JobNotFound(_)) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[async_trait]
+impl QueueBackend for MemoryQueueBackend {
+    async fn enqueue(&self, job: &Job) -> Result<()> {
+        let mut state = self.state();
+        state.store(job);
+        state.push_ready(job);
+
+        Ok(())
+    }
+
+    async fn reserve_submissions(&self, tenant: &str, quota: &TenantQuota, count: u64) -> Result<()> {
+        let mut state = self.state();
+
+        if let Some(max) = quota.max_queued_jobs {
+            let queued = state
+                .ready
+                .keys()
+                .filter(|id| state.jobs.get(id).is_some_and(|job| job.tenant.as_deref() == Some(tenant)))
+                .count() as u64;
+            if queued + count > max {
+                return Err(IronForgeError::QuotaExceeded(format!(
+                    "tenant {} has {} queued jobs (max {})",
+                    tenant, queued, max
+                )));
+            }
+        }
+
+        if let Some(limit) = quota.submissions_per_minute {
+            let used = state.submissions.entry(tenant.to_string()).or_default();
+            if *used + count > limit {
+                return Err(IronForgeError::QuotaExceeded(format!(
+                    "tenant {} exceeded {} submissions per minute",
+                    tenant, limit
+                )));
+            }
+            *used += count;
+        }
+
+        Ok(())
+    }
+
+    async fn release_submissions(&self, tenant: &str, quota: &TenantQuota, count: u64) -> Result<()> {
+        if quota.submissions_per_minute.is_none() {
+            return Ok(());
+        }
+
+        if let Some(used) = self.state().submissions.get_mut(tenant) {
+            *used = used.saturating_sub(count);
+        }
+
+        Ok(())
+    }
+
+    async fn claim_idempotency_key(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        job_id: Uuid,
+        _ttl_secs: u64,
+    ) -> Result<()> {
+        let mut state = self.state();
+        let Some(existing) = state.idempotency_keys.get(key) else {
+            state.idempotency_keys.insert(key.to_string(), format!("{}:{}", fingerprint, job_id));
+            return Ok(());
+        };
+
+        match existing.split_once(':') {
+            Some((existing_fingerprint, existing_job_id)) if existing_fingerprint == fingerprint => {
+                Err(IronForgeError::JobAlreadyExists(existing_job_id.to_string()))
+            }
+            _ => Err(IronForgeError::IdempotencyKeyConflict(key.to_string())),
+        }
+    }
+
+    async fn release_idempotency_key(&self, key: &str) -> Result<()> {
+        self.state().idempotency_keys.remove(key);
+
+        Ok(())
+    }
+
+    async fn claim_unique(&self, job: &Job) -> Result<Option<Uuid>> {
+        let Some(unique) = &job.unique else {
+            return Ok(None);
+        };
+
+        let mut state = self.state();
+        if let Some(&holder_id) = state.unique.get(&unique.key) {
+            // Un détenteur introuvable est en cours de soumission : il bloque
+            let stale = state.jobs.get(&holder_id).is_some_and(|holder| {
+                unique.scope != UniqueScope::Window
+                    && !holder.unique.as_ref().is_some_and(|lock| lock.scope.holds(holder.status))
+            });
+            if !stale {
+                return Ok(Some(holder_id));
+            }
+        }
+        state.unique.insert(unique.key.clone(), job.id);
+
+        Ok(None)
+    }
+
+    async fn release_unique(&self, job: &Job) -> Result<()> {
+        if let Some(unique) = &job.unique {
+            let mut state = self.state();
+            if state.unique.get(&unique.key) == Some(&job.id) {
+                state.unique.remove(&unique.key);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn merge_unique(&self, existing_id: Uuid, duplicate: &Job) -> Result<Option<Job>> {
+        let mut state = self.state();
+        let Some(mut existing) = state.jobs.get(&existing_id).cloned() else {
+            return Ok(None);
+        };
+
+        existing.merge_duplicate(duplicate);
+        existing.version += 1;
+        state.store(&existing);
+        // Recalcule le score si le job est encore dans la queue principale
+        if let Some(score) = state.ready.get_mut(&existing_id) {
+            *score = existing.calculate_redis_score();
+        }
+
+        Ok(Some(existing))
+    }
+
+    async fn enqueue_batch(&self, jobs: &[Job]) -> Result<()> {
+        let mut state = self.state();
+        for job in jobs {
+            state.store(job);
+            state.push_ready(job);
+        }
+
+        Ok(())
+    }
+
+    async fn enqueue_with_dependencies(&self, job: &Job) -> Result<JobStatus> {
+        if job.depends_on.is_empty() {
+            self.enqueue(job).await?;
+            return Ok(job.status);
+        }
+
+        let mut waiting = job.clone();
+        waiting.status = JobStatus::Waiting;
+        {
+            let mut state = self.state();
+            if let Some(missing) = job.depends_on.iter().find(|id| !state.jobs.contains_key(id)) {
+                return Err(IronForgeError::JobNotFound(missing.to_string()));
+            }
+            state.store(&waiting);
+            for parent_id in &job.depends_on {
+                state.dependents.entry(*parent_id).or_default().insert(job.id);
+            }
+        }
+
+        self.settle_dependencies(&waiting).await
+    }
+
+    async fn resolve_dependents(&self, parent_id: Uuid) -> Result<()> {
+        let workflow_id = self.state().jobs.get(&parent_id).and_then(|job| job.workflow_id);
+        if let Some(workflow_id) = workflow_id {
+            self.settle_workflow(workflow_id).await?;
+        }
+
+        let children: Vec<Uuid> = self
+            .state()
+            .dependents
+            .get(&parent_id)
+            .map(|children| children.iter().copied().collect())
+            .unwrap_or_default();
+
+        for child_id in children {
+            let child = self.state().jobs.get(&child_id).cloned();
+            if let Some(child) = child.filter(|child| child.status == JobStatus::Waiting) {
+                self.settle_dependencies(&child).await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn create_workflow(&self, plan: &WorkflowPlan) -> Result<()> {
+        let own: HashSet<Uuid> = plan.jobs.iter().map(|job| job.id).collect();
+        let mut settle = Vec::new();
+        {
+            // Tout ou rien, comme la transaction Redis
+            let mut state = self.state();
+            let parents = plan.jobs.iter().flat_map(|job| &job.depends_on);
+            if let Some(missing) = parents.filter(|id| !own.contains(id)).find(|id| !state.jobs.contains_key(id)) {
+                return Err(IronForgeError::JobNotFound(missing.to_string()));
+            }
+
+            state.workflows.insert(plan.workflow.id, plan.workflow.clone());
+            if let Some(errback) = &plan.errback {
+                let mut waiting = errback.clone();
+                waiting.status = JobStatus::Waiting;
+                state.store(&waiting);
+            }
+
+            for job in &plan.jobs {
+                if job.depends_on.is_empty() {
+                    state.store(job);
+                    state.push_ready(job);
+                    continue;
+                }
+
+                let mut waiting = job.clone();
+                waiting.status = JobStatus::Waiting;
+                state.store(&waiting);
+                for parent_id in &job.depends_on {
+                    state.dependents.entry(*parent_id).or_default().insert(job.id);
+                }
+                if job.depends_on.iter().any(|id| !own.contains(id)) {
+                    settle.push(waiting);
+                }
+            }
+        }
+
+        for waiting in &settle {
+            self.settle_dependencies(waiting).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn get_workflow(&self, workflow_id: Uuid) -> Result<Option<Workflow>> {
+        Ok(self.state().workflows.get(&workflow_id).cloned())
+    }
+
+    async fn dequeue(&self, _timeout_secs: u64) -> Result<Option<Job>> {
+        let mut state = self.state();
+
+        // Même tourniquet que le backend Redis : la queue principale puis celles
+        // des tenants par ordre alphabétique, à partir de la dernière servie
+        let mut queues: Vec<Option<String>> =
+            std::iter::once(None).chain(state.tenants.iter().cloned().map(Some)).collect();
+        if let Some(position) = state
+            .last_served
+            .as_ref()
+            .and_then(|last| queues.iter().position(|queue| queue == last))
+        {
+            queues.rotate_left(position + 1);
+        }
+
+        for queue in queues {
+            let next = state
+                .ready
+                .iter()
+                .filter(|(id, _)| state.jobs.get(id).is_some_and(|job| job.tenant == queue))
+                .min_by_key(|(id, score)| (**score, **id))
+                .map(|(id, _)| *id);
+
+            if let Some(id) = next {
+                state.ready.remove(&id);
+                state.last_served = Some(queue);
+                return Ok(state.jobs.get(&id).cloned());
+            }
+        }
+
+        Ok(None)
+    }
+
+    async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>> {
+        Ok(self.state().jobs.get(&job_id).cloned())
+    }
+
+    async fn list_jobs(
+        &self,
+        filter: &JobFilter,
+        cursor: Option<JobCursor>,
+        limit: usize,
+    ) -> Result<JobPage> {
+        let state = self.state();
+        let mut jobs: Vec<&Job> = state.jobs.values().filter(|job| filter.matches(job)).collect();
+        jobs.sort_by_key(|job| std::cmp::Reverse((job.created_at.timestamp_millis(), job.id)));
+
+        // Comme l'index Redis, une page complète porte toujours un curseur
+        let page: Vec<Job> = jobs
+            .into_iter()
+            .filter(|job| {
+                cursor.as_ref().is_none_or(|cursor| {
+                    (job.created_at.timestamp_millis(), job.id) < (cursor.created_at_ms, cursor.job_id)
+                })
+            })
+            .take(limit)
+            .cloned()
+            .collect();
+        let next_cursor = match page.last() {
+            Some(last) if page.len() == limit => Some(JobCursor::from_job(last).to_string()),
+            _ => None,
+        };
+
+        Ok(JobPage { jobs: page, next_cursor })
+    }
+
+    async fn update_job(&self, job: &Job) -> Result<()> {
+        let mut state = self.state();
+        state.store(job);
+        state.schedule_first_webhook(job);
+
+        Ok(())
+    }
+
+    async fn update_job_if_version(&self, job: &mut Job) -> Result<()> {
+        let mut state = self.state();
+        state.check_version(job)?;
+
+        job.version += 1;
+        state.store(job);
+        if job.status == JobStatus::Queued {
+            // Recalcule sa place (priorité, date d'exécution)
+            state.ready.remove(&job.id);
+            state.delayed.remove(&job.id);
+            state.parked.remove(&job.id);
+            state.push_ready(job);
+        }
+
+        Ok(())
+    }
+
+    async fn transition_job(&self, job: &mut Job, from: JobStatus) -> Result<()> {
+        if !from.can_transition_to(job.status) {
+            return Err(IronForgeError::InvalidStatusTransition { from, to: job.status });
+        }
+
+        let mut state = self.state();
+        state.check_version(job)?;
+
+        job.version += 1;
+        state.store(job);
+        if job.status == JobStatus::Queued {
+            state.push_ready(job);
+        }
+        state.schedule_first_webhook(job);
+
+        Ok(())
+    }
+
+    async fn delete_job(&self, job_id: Uuid) -> Result<()> {
+        let mut state = self.state();
+        if let Some(job) = state.jobs.remove(&job_id) {
+            if let Some(unique) = &job.unique {
+                if state.unique.get(&unique.key) == Some(&job_id) {
+                    state.unique.remove(&unique.key);
+                }
+            }
+        }
+        state.ready.remove(&job_id);
+        state.parked.remove(&job_id);
+        state.delayed.remove(&job_id);
+        state.locks.remove(&job_id);
+
+        Ok(())
+    }
+
+    async fn publish_progress(&self, job: &Job, progress: serde_json::Value) -> Result<()> {
+        let _ = self.state().events.0.send(JobEvent::progress(job, progress));
+
+        Ok(())
+    }
+
+    async fn subscribe_events(&self) -> Result<BoxStream<'static, JobEvent>> {
+        let receiver = self.state().events.0.subscribe();
+
+        Ok(stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((event, receiver)),
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        })
+        .boxed())
+    }
+
+    async fn claim_due_webhooks(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+        lease_secs: u64,
+    ) -> Result<Vec<(Uuid, u32)>> {
+        let mut state = self.state();
+        let mut due: Vec<(i64, String, (Uuid, u32))> = state
+            .webhooks
+            .iter()
+            .filter(|(_, due_at)| **due_at <= now.timestamp_millis())
+            .map(|(&(job_id, attempt), &due_at)| (due_at, format!("{}:{}", job_id, attempt), (job_id, attempt)))
+            .collect();
+        // Même ordre que ZRANGEBYSCORE : échéance, puis membre
+        due.sort();
+        due.truncate(limit);
+
+        // La livraison reste en attente jusqu'à la fin du bail
+        let lease_until = (now + chrono::Duration::seconds(lease_secs as i64)).timestamp_millis();
+        Ok(due
+            .into_iter()
+            .map(|(_, _, delivery)| {
+                state.webhooks.insert(delivery, lease_until);
+                delivery
+            })
+            .collect())
+    }
+
+    async fn complete_webhook(
+        &self,
+        job_id: Uuid,
+        attempt: u32,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
+        let mut state = self.state();
+        state.webhooks.remove(&(job_id, attempt));
+        if let Some(due_at) = next_attempt_at {
+            state.webhooks.insert((job_id, attempt + 1), due_at.timestamp_millis());
+        }
+
+        Ok(())
+    }
+
+    async fn record_webhook_delivery(&self, job_id: Uuid, delivery: &WebhookDelivery) -> Result<()> {
+        // Écriture directe : le statut ne change pas, ni index ni événement
+        if let Some(job) = self.state().jobs.get_mut(&job_id) {
+            job.webhook_deliveries.push(delivery.clone());
+            job.version += 1;
+        }
+
+        Ok(())
+    }
+
+    async fn promote_delayed(&self, now: DateTime<Utc>, limit: usize) -> Result<u64> {
+        let mut state = self.state();
+        let mut due: Vec<(i64, String, Uuid)> = state
+            .delayed
+            .iter()
+            .filter(|(_, due_at)| **due_at <= now.timestamp_millis())
+            .map(|(&job_id, &due_at)| (due_at, job_id.to_string(), job_id))
+            .collect();
+        due.sort();
+        due.truncate(limit);
+
+        let mut promoted = 0;
+        for (_, _, job_id) in due {
+            state.delayed.remove(&job_id);
+            // Job supprimé ou déjà sorti de la queue entre-temps
+            let Some(job) = state.jobs.get(&job_id).filter(|job| job.status == JobStatus::Queued) else {
+                continue;
+            };
+            let score = job.calculate_redis_score();
+            state.ready.insert(job_id, score);
+            promoted += 1;
+        }
+
+        Ok(promoted)
+    }
+
+    async fn move_to_dlq(&self, job: &Job) -> Result<()> {
+        self.modify_job(job.id, Some(JobStatus::DeadLetter), |latest| {
+            latest.absorb_execution(job);
+            Ok(())
+        })
+        .await?;
+
+        Ok(())
+    }
+
+    async fn get_stats(&self) -> Result<QueueStats> {
+        let state = self.state();
+        let mut tenant_queue_depths: HashMap<String, i64> =
+            state.tenants.iter().map(|tenant| (tenant.clone(), 0)).collect();
+        for job in state.ready.keys().filter_map(|id| state.jobs.get(id)) {
+            if let Some(depth) = job.tenant.as_ref().and_then(|tenant| tenant_queue_depths.get_mut(tenant)) {
+                *depth += 1;
+            }
+        }
+
+        Ok(QueueStats {
+            queue_depth: state.ready.len() as i64,
+            dlq_depth: state.jobs.values().filter(|job| job.status == JobStatus::DeadLetter).count() as i64,
+            active_jobs: state.locks.len() as i64,
+            parked_jobs: state.parked.len() as i64,
+            delayed_jobs: state.delayed.len() as i64,
+            paused: state.paused.clone(),
+            tenant_queue_depths,
+        })
+    }
+
+    async fn acquire_lock(&self, job_id: Uuid, _timeout_secs: u64) -> Result<bool> {
+        Ok(self.state().locks.insert(job_id))
+    }
+
+    async fn release_lock(&self, job_id: Uuid) -> Result<()> {
+        self.state().locks.remove(&job_id);
+
+        Ok(())
+    }
+
+    async fn register_worker(&self, worker: &WorkerInfo, _ttl_secs: u64) -> Result<()> {
+        self.state().workers.insert(worker.id.clone(), worker.clone());
+
+        Ok(())
+    }
+
+    async fn unregister_worker(&self, worker_id: &str) -> Result<()> {
+        self.state().workers.remove(worker_id);
+
+        Ok(())
+    }
+
+    async fn list_workers(&self) -> Result<Vec<WorkerInfo>> {
+        Ok(self.state().workers.values().cloned().collect())
+    }
+
+    async fn get_worker(&self, worker_id: &str) -> Result<Option<WorkerInfo>> {
+        Ok(self.state().workers.get(worker_id).cloned())
+    }
+
+    async fn pause(&self, target: &PauseTarget) -> Result<()> {
+        let mut state = self.state();
+        let (set, name) = match target {
+            PauseTarget::Queue(name) => (&mut state.paused.queues, name),
+            PauseTarget::Kind(kind) => (&mut state.paused.kinds, kind),
+        };
+        if !set.contains(name) {
+            set.push(name.clone());
+            set.sort();
+        }
+
+        Ok(())
+    }
+
+    async fn resume(&self, target: &PauseTarget) -> Result<u64> {
+        let mut state = self.state();
+        match target {
+            PauseTarget::Queue(name) => state.paused.queues.retain(|queue| queue != name),
+            PauseTarget::Kind(kind) => state.paused.kinds.retain(|k| k != kind),
+        }
+
+        let released: Vec<(Uuid, i64)> = state
+            .parked
+            .iter()
+            .filter(|(id, _)| state.jobs.get(id).is_some_and(|job| !state.paused.is_paused(job)))
+            .map(|(id, score)| (*id, *score))
+            .collect();
+        for (id, score) in &released {
+            state.parked.remove(id);
+            state.ready.insert(*id, *score);
+        }
+
+        Ok(released.len() as u64)
+    }
+
+    async fn paused_targets(&self) -> Result<PausedTargets> {
+        Ok(self.state().paused.clone())
+    }
+
+    async fn park_job(&self, job: &Job) -> Result<()> {
+        self.state().parked.insert(job.id, job.calculate_redis_score());
+
+        Ok(())
+    }
+
+    async fn save_schedule(&self, schedule: &Schedule) -> Result<()> {
+        self.state().schedules.insert(schedule.id, schedule.clone());
+
+        Ok(())
+    }
+
+    async fn get_schedule(&self, schedule_id: Uuid) -> Result<Option<Schedule>> {
+        let state = self.state();
+
+        Ok(state.schedules.get(&schedule_id).map(|schedule| state.schedule(schedule)))
+    }
+
+    async fn list_schedules(&self) -> Result<Vec<Schedule>> {
+        let state = self.state();
+        let mut schedules: Vec<Schedule> = state.schedules.values().map(|schedule| state.schedule(schedule)).collect();
+        schedules.sort_by_key(|schedule| schedule.created_at);
+
+        Ok(schedules)
+    }
+
+    async fn record_schedule_run(&self, schedule: &Schedule, lease: &str, holder_id: &str) -> Result<bool> {
+        let mut state = self.state();
+        if state.leaders.get(lease).map(String::as_str) != Some(holder_id) {
+            return Err(IronForgeError::LeadershipLost(lease.to_string()));
+        }
+        let Some(stored) = state.schedules.get_mut(&schedule.id) else {
+            return Ok(false);
+        };
+
+        stored.next_run_at = schedule.next_run_at;
+        let run = state.schedule_runs.entry(schedule.id).or_default();
+        run.last_run_at = schedule.last_run_at.or(run.last_run_at);
+        run.last_job_id = schedule.last_job_id.or(run.last_job_id);
+
+        Ok(true)
+    }
+
+    async fn delete_schedule(&self, schedule_id: Uuid) -> Result<bool> {
+        let mut state = self.state();
+        state.schedule_runs.remove(&schedule_id);
+
+        Ok(state.schedules.remove(&schedule_id).is_some())
+    }
+
+    async fn try_acquire_leadership(&self, lease: &str, holder_id: &str, _ttl_secs: u64) -> Result<bool> {
+        let mut state = self.state();
+        let holder = state
+            .leaders
+            .entry(lease.to_string())
+            .or_insert_with(|| holder_id.to_string());
+
+        Ok(holder == holder_id)
+    }
+
+    async fn current_leader(&self, lease: &str) -> Result<Option<String>> {
+        Ok(self.state().leaders.get(lease).cloned())
+    }
+
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -1,5 +1,11 @@
 mod traits;
 mod redis;
+#[cfg(test)]
+mod memory;
+#[cfg(test)]
+mod conformance;
 
-pub use traits::{QueueBackend, QueueStats};
+pub use traits::{QueueBackend, QueueStats, PauseTarget, PausedTargets, JOB_UPDATE_ATTEMPTS};
 pub use redis::RedisQueueBackend;
+#[cfg(test)]
+pub(crate) use memory::MemoryQueueBackend;
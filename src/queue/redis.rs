@@ -1,133 +1,1324 @@
 use async_trait::async_trait;
+use futures::stream::{BoxStream, StreamExt};
 use redis::{AsyncCommands, Client, aio::ConnectionManager};
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
 use uuid::Uuid;
-use crate::models::{Job, JobStatus, Result, IronForgeError};
-use super::traits::{QueueBackend, QueueStats};
+use crate::models::{
+    DependencyState, Job, JobCursor, JobEvent, JobFilter, JobPage, JobStatus, Result, IronForgeError,
+    Schedule, TenantQuota, UniqueLock, UniqueScope, WebhookDelivery, WorkerInfo, Workflow,
+    WorkflowPlan, WorkflowStatus, WEBHOOK_STATUSES,
+};
+use chrono::{DateTime, Utc};
+use super::traits::{QueueBackend, QueueStats, PauseTarget, PausedTargets, JOB_UPDATE_ATTEMPTS};
 
 /// Backend Redis pour la queue de jobs
 pub struct RedisQueueBackend {
+    client: Client,
     conn_manager: ConnectionManager,
+    /// Dernière queue servie par un dequeue (tourniquet entre tenants)
+    last_served: Mutex<Option<String>>,
 }
 
 impl RedisQueueBackend {
     /// Crée une nouvelle instance du backend Redis
     pub async fn new(redis_url: &str) -> Result<Self> {
         let client = Client::open(redis_url)?;
-        let conn_manager = ConnectionManager::new(client).await?;
+        let conn_manager = ConnectionManager::new(client.clone()).await?;
         
         Ok(Self {
+            client,
             conn_manager,
+            last_served: Mutex::new(None),
         })
     }
     
     /// Clés Redis utilisées
     fn queue_key() -> &'static str { "queue:main" }
-    fn dlq_key() -> &'static str { "queue:dlq" }
     fn job_key(job_id: Uuid) -> String { format!("jobs:{}", job_id) }
     fn lock_key(job_id: Uuid) -> String { format!("lock:{}", job_id) }
     fn active_jobs_key() -> &'static str { "active:jobs" }
+    fn worker_key(worker_id: &str) -> String { format!("workers:{}", worker_id) }
+    fn workers_registry_key() -> &'static str { "workers:registry" }
+    fn paused_queues_key() -> &'static str { "paused:queues" }
+    fn paused_kinds_key() -> &'static str { "paused:kinds" }
+    fn parked_key() -> &'static str { "queue:parked" }
+    fn delayed_key() -> &'static str { "queue:delayed" }
+    fn dependents_key(parent_id: Uuid) -> String { format!("deps:children:{}", parent_id) }
+    fn dependency_resolved_key(job_id: Uuid) -> String { format!("deps:resolved:{}", job_id) }
+    fn workflow_key(workflow_id: Uuid) -> String { format!("workflows:{}", workflow_id) }
+    fn errback_claim_key(workflow_id: Uuid) -> String { format!("workflows:errback:{}", workflow_id) }
+    fn schedule_key(schedule_id: Uuid) -> String { format!("schedules:{}", schedule_id) }
+    fn schedules_index_key() -> &'static str { "schedules:index" }
+    fn schedule_run_key(schedule_id: Uuid) -> String { format!("schedules:{}:run", schedule_id) }
+    fn leader_key(lease: &str) -> String { format!("leader:{}", lease) }
+    fn all_jobs_index_key() -> &'static str { "idx:jobs:all" }
+    fn status_index_key(status: JobStatus) -> String { format!("idx:jobs:status:{}", status.as_str()) }
+    fn kind_index_key(kind: &str) -> String { format!("idx:jobs:kind:{}", kind) }
+    fn queue_index_key(queue: &str) -> String { format!("idx:jobs:queue:{}", queue) }
+    fn metadata_index_key(key: &str, value: &str) -> String { format!("idx:jobs:meta:{}={}", key, value) }
+    fn batch_index_key(batch_id: Uuid) -> String { format!("idx:jobs:batch:{}", batch_id) }
+    fn idempotency_key(key: &str) -> String { format!("idempotency:{}", key) }
+    fn unique_key(key: &str) -> String { format!("unique:{}", key) }
+    fn events_channel() -> &'static str { "events:jobs" }
+    fn webhooks_pending_key() -> &'static str { "webhooks:pending" }
+    fn tenant_queue_key(tenant: &str) -> String { format!("queue:tenant:{}", tenant) }
+    fn tenants_key() -> &'static str { "tenants:registry" }
+    fn tenant_index_key(tenant: &str) -> String { format!("idx:jobs:tenant:{}", tenant) }
+    fn submissions_key(tenant: &str, minute: i64) -> String { format!("quota:submissions:{}:{}", tenant, minute) }
+    
+    /// Queue d'exécution du job : celle de son tenant, sinon la queue principale
+    fn ready_key(job: &Job) -> String {
+        match &job.tenant {
+            Some(tenant) => Self::tenant_queue_key(tenant),
+            None => Self::queue_key().to_string(),
+        }
+    }
+    
+    /// Ajoute au pipeline la mise en queue d'exécution du job, ou parmi les jobs
+    /// différés (score = date d'exécution) s'il n'est pas encore exécutable
+    fn push_ready(pipe: &mut redis::Pipeline, job: &Job) {
+        match job.scheduled_for {
+            Some(scheduled_for) if job.is_delayed(Utc::now()) => {
+                pipe.zadd(Self::delayed_key(), job.id.to_string(), scheduled_for.timestamp_millis())
+                    .ignore();
+            }
+            _ => {
+                pipe.zadd(Self::ready_key(job), job.id.to_string(), job.calculate_redis_score())
+                    .ignore();
+            }
+        }
+        if let Some(tenant) = &job.tenant {
+            pipe.sadd(Self::tenants_key(), tenant).ignore();
+        }
+    }
+    
+    /// Index secondaires dont l'appartenance ne dépend pas du statut
+    fn static_index_keys(job: &Job) -> Vec<String> {
+        let mut keys = vec![
+            Self::all_jobs_index_key().to_string(),
+            Self::kind_index_key(&job.kind),
+            Self::queue_index_key(&job.queue),
+        ];
+        keys.extend(
+            job.metadata
+                .iter()
+                .map(|(key, value)| Self::metadata_index_key(key, value)),
+        );
+        if let Some(batch_id) = job.batch_id {
+            keys.push(Self::batch_index_key(batch_id));
+        }
+        if let Some(tenant) = &job.tenant {
+            keys.push(Self::tenant_index_key(tenant));
+        }
+        keys
+    }
+    
+    /// Index le plus sélectif pour parcourir les jobs correspondant au filtre
+    fn driving_index_key(filter: &JobFilter) -> String {
+        if let Some(batch_id) = filter.batch_id {
+            Self::batch_index_key(batch_id)
+        } else if let Some((key, value)) = filter.metadata.first() {
+            Self::metadata_index_key(key, value)
+        } else if let Some(kind) = &filter.kind {
+            Self::kind_index_key(kind)
+        } else if let Some(queue) = &filter.queue {
+            Self::queue_index_key(queue)
+        } else if let Some(status) = filter.status {
+            Self::status_index_key(status)
+        } else if let Some(tenant) = &filter.tenant {
+            Self::tenant_index_key(tenant)
+        } else {
+            Self::all_jobs_index_key().to_string()
+        }
+    }
+    
+    /// Ajoute au pipeline l'écriture du job et la mise à jour de ses index
+    /// secondaires (score = date de création, pour une pagination stable)
+    fn store_job(pipe: &mut redis::Pipeline, job: &Job) -> Result<()> {
+        Self::write_job(pipe, job)?;
+        Self::settle_job_unique(pipe, job);
+        
+        Ok(())
+    }
+    
+    /// Ajoute au pipeline l'écriture du job, de ses index et de son événement
+    fn write_job(pipe: &mut redis::Pipeline, job: &Job) -> Result<()> {
+        let member = job.id.to_string();
+        let score = job.created_at.timestamp_millis();
+        
+        pipe.set(Self::job_key(job.id), serde_json::to_string(job)?).ignore();
+        
+        for status in JobStatus::ALL {
+            if status != job.status {
+                pipe.zrem(Self::status_index_key(status), &member).ignore();
+            }
+        }
+        pipe.zadd(Self::status_index_key(job.status), &member, score).ignore();
+        
+        for key in Self::static_index_keys(job) {
+            pipe.zadd(key, &member, score).ignore();
+        }
+        
+        pipe.publish(Self::events_channel(), serde_json::to_string(&JobEvent::status(job))?)
+            .ignore();
+        
+        Ok(())
+    }
+    
+    /// Ajoute au pipeline la libération ou la pérennisation du verrou
+    /// d'unicité du job selon son statut
+    fn settle_job_unique(pipe: &mut redis::Pipeline, job: &Job) {
+        if let Some(unique) = &job.unique {
+            // Le verrou d'une fenêtre expire seul ; les autres suivent le statut
+            if unique.scope != UniqueScope::Window {
+                let action = if unique.scope.holds(job.status) { "persist" } else { "release" };
+                Self::settle_unique(pipe, unique, &job.id.to_string(), action);
+            }
+        }
+    }
+    
+    /// Ajoute au pipeline la première livraison du webhook d'un job terminé
+    fn schedule_first_webhook(pipe: &mut redis::Pipeline, job: &Job) {
+        if job.callback_url.is_some() && WEBHOOK_STATUSES.contains(&job.status) {
+            pipe.zadd(Self::webhooks_pending_key(), format!("{}:1", job.id), Utc::now().timestamp_millis())
+                .ignore();
+        }
+    }
+    
+    /// Reporte sur une planification l'avancement enregistré par le scheduler
+    fn apply_schedule_run(schedule: &mut Schedule, run: HashMap<String, String>) {
+        let parse_date = |value: &String| {
+            DateTime::parse_from_rfc3339(value).ok().map(|date| date.with_timezone(&Utc))
+        };
+
+        // Un avancement enregistré fait foi, y compris une prochaine exécution absente
+        if !run.is_empty() {
+            schedule.next_run_at = run.get("next_run_at").and_then(parse_date);
+        }
+        if let Some(last_run_at) = run.get("last_run_at") {
+            schedule.last_run_at = parse_date(last_run_at);
+        }
+        if let Some(last_job_id) = run.get("last_job_id") {
+            schedule.last_job_id = Uuid::parse_str(last_job_id).ok();
+        }
+    }
+    
+    /// Ajoute au pipeline la libération (ou la pérennisation) du verrou
+    /// d'unicité, uniquement s'il est détenu par le job
+    fn settle_unique(pipe: &mut redis::Pipeline, unique: &UniqueLock, member: &str, action: &str) {
+        pipe.cmd("EVAL")
+            .arg(SETTLE_UNIQUE_SCRIPT)
+            .arg(1)
+            .arg(Self::unique_key(&unique.key))
+            .arg(member)
+            .arg(action)
+            .ignore();
+    }
+    
+    fn pause_set_key(target: &PauseTarget) -> (&'static str, &str) {
+        match target {
+            PauseTarget::Queue(name) => (Self::paused_queues_key(), name),
+            PauseTarget::Kind(kind) => (Self::paused_kinds_key(), kind),
+        }
+    }
+    
+    /// Exécute atomiquement les commandes du pipeline si le job stocké est encore
+    /// à la version `expected` (`VersionConflict` sinon)
+    async fn write_if_version(&self, job_id: Uuid, expected: u64, pipe: &redis::Pipeline) -> Result<()> {
+        let script = redis::Script::new(WRITE_IF_VERSION_SCRIPT);
+        let mut invocation = script.key(Self::job_key(job_id));
+        invocation.arg(expected);
+        for cmd in pipe.cmd_iter() {
+            invocation.arg(cmd.args_iter().len());
+            for arg in cmd.args_iter() {
+                if let redis::Arg::Simple(bytes) = arg {
+                    invocation.arg(bytes);
+                }
+            }
+        }
+        
+        let mut conn = self.conn_manager.clone();
+        let (applied, actual): (i64, i64) = invocation.invoke_async(&mut conn).await?;
+        
+        match (applied, u64::try_from(actual)) {
+            (1, _) => Ok(()),
+            (_, Ok(actual)) => Err(IronForgeError::VersionConflict {
+                job_id: job_id.to_string(),
+                expected,
+                actual,
+            }),
+            (_, Err(_)) => Err(IronForgeError::JobNotFound(job_id.to_string())),
+        }
+    }
+    
+    /// Relit et réécrit un job sans changer son statut, sous condition de
+    /// version. `apply` modifie le job (version déjà incrémentée) et remplit le
+    /// pipeline d'écriture. Retourne `None` si le job n'existe pas.
+    async fn rewrite_job<F>(&self, job_id: Uuid, mut apply: F) -> Result<Option<Job>>
+    where
+        F: FnMut(&mut Job, &mut redis::Pipeline) -> Result<()> + Send,
+    {
+        let mut attempt = 1;
+        
+        loop {
+            let Some(mut job) = self.get_job(job_id).await? else {
+                return Ok(None);
+            };
+            
+            let expected = job.version;
+            job.version += 1;
+            let mut pipe = redis::pipe();
+            apply(&mut job, &mut pipe)?;
+            
+            match self.write_if_version(job_id, expected, &pipe).await {
+                Ok(()) => return Ok(Some(job)),
+                Err(IronForgeError::VersionConflict { .. }) if attempt < JOB_UPDATE_ATTEMPTS => attempt += 1,
+                Err(IronForgeError::JobNotFound(_)) => return Ok(None),
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    
+    /// Réévalue les dépendances d'un job en Waiting et le débloque ou l'annule
+    async fn settle_dependencies(&self, job: &Job) -> Result<JobStatus> {
+        let mut parents = Vec::with_capacity(job.depends_on.len());
+        for parent_id in &job.depends_on {
+            parents.push(self.get_job(*parent_id).await?);
+        }
+        
+        let statuses: Vec<Option<JobStatus>> = parents
+            .iter()
+            .map(|parent| parent.as_ref().map(|p| p.status))
+            .collect();
+        
+        let next_status = match DependencyState::evaluate(&statuses) {
+            DependencyState::Pending => return Ok(JobStatus::Waiting),
+            DependencyState::Ready => JobStatus::Queued,
+            DependencyState::Failed => job.on_dependency_failure.status(),
+        };
+        
+        // Plusieurs parents peuvent se terminer en même temps : un seul gagne
+        let mut conn = self.conn_manager.clone();
+        let claimed: bool = redis::cmd("SET")
+            .arg(Self::dependency_resolved_key(job.id))
+            .arg(format!("{:?}", next_status))
+            .arg("NX")
+            .arg("EX")
+            .arg(DEPENDENCY_GUARD_TTL_SECS)
+            .query_async(&mut conn)
+            .await
+            .unwrap_or(false);
+        
+        if !claimed {
+            return Ok(next_status);
+        }
+        
+        // Transmet les résultats des parents (ex: étape précédente d'une chaîne)
+        let dependency_results: Vec<serde_json::Value> = match next_status {
+            JobStatus::Queued => parents
+                .into_iter()
+                .map(|parent| parent.and_then(|p| p.result).unwrap_or(serde_json::Value::Null))
+                .collect(),
+            _ => Vec::new(),
+        };
+        
+        self.modify_job(job.id, Some(next_status), |settled| {
+            settled.dependency_results = dependency_results.clone();
+            Ok(())
+        }).await?;
+        
+        if next_status != JobStatus::Queued {
+            tracing::warn!(
+                job_id = %job.id,
+                status = ?next_status,
+                "Dependency failed, dependent job settled"
+            );
+            
+            // Propage l'échec aux jobs qui dépendent de celui-ci
+            self.resolve_dependents(job.id).await?;
+        }
+        
+        Ok(next_status)
+    }
+    
+    /// Déclenche l'errback d'un workflow en échec, ou l'annule s'il a réussi
+    async fn settle_workflow(&self, workflow_id: Uuid) -> Result<()> {
+        let Some(workflow) = self.get_workflow(workflow_id).await? else {
+            return Ok(());
+        };
+        let Some(errback_id) = workflow.errback_id else {
+            return Ok(());
+        };
+        
+        let mut statuses = Vec::with_capacity(workflow.job_ids.len());
+        let mut failed_jobs = Vec::new();
+        for job_id in &workflow.job_ids {
+            let status = self
+                .get_job(*job_id)
+                .await?
+                .map(|job| job.status)
+                .unwrap_or(JobStatus::Cancelled);
+            if status.is_terminal() && status != JobStatus::Completed {
+                failed_jobs.push(job_id.to_string());
+            }
+            statuses.push(status);
+        }
+        
+        let aggregate = WorkflowStatus::aggregate(&statuses);
+        if !matches!(aggregate, WorkflowStatus::Failed | WorkflowStatus::Completed) {
+            return Ok(());
+        }
+        
+        let mut conn = self.conn_manager.clone();
+        let claimed: bool = redis::cmd("SET")
+            .arg(Self::errback_claim_key(workflow_id))
+            .arg(format!("{:?}", aggregate))
+            .arg("NX")
+            .arg("EX")
+            .arg(DEPENDENCY_GUARD_TTL_SECS)
+            .query_async(&mut conn)
+            .await
+            .unwrap_or(false);
+        
+        if !claimed {
+            return Ok(());
+        }
+        
+        let settled = if aggregate == WorkflowStatus::Failed {
+            let failed_job_ids = failed_jobs.join(",");
+            self.modify_job(errback_id, Some(JobStatus::Queued), |errback| {
+                errback.metadata.insert("failed_job_ids".to_string(), failed_job_ids.clone());
+                Ok(())
+            }).await
+        } else {
+            self.modify_job(errback_id, Some(JobStatus::Cancelled), |_| Ok(())).await
+        };
+        
+        match settled {
+            Ok(_) if aggregate == WorkflowStatus::Failed => {
+                tracing::warn!(workflow_id = %workflow_id, errback_id = %errback_id, "Workflow failed, errback enqueued");
+            }
+            // Errback supprimé entre-temps
+            Ok(_) | Err(IronForgeError::JobNotFound(_)) => {}
+            Err(e) => return Err(e),
+        }
+        
+        Ok(())
+    }
 }
 
+/// Acquiert le bail s'il est libre, ou le renouvelle si on le détient déjà
+const ACQUIRE_LEADERSHIP_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+    redis.call('SET', KEYS[1], ARGV[1], 'EX', ARGV[2])
+    return 1
+end
+if current == ARGV[1] then
+    redis.call('EXPIRE', KEYS[1], ARGV[2])
+    return 1
+end
+return 0
+"#;
+
+/// Libère (`release`) ou rend permanent (`persist`) un verrou d'unicité
+/// s'il est détenu par le job
+const SETTLE_UNIQUE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) ~= ARGV[1] then
+    return 0
+end
+if ARGV[2] == 'release' then
+    redis.call('DEL', KEYS[1])
+else
+    redis.call('PERSIST', KEYS[1])
+end
+return 1
+"#;
+
+/// Compare-and-set sur la version d'un job : exécute les commandes transmises
+/// (nombre d'arguments puis arguments, pour chaque commande) uniquement si le
+/// job est toujours à la version attendue. Retourne {appliqué, version trouvée},
+/// avec une version de -1 si le job n'existe pas.
+const WRITE_IF_VERSION_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if not current then
+    return {0, -1}
+end
+local version = cjson.decode(current).version or 0
+if version ~= tonumber(ARGV[1]) then
+    return {0, version}
+end
+local i = 2
+while i <= #ARGV do
+    local count = tonumber(ARGV[i])
+    redis.call(unpack(ARGV, i + 1, i + count))
+    i = i + count + 1
+end
+return {1, version}
+"#;
+
+/// Déplace un job différé échu vers sa queue d'exécution, sauf s'il a été
+/// promu par une autre instance ou différé à nouveau entre-temps
+const PROMOTE_DELAYED_SCRIPT: &str = r#"
+local due = redis.call('ZSCORE', KEYS[1], ARGV[1])
+if not due or tonumber(due) > tonumber(ARGV[3]) then
+    return 0
+end
+redis.call('ZREM', KEYS[1], ARGV[1])
+redis.call('ZADD', KEYS[2], ARGV[2], ARGV[1])
+return 1
+"#;
+
+/// Enregistre l'avancement d'une planification si elle existe toujours et si
+/// l'instance détient encore le bail de leader (-1 sinon) ; une valeur vide
+/// efface `next_run_at` et conserve les autres champs
+const RECORD_SCHEDULE_RUN_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[3]) ~= ARGV[4] then
+    return -1
+end
+if redis.call('EXISTS', KEYS[1]) == 0 then
+    return 0
+end
+local fields = {'next_run_at', 'last_run_at', 'last_job_id'}
+for i, field in ipairs(fields) do
+    if ARGV[i] ~= '' then
+        redis.call('HSET', KEYS[2], field, ARGV[i])
+    elseif field == 'next_run_at' then
+        redis.call('HDEL', KEYS[2], field)
+    end
+end
+return 1
+"#;
+
+/// Prolonge jusqu'à la fin du bail une livraison de webhook échue, sauf si
+/// une autre instance l'a déjà réservée
+const LEASE_WEBHOOK_SCRIPT: &str = r#"
+local due = redis.call('ZSCORE', KEYS[1], ARGV[1])
+if not due or tonumber(due) > tonumber(ARGV[2]) then
+    return 0
+end
+redis.call('ZADD', KEYS[1], ARGV[3], ARGV[1])
+return 1
+"#;
+
+/// Rend des soumissions au compteur de la minute en cours, sans descendre
+/// sous zéro (la réservation a pu être faite la minute précédente)
+const RELEASE_SUBMISSIONS_SCRIPT: &str = r#"
+local used = tonumber(redis.call('GET', KEYS[1]) or '0')
+local refund = math.min(used, tonumber(ARGV[1]))
+if refund > 0 then
+    redis.call('DECRBY', KEYS[1], refund)
+end
+return refund
+"#;
+
+/// Reprend un verrou d'unicité périmé s'il n'a pas changé de détenteur
+const REPLACE_UNIQUE_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[3])
+    return 1
+end
+return 0
+"#;
+
+/// Durée d'une réservation de verrou d'unicité dont le job n'a pas encore été
+/// enregistré (au-delà, une soumission interrompue ne bloque plus les suivantes)
+const UNIQUE_CLAIM_TTL_SECS: u64 = 60;
+
+/// Nombre de tentatives de réservation d'un verrou d'unicité disputé
+const UNIQUE_CLAIM_ATTEMPTS: usize = 3;
+
+/// Nombre d'entrées d'index lues par aller-retour lors d'une recherche
+const LIST_SCAN_BATCH: usize = 200;
+
+/// Nombre maximum d'entrées d'index examinées par page de recherche
+const MAX_LIST_SCAN: usize = 10_000;
+
+/// Durée de conservation des marqueurs de résolution des dépendances
+const DEPENDENCY_GUARD_TTL_SECS: u64 = 7 * 24 * 3600;
+
 #[async_trait]
 impl QueueBackend for RedisQueueBackend {
     async fn enqueue(&self, job: &Job) -> Result<()> {
         let mut conn = self.conn_manager.clone();
-        let score = job.calculate_redis_score();
-        let job_json = serde_json::to_string(job)?;
         
-        // Stocke les métadonnées du job
-        let _: () = conn.set(Self::job_key(job.id), &job_json).await?;
+        // Stocke le job et ses index, puis l'ajoute à sa queue d'exécution (Sorted Set)
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        Self::store_job(&mut pipe, job)?;
+        Self::push_ready(&mut pipe, job);
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        tracing::info!(
+            job_id = %job.id,
+            kind = %job.kind,
+            priority = ?job.priority,
+            "Job enqueued"
+        );
+        
+        Ok(())
+    }
+    
+    async fn reserve_submissions(&self, tenant: &str, quota: &TenantQuota, count: u64) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        
+        // Limite indicative : des soumissions concurrentes peuvent la dépasser de peu
+        if let Some(max) = quota.max_queued_jobs {
+            let queued: u64 = conn.zcard(Self::tenant_queue_key(tenant)).await?;
+            if queued + count > max {
+                return Err(IronForgeError::QuotaExceeded(format!(
+                    "tenant {} has {} queued jobs (max {})",
+                    tenant, queued, max
+                )));
+            }
+        }
+        
+        // Fenêtre fixe d'une minute, compteur rendu si la limite est dépassée
+        if let Some(limit) = quota.submissions_per_minute {
+            let key = Self::submissions_key(tenant, Utc::now().timestamp() / 60);
+            let (used,): (u64,) = redis::pipe()
+                .atomic()
+                .incr(&key, count)
+                .expire(&key, 120).ignore()
+                .query_async(&mut conn)
+                .await?;
+            
+            if used > limit {
+                let _: () = conn.decr(&key, count).await?;
+                return Err(IronForgeError::QuotaExceeded(format!(
+                    "tenant {} exceeded {} submissions per minute",
+                    tenant, limit
+                )));
+            }
+        }
+        
+        Ok(())
+    }
+    
+    async fn release_submissions(&self, tenant: &str, quota: &TenantQuota, count: u64) -> Result<()> {
+        // `max_queued_jobs` se lit sur la queue : seul le compteur par minute est à rendre
+        if quota.submissions_per_minute.is_none() {
+            return Ok(());
+        }
+        
+        let mut conn = self.conn_manager.clone();
+        let key = Self::submissions_key(tenant, Utc::now().timestamp() / 60);
+        let _: i64 = redis::Script::new(RELEASE_SUBMISSIONS_SCRIPT)
+            .key(&key)
+            .arg(count)
+            .invoke_async(&mut conn)
+            .await?;
+        
+        Ok(())
+    }
+    
+    async fn claim_idempotency_key(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        job_id: Uuid,
+        ttl_secs: u64,
+    ) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let claim = format!("{}:{}", fingerprint, job_id);
+        
+        let claimed: bool = redis::cmd("SET")
+            .arg(Self::idempotency_key(key))
+            .arg(&claim)
+            .arg("NX")
+            .arg("EX")
+            .arg(ttl_secs)
+            .query_async(&mut conn)
+            .await
+            .unwrap_or(false);
+        
+        if claimed {
+            return Ok(());
+        }
+        
+        let existing: Option<String> = conn.get(Self::idempotency_key(key)).await?;
+        
+        match existing.as_deref().and_then(|claim| claim.split_once(':')) {
+            Some((existing_fingerprint, existing_job_id)) if existing_fingerprint == fingerprint => {
+                Err(IronForgeError::JobAlreadyExists(existing_job_id.to_string()))
+            }
+            Some(_) => Err(IronForgeError::IdempotencyKeyConflict(key.to_string())),
+            // La clé a expiré entre-temps : nouvelle tentative
+            None => self.claim_idempotency_key(key, fingerprint, job_id, ttl_secs).await,
+        }
+    }
+    
+    async fn release_idempotency_key(&self, key: &str) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let _: () = conn.del(Self::idempotency_key(key)).await?;
+        
+        Ok(())
+    }
+    
+    async fn claim_unique(&self, job: &Job) -> Result<Option<Uuid>> {
+        let Some(unique) = &job.unique else {
+            return Ok(None);
+        };
+        
+        let mut conn = self.conn_manager.clone();
+        let key = Self::unique_key(&unique.key);
+        let member = job.id.to_string();
+        let ttl_secs = unique.window_secs.unwrap_or(UNIQUE_CLAIM_TTL_SECS);
+        
+        for _ in 0..UNIQUE_CLAIM_ATTEMPTS {
+            let claimed: bool = redis::cmd("SET")
+                .arg(&key)
+                .arg(&member)
+                .arg("NX")
+                .arg("EX")
+                .arg(ttl_secs)
+                .query_async(&mut conn)
+                .await
+                .unwrap_or(false);
+            
+            if claimed {
+                return Ok(None);
+            }
+            
+            let holder: Option<String> = conn.get(&key).await?;
+            let Some(holder_id) = holder.as_deref().and_then(|id| Uuid::parse_str(id).ok()) else {
+                // Le verrou a expiré entre-temps : nouvelle tentative
+                continue;
+            };
+            
+            if unique.scope == UniqueScope::Window {
+                return Ok(Some(holder_id));
+            }
+            
+            // Un détenteur introuvable est en cours de soumission : il bloque
+            let stale = self.get_job(holder_id).await?.is_some_and(|holder| {
+                !holder
+                    .unique
+                    .is_some_and(|lock| lock.scope.holds(holder.status))
+            });
+            if !stale {
+                return Ok(Some(holder_id));
+            }
+            
+            let replaced: i64 = redis::Script::new(REPLACE_UNIQUE_SCRIPT)
+                .key(&key)
+                .arg(holder_id.to_string())
+                .arg(&member)
+                .arg(ttl_secs)
+                .invoke_async(&mut conn)
+                .await?;
+            
+            if replaced == 1 {
+                return Ok(None);
+            }
+        }
+        
+        Err(IronForgeError::QueueBackend(format!(
+            "unique lock {} is contended",
+            unique.key
+        )))
+    }
+    
+    async fn release_unique(&self, job: &Job) -> Result<()> {
+        let Some(unique) = &job.unique else {
+            return Ok(());
+        };
+        
+        let mut conn = self.conn_manager.clone();
+        let mut pipe = redis::pipe();
+        Self::settle_unique(&mut pipe, unique, &job.id.to_string(), "release");
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        Ok(())
+    }
+    
+    async fn merge_unique(&self, existing_id: Uuid, duplicate: &Job) -> Result<Option<Job>> {
+        // Le statut ne change pas : le verrou d'unicité reste en l'état
+        let merged = self.rewrite_job(existing_id, |existing, pipe| {
+            existing.merge_duplicate(duplicate);
+            Self::write_job(pipe, existing)?;
+            if existing.status == JobStatus::Queued {
+                // Recalcule le score si le job est encore dans sa queue d'exécution
+                pipe.cmd("ZADD")
+                    .arg(Self::ready_key(existing))
+                    .arg("XX")
+                    .arg(existing.calculate_redis_score())
+                    .arg(existing_id.to_string())
+                    .ignore();
+            }
+            Ok(())
+        }).await?;
+        
+        let Some(existing) = merged else {
+            return Ok(None);
+        };
+        
+        tracing::info!(
+            job_id = %existing_id,
+            duplicate_id = %duplicate.id,
+            priority = ?existing.priority,
+            "Duplicate merged into unique job"
+        );
+        
+        Ok(Some(existing))
+    }
+    
+    async fn enqueue_batch(&self, jobs: &[Job]) -> Result<()> {
+        if jobs.is_empty() {
+            return Ok(());
+        }
+        
+        let mut conn = self.conn_manager.clone();
+        
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        for job in jobs {
+            Self::store_job(&mut pipe, job)?;
+            Self::push_ready(&mut pipe, job);
+        }
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        tracing::info!(
+            count = jobs.len(),
+            batch_id = ?jobs[0].batch_id,
+            "Job batch enqueued"
+        );
+        
+        Ok(())
+    }
+    
+    async fn enqueue_with_dependencies(&self, job: &Job) -> Result<JobStatus> {
+        if job.depends_on.is_empty() {
+            self.enqueue(job).await?;
+            return Ok(job.status);
+        }
+        
+        for parent_id in &job.depends_on {
+            if self.get_job(*parent_id).await?.is_none() {
+                return Err(IronForgeError::JobNotFound(parent_id.to_string()));
+            }
+        }
+        
+        let mut conn = self.conn_manager.clone();
+        let mut waiting = job.clone();
+        waiting.status = JobStatus::Waiting;
+        
+        // Enregistre le job avant de vérifier les parents : un parent qui se
+        // termine entre-temps verra forcément ce dépendant
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        Self::store_job(&mut pipe, &waiting)?;
+        for parent_id in &job.depends_on {
+            pipe.sadd(Self::dependents_key(*parent_id), job.id.to_string()).ignore();
+        }
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        tracing::info!(
+            job_id = %job.id,
+            kind = %job.kind,
+            depends_on = ?job.depends_on,
+            "Job waiting for dependencies"
+        );
+        
+        self.settle_dependencies(&waiting).await
+    }
+    
+    async fn resolve_dependents(&self, parent_id: Uuid) -> Result<()> {
+        if let Some(workflow_id) = self.get_job(parent_id).await?.and_then(|job| job.workflow_id) {
+            self.settle_workflow(workflow_id).await?;
+        }
+        
+        let mut conn = self.conn_manager.clone();
+        let children: Vec<String> = conn.smembers(Self::dependents_key(parent_id)).await?;
+        
+        for child_id_str in children {
+            let child_id = Uuid::parse_str(&child_id_str)
+                .map_err(|e| IronForgeError::QueueBackend(e.to_string()))?;
+            
+            match self.get_job(child_id).await? {
+                Some(child) if child.status == JobStatus::Waiting => {
+                    self.settle_dependencies(&child).await?;
+                }
+                _ => {}
+            }
+        }
+        
+        Ok(())
+    }
+    
+    async fn create_workflow(&self, plan: &WorkflowPlan) -> Result<()> {
+        // Les parents extérieurs au workflow doivent exister
+        let own: HashSet<Uuid> = plan.jobs.iter().map(|job| job.id).collect();
+        let external = |job: &Job| job.depends_on.iter().any(|parent_id| !own.contains(parent_id));
+        for parent_id in plan.jobs.iter().flat_map(|job| &job.depends_on) {
+            if !own.contains(parent_id) && self.get_job(*parent_id).await?.is_none() {
+                return Err(IronForgeError::JobNotFound(parent_id.to_string()));
+            }
+        }
+        
+        // Workflow, errback et jobs sont écrits en une seule transaction : un
+        // échec ne laisse aucun job orphelin. Les parents internes ne sont pas
+        // terminés, leurs dépendants restent en Waiting.
+        let mut conn = self.conn_manager.clone();
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .set(Self::workflow_key(plan.workflow.id), serde_json::to_string(&plan.workflow)?)
+            .ignore();
+        
+        // L'errback est stocké en attente, il n'est enqueue qu'en cas d'échec
+        if let Some(errback) = &plan.errback {
+            let mut waiting = errback.clone();
+            waiting.status = JobStatus::Waiting;
+            Self::store_job(&mut pipe, &waiting)?;
+        }
+        
+        let mut settle = Vec::new();
+        for job in &plan.jobs {
+            if job.depends_on.is_empty() {
+                Self::store_job(&mut pipe, job)?;
+                Self::push_ready(&mut pipe, job);
+                continue;
+            }
+            
+            let mut waiting = job.clone();
+            waiting.status = JobStatus::Waiting;
+            Self::store_job(&mut pipe, &waiting)?;
+            for parent_id in &job.depends_on {
+                pipe.sadd(Self::dependents_key(*parent_id), job.id.to_string()).ignore();
+            }
+            if external(job) {
+                settle.push(waiting);
+            }
+        }
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        // Un parent extérieur a pu se terminer avant l'écriture du workflow
+        for waiting in &settle {
+            self.settle_dependencies(waiting).await?;
+        }
+        
+        tracing::info!(
+            workflow_id = %plan.workflow.id,
+            kind = ?plan.workflow.kind,
+            jobs = plan.jobs.len(),
+            "Workflow created"
+        );
+        
+        Ok(())
+    }
+    
+    async fn get_workflow(&self, workflow_id: Uuid) -> Result<Option<Workflow>> {
+        let mut conn = self.conn_manager.clone();
+        let workflow_json: Option<String> = conn.get(Self::workflow_key(workflow_id)).await?;
+        
+        match workflow_json {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn dequeue(&self, timeout_secs: u64) -> Result<Option<Job>> {
+        let mut conn = self.conn_manager.clone();
+        
+        let tenants: Vec<String> = conn.smembers(Self::tenants_key()).await?;
+        let mut keys: Vec<String> = tenants.iter().map(|tenant| Self::tenant_queue_key(tenant)).collect();
+        keys.sort();
+        keys.insert(0, Self::queue_key().to_string());
+        
+        // BZPOPMIN sert la première queue non vide : on commence après la
+        // dernière queue servie pour servir les tenants à tour de rôle. Un tenant
+        // apparu pendant l'attente n'est pris en compte qu'au dequeue suivant.
+        let last_served = self.last_served.lock().unwrap().clone();
+        if let Some(position) = last_served.and_then(|last| keys.iter().position(|key| *key == last)) {
+            keys.rotate_left(position + 1);
+        }
+        
+        // Pop bloquant du job avec le score le plus bas (plus prioritaire)
+        let result: Option<(String, String, i64)> = conn
+            .bzpopmin(keys, timeout_secs as f64)
+            .await?;
+        
+        if let Some((key, job_id_str, _score)) = result {
+            *self.last_served.lock().unwrap() = Some(key);
+            
+            let job_id = Uuid::parse_str(&job_id_str)
+                .map_err(|e| IronForgeError::QueueBackend(e.to_string()))?;
+            
+            // Récupère le job complet
+            if let Some(job) = self.get_job(job_id).await? {
+                tracing::debug!(job_id = %job.id, "Job dequeued");
+                return Ok(Some(job));
+            }
+        }
+        
+        Ok(None)
+    }
+    
+    async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>> {
+        let mut conn = self.conn_manager.clone();
+        let job_json: Option<String> = conn.get(Self::job_key(job_id)).await?;
+        
+        if let Some(json) = job_json {
+            let job: Job = serde_json::from_str(&json)?;
+            Ok(Some(job))
+        } else {
+            Ok(None)
+        }
+    }
+    
+    async fn list_jobs(
+        &self,
+        filter: &JobFilter,
+        cursor: Option<JobCursor>,
+        limit: usize,
+    ) -> Result<JobPage> {
+        let mut conn = self.conn_manager.clone();
+        let index = Self::driving_index_key(filter);
+        let min = filter
+            .created_after
+            .map(|after| after.timestamp_millis().to_string())
+            .unwrap_or_else(|| "-inf".to_string());
+        
+        // Reprend juste après le curseur : à score égal, l'ordre décroissant
+        // place d'abord les ids supérieurs ou égaux, déjà parcourus
+        let (max, mut offset) = match cursor {
+            Some(cursor) => {
+                let cursor_member = cursor.job_id.to_string();
+                let same_score: Vec<String> = conn
+                    .zrangebyscore(&index, cursor.created_at_ms, cursor.created_at_ms)
+                    .await?;
+                let seen = same_score
+                    .iter()
+                    .filter(|member| member.as_str() >= cursor_member.as_str())
+                    .count();
+                (cursor.created_at_ms.to_string(), seen)
+            }
+            None => ("+inf".to_string(), 0),
+        };
+        
+        let mut jobs = Vec::new();
+        let mut scanned = 0;
+        
+        loop {
+            let batch: Vec<(String, f64)> = conn
+                .zrevrangebyscore_limit_withscores(&index, &max, &min, offset as isize, LIST_SCAN_BATCH as isize)
+                .await?;
+            if batch.is_empty() {
+                return Ok(JobPage { jobs, next_cursor: None });
+            }
+            offset += batch.len();
+            
+            let keys: Vec<String> = batch
+                .iter()
+                .filter_map(|(member, _)| Uuid::parse_str(member).ok())
+                .map(Self::job_key)
+                .collect();
+            let jobs_json: Vec<Option<String>> = redis::cmd("MGET")
+                .arg(&keys)
+                .query_async(&mut conn)
+                .await?;
+            
+            let mut last = None;
+            for ((member, score), json) in batch.iter().zip(jobs_json) {
+                scanned += 1;
+                if let Ok(job_id) = Uuid::parse_str(member) {
+                    last = Some(JobCursor { created_at_ms: *score as i64, job_id });
+                }
+                
+                // Entrée d'un job supprimé entre-temps
+                let Some(json) = json else { continue };
+                let job: Job = serde_json::from_str(&json)?;
+                
+                if filter.matches(&job) {
+                    jobs.push(job);
+                    if jobs.len() == limit {
+                        return Ok(JobPage {
+                            jobs,
+                            next_cursor: last.map(|cursor| cursor.to_string()),
+                        });
+                    }
+                }
+            }
+            
+            if batch.len() < LIST_SCAN_BATCH {
+                return Ok(JobPage { jobs, next_cursor: None });
+            }
+            
+            // Page partielle : le client reprend la recherche avec le curseur
+            if scanned >= MAX_LIST_SCAN {
+                return Ok(JobPage {
+                    jobs,
+                    next_cursor: last.map(|cursor| cursor.to_string()),
+                });
+            }
+        }
+    }
+    
+    async fn update_job(&self, job: &Job) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        Self::store_job(&mut pipe, job)?;
+        Self::schedule_first_webhook(&mut pipe, job);
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        tracing::debug!(
+            job_id = %job.id,
+            status = ?job.status,
+            "Job updated"
+        );
+        
+        Ok(())
+    }
+    
+    async fn update_job_if_version(&self, job: &mut Job) -> Result<()> {
+        let expected = job.version;
+        job.version += 1;
+        
+        let mut pipe = redis::pipe();
+        Self::write_job(&mut pipe, job)?;
+        if job.status == JobStatus::Queued {
+            // Recalcule sa place : un job dequeue mais pas encore démarré est
+            // ainsi remis en queue, le worker qui le démarre en second l'ignore
+            let member = job.id.to_string();
+            pipe.zrem(Self::ready_key(job), &member).ignore()
+                .zrem(Self::delayed_key(), &member).ignore()
+                .zrem(Self::parked_key(), &member).ignore();
+            Self::push_ready(&mut pipe, job);
+        }
+        if let Err(e) = self.write_if_version(job.id, expected, &pipe).await {
+            job.version = expected;
+            return Err(e);
+        }
+        
+        tracing::debug!(job_id = %job.id, version = job.version, "Job updated");
+        
+        Ok(())
+    }
+    
+    async fn transition_job(&self, job: &mut Job, from: JobStatus) -> Result<()> {
+        if !from.can_transition_to(job.status) {
+            return Err(IronForgeError::InvalidStatusTransition { from, to: job.status });
+        }
+        
+        let expected = job.version;
+        job.version += 1;
+        
+        let mut pipe = redis::pipe();
+        Self::write_job(&mut pipe, job)?;
+        if job.status == JobStatus::Queued {
+            Self::push_ready(&mut pipe, job);
+        }
+        Self::schedule_first_webhook(&mut pipe, job);
+        if let Err(e) = self.write_if_version(job.id, expected, &pipe).await {
+            job.version = expected;
+            return Err(e);
+        }
+        
+        // Le verrou d'unicité suit le nouveau statut (script idempotent, hors du compare-and-set)
+        if job.unique.is_some() {
+            let mut conn = self.conn_manager.clone();
+            let mut pipe = redis::pipe();
+            Self::settle_job_unique(&mut pipe, job);
+            let _: () = pipe.query_async(&mut conn).await?;
+        }
+        
+        tracing::debug!(
+            job_id = %job.id,
+            from = ?from,
+            to = ?job.status,
+            "Job status changed"
+        );
+        
+        Ok(())
+    }
+    
+    async fn delete_job(&self, job_id: Uuid) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let member = job_id.to_string();
+        
+        // Supprime de toutes les structures, index secondaires compris
+        let mut pipe = redis::pipe();
+        pipe.atomic();
+        if let Some(job) = self.get_job(job_id).await? {
+            for key in Self::static_index_keys(&job) {
+                pipe.zrem(key, &member).ignore();
+            }
+            if let Some(unique) = &job.unique {
+                Self::settle_unique(&mut pipe, unique, &member, "release");
+            }
+            pipe.zrem(Self::ready_key(&job), &member).ignore();
+        }
+        for status in JobStatus::ALL {
+            pipe.zrem(Self::status_index_key(status), &member).ignore();
+        }
+        pipe.del(Self::job_key(job_id)).ignore()
+            .zrem(Self::queue_key(), &member).ignore()
+            .zrem(Self::parked_key(), &member).ignore()
+            .zrem(Self::delayed_key(), &member).ignore()
+            .srem(Self::active_jobs_key(), &member).ignore();
+        let _: () = pipe.query_async(&mut conn).await?;
         
-        // Ajoute à la queue principale (Sorted Set)
-        let _: () = conn.zadd(Self::queue_key(), job.id.to_string(), score).await?;
+        tracing::info!(job_id = %job_id, "Job deleted");
         
-        tracing::info!(
-            job_id = %job.id,
-            kind = %job.kind,
-            priority = ?job.priority,
-            "Job enqueued"
-        );
+        Ok(())
+    }
+    
+    async fn publish_progress(&self, job: &Job, progress: serde_json::Value) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let event = serde_json::to_string(&JobEvent::progress(job, progress))?;
+        let _: () = conn.publish(Self::events_channel(), event).await?;
         
         Ok(())
     }
     
-    async fn dequeue(&self, timeout_secs: u64) -> Result<Option<Job>> {
+    async fn subscribe_events(&self) -> Result<BoxStream<'static, JobEvent>> {
+        // Connexion dédiée : une connexion abonnée ne peut plus servir aux commandes
+        let mut pubsub = self.client.get_async_pubsub().await?;
+        pubsub.subscribe(Self::events_channel()).await?;
+        
+        let events = pubsub.into_on_message().filter_map(|message| async move {
+            let payload: String = message.get_payload().ok()?;
+            serde_json::from_str(&payload).ok()
+        });
+        
+        Ok(events.boxed())
+    }
+    
+    async fn claim_due_webhooks(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+        lease_secs: u64,
+    ) -> Result<Vec<(Uuid, u32)>> {
         let mut conn = self.conn_manager.clone();
         
-        // BZPOPMIN : Pop bloquant du job avec le score le plus bas (plus prioritaire)
-        let result: Option<(String, String, i64)> = conn
-            .bzpopmin(Self::queue_key(), timeout_secs as f64)
+        let due: Vec<String> = conn
+            .zrangebyscore_limit(
+                Self::webhooks_pending_key(),
+                "-inf",
+                now.timestamp_millis(),
+                0,
+                limit as isize,
+            )
             .await?;
         
-        if let Some((_key, job_id_str, _score)) = result {
-            let job_id = Uuid::parse_str(&job_id_str)
-                .map_err(|e| IronForgeError::QueueBackend(e.to_string()))?;
+        let lease_until = (now + chrono::Duration::seconds(lease_secs as i64)).timestamp_millis();
+        let mut claimed = Vec::with_capacity(due.len());
+        for member in due {
+            // La livraison reste en attente jusqu'à la fin du bail : une seule
+            // instance la réserve, et elle est reprise si celle-ci s'arrête
+            let leased: i64 = redis::Script::new(LEASE_WEBHOOK_SCRIPT)
+                .key(Self::webhooks_pending_key())
+                .arg(&member)
+                .arg(now.timestamp_millis())
+                .arg(lease_until)
+                .invoke_async(&mut conn)
+                .await?;
+            if leased == 0 {
+                continue;
+            }
             
-            // Récupère le job complet
-            if let Some(job) = self.get_job(job_id).await? {
-                tracing::debug!(job_id = %job.id, "Job dequeued");
-                return Ok(Some(job));
+            let parsed = member.split_once(':').and_then(|(job_id, attempt)| {
+                Some((Uuid::parse_str(job_id).ok()?, attempt.parse().ok()?))
+            });
+            match parsed {
+                Some(delivery) => claimed.push(delivery),
+                None => {
+                    tracing::warn!(member = %member, "Invalid webhook delivery entry");
+                    let _: () = conn.zrem(Self::webhooks_pending_key(), &member).await?;
+                }
             }
         }
         
-        Ok(None)
+        Ok(claimed)
     }
     
-    async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>> {
+    async fn complete_webhook(
+        &self,
+        job_id: Uuid,
+        attempt: u32,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<()> {
         let mut conn = self.conn_manager.clone();
-        let job_json: Option<String> = conn.get(Self::job_key(job_id)).await?;
         
-        if let Some(json) = job_json {
-            let job: Job = serde_json::from_str(&json)?;
-            Ok(Some(job))
-        } else {
-            Ok(None)
+        let mut pipe = redis::pipe();
+        pipe.atomic()
+            .zrem(Self::webhooks_pending_key(), format!("{}:{}", job_id, attempt))
+            .ignore();
+        if let Some(due_at) = next_attempt_at {
+            pipe.zadd(Self::webhooks_pending_key(), format!("{}:{}", job_id, attempt + 1), due_at.timestamp_millis())
+                .ignore();
         }
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        Ok(())
     }
     
-    async fn update_job(&self, job: &Job) -> Result<()> {
-        let mut conn = self.conn_manager.clone();
-        let job_json = serde_json::to_string(job)?;
-        
-        let _: () = conn.set(Self::job_key(job.id), job_json).await?;
-        
-        tracing::debug!(
-            job_id = %job.id,
-            status = ?job.status,
-            "Job updated"
-        );
+    async fn record_webhook_delivery(&self, job_id: Uuid, delivery: &WebhookDelivery) -> Result<()> {
+        // Écriture directe : le statut ne change pas, ni index ni événement
+        self.rewrite_job(job_id, |job, pipe| {
+            job.webhook_deliveries.push(delivery.clone());
+            pipe.set(Self::job_key(job_id), serde_json::to_string(job)?).ignore();
+            Ok(())
+        }).await?;
         
         Ok(())
     }
     
-    async fn delete_job(&self, job_id: Uuid) -> Result<()> {
+    async fn promote_delayed(&self, now: DateTime<Utc>, limit: usize) -> Result<u64> {
         let mut conn = self.conn_manager.clone();
+        let now_ms = now.timestamp_millis();
         
-        // Supprime de toutes les structures
-        let _: () = conn.del(Self::job_key(job_id)).await?;
-        let _: () = conn.zrem(Self::queue_key(), job_id.to_string()).await?;
-        let _: () = conn.srem(Self::active_jobs_key(), job_id.to_string()).await?;
+        let due: Vec<String> = conn
+            .zrangebyscore_limit(Self::delayed_key(), "-inf", now_ms, 0, limit as isize)
+            .await?;
         
-        tracing::info!(job_id = %job_id, "Job deleted");
+        let mut promoted = 0;
+        for member in due {
+            let job = match Uuid::parse_str(&member) {
+                Ok(job_id) => self.get_job(job_id).await?,
+                Err(_) => None,
+            };
+            
+            // Job supprimé ou déjà sorti de la queue entre-temps
+            let Some(job) = job.filter(|job| job.status == JobStatus::Queued) else {
+                let _: () = conn.zrem(Self::delayed_key(), &member).await?;
+                continue;
+            };
+            
+            let moved: i64 = redis::Script::new(PROMOTE_DELAYED_SCRIPT)
+                .key(Self::delayed_key())
+                .key(Self::ready_key(&job))
+                .arg(&member)
+                .arg(job.calculate_redis_score())
+                .arg(now_ms)
+                .invoke_async(&mut conn)
+                .await?;
+            promoted += moved as u64;
+        }
         
-        Ok(())
+        if promoted > 0 {
+            tracing::debug!(count = promoted, "Delayed jobs promoted");
+        }
+        
+        Ok(promoted)
     }
     
     async fn move_to_dlq(&self, job: &Job) -> Result<()> {
-        let mut conn = self.conn_manager.clone();
-        let mut updated_job = job.clone();
-        updated_job.status = JobStatus::DeadLetter;
-        
-        // Met à jour le statut
-        self.update_job(&updated_job).await?;
-        
-        // Ajoute à la DLQ
-        let _: () = conn.lpush(Self::dlq_key(), job.id.to_string()).await?;
+        // La DLQ est l'index du statut DeadLetter : un job n'y figure qu'une fois
+        self.modify_job(job.id, Some(JobStatus::DeadLetter), |latest| {
+            latest.absorb_execution(job);
+            Ok(())
+        }).await?;
         
         tracing::warn!(
             job_id = %job.id,
             kind = %job.kind,
             retry_count = job.retry_count,
+            error = job.last_error.as_deref().unwrap_or_default(),
             "Job moved to DLQ"
         );
         
@@ -137,14 +1328,28 @@ impl QueueBackend for RedisQueueBackend {
     async fn get_stats(&self) -> Result<QueueStats> {
         let mut conn = self.conn_manager.clone();
         
-        let queue_depth: i64 = conn.zcard(Self::queue_key()).await?;
-        let dlq_depth: i64 = conn.llen(Self::dlq_key()).await?;
+        let mut queue_depth: i64 = conn.zcard(Self::queue_key()).await?;
+        let tenants: Vec<String> = conn.smembers(Self::tenants_key()).await?;
+        let mut tenant_queue_depths = HashMap::new();
+        for tenant in tenants {
+            let depth: i64 = conn.zcard(Self::tenant_queue_key(&tenant)).await?;
+            queue_depth += depth;
+            tenant_queue_depths.insert(tenant, depth);
+        }
+        let dlq_depth: i64 = conn.zcard(Self::status_index_key(JobStatus::DeadLetter)).await?;
         let active_jobs: i64 = conn.scard(Self::active_jobs_key()).await?;
+        let parked_jobs: i64 = conn.zcard(Self::parked_key()).await?;
+        let delayed_jobs: i64 = conn.zcard(Self::delayed_key()).await?;
+        let paused = self.paused_targets().await?;
         
         Ok(QueueStats {
             queue_depth,
             dlq_depth,
             active_jobs,
+            parked_jobs,
+            delayed_jobs,
+            paused,
+            tenant_queue_depths,
         })
     }
     
@@ -181,6 +1386,292 @@ impl QueueBackend for RedisQueueBackend {
         
         Ok(())
     }
+    
+    async fn register_worker(&self, worker: &WorkerInfo, ttl_secs: u64) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let worker_json = serde_json::to_string(worker)?;
+        let expires_at = chrono::Utc::now().timestamp() + ttl_secs as i64;
+        
+        // La fiche expire d'elle-même si les heartbeats s'arrêtent
+        let _: () = redis::pipe()
+            .set_ex(Self::worker_key(&worker.id), worker_json, ttl_secs)
+            .ignore()
+            .zadd(Self::workers_registry_key(), &worker.id, expires_at)
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+        
+        tracing::debug!(worker_id = %worker.id, "Worker heartbeat");
+        
+        Ok(())
+    }
+    
+    async fn unregister_worker(&self, worker_id: &str) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        
+        let _: () = conn.del(Self::worker_key(worker_id)).await?;
+        let _: () = conn.zrem(Self::workers_registry_key(), worker_id).await?;
+        
+        tracing::info!(worker_id = %worker_id, "Worker unregistered");
+        
+        Ok(())
+    }
+    
+    async fn list_workers(&self) -> Result<Vec<WorkerInfo>> {
+        let mut conn = self.conn_manager.clone();
+        let now = chrono::Utc::now().timestamp();
+        
+        // Purge les workers dont le dernier heartbeat a expiré
+        let _: () = conn
+            .zrembyscore(Self::workers_registry_key(), "-inf", now)
+            .await?;
+        
+        let worker_ids: Vec<String> = conn.zrange(Self::workers_registry_key(), 0, -1).await?;
+        if worker_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let keys: Vec<String> = worker_ids.iter().map(|id| Self::worker_key(id)).collect();
+        let workers_json: Vec<Option<String>> = redis::cmd("MGET")
+            .arg(&keys)
+            .query_async(&mut conn)
+            .await?;
+        
+        let mut workers = Vec::with_capacity(workers_json.len());
+        for json in workers_json.into_iter().flatten() {
+            workers.push(serde_json::from_str::<WorkerInfo>(&json)?);
+        }
+        
+        Ok(workers)
+    }
+    
+    async fn get_worker(&self, worker_id: &str) -> Result<Option<WorkerInfo>> {
+        let mut conn = self.conn_manager.clone();
+        let worker_json: Option<String> = conn.get(Self::worker_key(worker_id)).await?;
+        
+        match worker_json {
+            Some(json) => Ok(Some(serde_json::from_str(&json)?)),
+            None => Ok(None),
+        }
+    }
+    
+    async fn pause(&self, target: &PauseTarget) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let (key, name) = Self::pause_set_key(target);
+        
+        let _: () = conn.sadd(key, name).await?;
+        
+        tracing::warn!(target = ?target, "Paused");
+        
+        Ok(())
+    }
+    
+    async fn resume(&self, target: &PauseTarget) -> Result<u64> {
+        let mut conn = self.conn_manager.clone();
+        let (key, name) = Self::pause_set_key(target);
+        
+        let _: () = conn.srem(key, name).await?;
+        
+        // Remet en queue les jobs mis de côté qui ne sont plus en pause
+        let paused = self.paused_targets().await?;
+        let parked: Vec<(String, i64)> = conn
+            .zrange_withscores(Self::parked_key(), 0, -1)
+            .await?;
+        
+        let mut released = 0;
+        for (job_id_str, score) in parked {
+            let job_id = Uuid::parse_str(&job_id_str)
+                .map_err(|e| IronForgeError::QueueBackend(e.to_string()))?;
+            
+            let Some(job) = self.get_job(job_id).await? else {
+                let _: () = conn.zrem(Self::parked_key(), &job_id_str).await?;
+                continue;
+            };
+            
+            if paused.is_paused(&job) {
+                continue;
+            }
+            
+            // ZREM conditionne le ZADD pour ne pas dupliquer un job déjà relâché
+            let removed: i64 = conn.zrem(Self::parked_key(), &job_id_str).await?;
+            if removed == 1 {
+                let _: () = conn.zadd(Self::ready_key(&job), &job_id_str, score).await?;
+                released += 1;
+            }
+        }
+        
+        tracing::info!(target = ?target, released, "Resumed");
+        
+        Ok(released)
+    }
+    
+    async fn paused_targets(&self) -> Result<PausedTargets> {
+        let mut conn = self.conn_manager.clone();
+        
+        let mut queues: Vec<String> = conn.smembers(Self::paused_queues_key()).await?;
+        let mut kinds: Vec<String> = conn.smembers(Self::paused_kinds_key()).await?;
+        queues.sort();
+        kinds.sort();
+        
+        Ok(PausedTargets { queues, kinds })
+    }
+    
+    async fn park_job(&self, job: &Job) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        
+        let _: () = conn
+            .zadd(Self::parked_key(), job.id.to_string(), job.calculate_redis_score())
+            .await?;
+        
+        tracing::info!(
+            job_id = %job.id,
+            kind = %job.kind,
+            queue = %job.queue,
+            "Job parked (paused)"
+        );
+        
+        Ok(())
+    }
+    
+    async fn save_schedule(&self, schedule: &Schedule) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let schedule_json = serde_json::to_string(schedule)?;
+        let run_key = Self::schedule_run_key(schedule.id);
+        
+        // La définition fixe la prochaine exécution ; l'historique reste celui du scheduler
+        let mut pipe = redis::pipe();
+        pipe.set(Self::schedule_key(schedule.id), schedule_json)
+            .ignore()
+            .sadd(Self::schedules_index_key(), schedule.id.to_string())
+            .ignore();
+        match schedule.next_run_at {
+            Some(next_run_at) => pipe.hset(&run_key, "next_run_at", next_run_at.to_rfc3339()).ignore(),
+            None => pipe.hdel(&run_key, "next_run_at").ignore(),
+        };
+        let _: () = pipe.query_async(&mut conn).await?;
+        
+        tracing::debug!(schedule_id = %schedule.id, name = %schedule.name, "Schedule saved");
+        
+        Ok(())
+    }
+    
+    async fn get_schedule(&self, schedule_id: Uuid) -> Result<Option<Schedule>> {
+        let mut conn = self.conn_manager.clone();
+        let (schedule_json, run): (Option<String>, HashMap<String, String>) = redis::pipe()
+            .get(Self::schedule_key(schedule_id))
+            .hgetall(Self::schedule_run_key(schedule_id))
+            .query_async(&mut conn)
+            .await?;
+        
+        match schedule_json {
+            Some(json) => {
+                let mut schedule: Schedule = serde_json::from_str(&json)?;
+                Self::apply_schedule_run(&mut schedule, run);
+                Ok(Some(schedule))
+            }
+            None => Ok(None),
+        }
+    }
+    
+    async fn list_schedules(&self) -> Result<Vec<Schedule>> {
+        let mut conn = self.conn_manager.clone();
+        
+        let schedule_ids: Vec<String> = conn.smembers(Self::schedules_index_key()).await?;
+        let schedule_ids: Vec<Uuid> = schedule_ids
+            .iter()
+            .filter_map(|id| Uuid::parse_str(id).ok())
+            .collect();
+        if schedule_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        let keys: Vec<String> = schedule_ids.iter().copied().map(Self::schedule_key).collect();
+        let schedules_json: Vec<Option<String>> = redis::cmd("MGET")
+            .arg(&keys)
+            .query_async(&mut conn)
+            .await?;
+        
+        let mut pipe = redis::pipe();
+        for schedule_id in &schedule_ids {
+            pipe.hgetall(Self::schedule_run_key(*schedule_id));
+        }
+        let runs: Vec<HashMap<String, String>> = pipe.query_async(&mut conn).await?;
+        
+        let mut schedules = Vec::with_capacity(schedules_json.len());
+        for (json, run) in schedules_json.into_iter().zip(runs) {
+            if let Some(json) = json {
+                let mut schedule: Schedule = serde_json::from_str(&json)?;
+                Self::apply_schedule_run(&mut schedule, run);
+                schedules.push(schedule);
+            }
+        }
+        schedules.sort_by_key(|schedule| schedule.created_at);
+        
+        Ok(schedules)
+    }
+    
+    async fn record_schedule_run(&self, schedule: &Schedule, lease: &str, holder_id: &str) -> Result<bool> {
+        let mut conn = self.conn_manager.clone();
+        let format_date = |date: Option<DateTime<Utc>>| date.map(|date| date.to_rfc3339()).unwrap_or_default();
+        
+        let recorded: i64 = redis::Script::new(RECORD_SCHEDULE_RUN_SCRIPT)
+            .key(Self::schedule_key(schedule.id))
+            .key(Self::schedule_run_key(schedule.id))
+            .key(Self::leader_key(lease))
+            .arg(format_date(schedule.next_run_at))
+            .arg(format_date(schedule.last_run_at))
+            .arg(schedule.last_job_id.map(|id| id.to_string()).unwrap_or_default())
+            .arg(holder_id)
+            .invoke_async(&mut conn)
+            .await?;
+        
+        match recorded {
+            -1 => Err(IronForgeError::LeadershipLost(lease.to_string())),
+            recorded => Ok(recorded == 1),
+        }
+    }
+    
+    async fn delete_schedule(&self, schedule_id: Uuid) -> Result<bool> {
+        let mut conn = self.conn_manager.clone();
+        
+        let (deleted, _, _): (i64, i64, i64) = redis::pipe()
+            .del(Self::schedule_key(schedule_id))
+            .del(Self::schedule_run_key(schedule_id))
+            .srem(Self::schedules_index_key(), schedule_id.to_string())
+            .query_async(&mut conn)
+            .await?;
+        
+        tracing::info!(schedule_id = %schedule_id, "Schedule deleted");
+        
+        Ok(deleted == 1)
+    }
+    
+    async fn try_acquire_leadership(&self, lease: &str, holder_id: &str, ttl_secs: u64) -> Result<bool> {
+        let mut conn = self.conn_manager.clone();
+        
+        let acquired: i64 = redis::Script::new(ACQUIRE_LEADERSHIP_SCRIPT)
+            .key(Self::leader_key(lease))
+            .arg(holder_id)
+            .arg(ttl_secs)
+            .invoke_async(&mut conn)
+            .await?;
+        
+        Ok(acquired == 1)
+    }
+    
+    async fn current_leader(&self, lease: &str) -> Result<Option<String>> {
+        let mut conn = self.conn_manager.clone();
+        let leader: Option<String> = conn.get(Self::leader_key(lease)).await?;
+        
+        Ok(leader)
+    }
+    
+    async fn ping(&self) -> Result<()> {
+        let mut conn = self.conn_manager.clone();
+        let _: String = redis::cmd("PING").query_async(&mut conn).await?;
+        
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -210,4 +1701,150 @@ mod tests {
         
         assert_eq!(dequeued.id, job.id);
     }
+
+    #[tokio::test]
+    #[ignore] // À exécuter manuellement avec Redis lancé
+    async fn test_redis_worker_registry() {
+        let backend = RedisQueueBackend::new("redis://127.0.0.1:6379")
+            .await
+            .expect("Failed to connect to Redis");
+        
+        let worker = WorkerInfo::new(WorkerInfo::generate_id(), vec!["test.job".to_string()], 2);
+        backend.register_worker(&worker, 30).await.expect("Failed to register");
+        
+        let workers = backend.list_workers().await.expect("Failed to list workers");
+        assert!(workers.iter().any(|w| w.id == worker.id));
+        
+        backend.unregister_worker(&worker.id).await.expect("Failed to unregister");
+        assert!(backend.get_worker(&worker.id).await.expect("Failed to get worker").is_none());
+    }
+
+    #[tokio::test]
+    #[ignore] // À exécuter manuellement avec Redis lancé
+    async fn test_redis_list_jobs_pagination() {
+        let backend = RedisQueueBackend::new("redis://127.0.0.1:6379")
+            .await
+            .expect("Failed to connect to Redis");
+        
+        let kind = format!("test.list.{}", Uuid::new_v4().simple());
+        let mut ids = Vec::new();
+        for i in 0..5 {
+            let job = Job::new(kind.clone(), serde_json::json!({ "i": i }));
+            backend.update_job(&job).await.expect("Failed to store job");
+            ids.push(job.id);
+        }
+        
+        let filter = JobFilter { kind: Some(kind), ..Default::default() };
+        let first = backend.list_jobs(&filter, None, 3).await.expect("Failed to list");
+        assert_eq!(first.jobs.len(), 3);
+        
+        let cursor = first.next_cursor.expect("Missing cursor").parse().unwrap();
+        let second = backend.list_jobs(&filter, Some(cursor), 3).await.expect("Failed to list");
+        assert_eq!(second.jobs.len(), 2);
+        
+        let mut seen: Vec<Uuid> = first.jobs.iter().chain(&second.jobs).map(|j| j.id).collect();
+        seen.sort();
+        ids.sort();
+        assert_eq!(seen, ids);
+        
+        for id in ids {
+            let _ = backend.delete_job(id).await;
+        }
+    }
+
+    #[tokio::test]
+    #[ignore] // À exécuter manuellement avec Redis lancé
+    async fn test_redis_unique_lock_released_when_job_starts() {
+        let backend = RedisQueueBackend::new("redis://127.0.0.1:6379")
+            .await
+            .expect("Failed to connect to Redis");
+        
+        let key = Uuid::new_v4().to_string();
+        let new_job = || {
+            let req: crate::models::CreateJobRequest = serde_json::from_value(serde_json::json!({
+                "kind": "test.unique",
+                "payload": {},
+                "unique": { "key": key },
+            }))
+            .unwrap();
+            req.into_job()
+        };
+        
+        let mut first = new_job();
+        assert_eq!(backend.claim_unique(&first).await.unwrap(), None);
+        backend.enqueue(&first).await.expect("Failed to enqueue");
+        
+        let duplicate = new_job();
+        assert_eq!(backend.claim_unique(&duplicate).await.unwrap(), Some(first.id));
+        
+        // Scope `queued` : le verrou est libéré quand le job démarre
+        first.status = JobStatus::Running;
+        backend.update_job(&first).await.expect("Failed to update");
+        assert_eq!(backend.claim_unique(&duplicate).await.unwrap(), None);
+        
+        let _ = backend.release_unique(&duplicate).await;
+        let _ = backend.delete_job(first.id).await;
+    }
+    
+    #[tokio::test]
+    #[ignore] // À exécuter manuellement avec Redis lancé
+    async fn test_redis_stale_version_is_rejected() {
+        let backend = RedisQueueBackend::new("redis://127.0.0.1:6379")
+            .await
+            .expect("Failed to connect to Redis");
+        
+        let mut job = Job::new("test.transition".to_string(), serde_json::json!({}));
+        backend.update_job(&job).await.expect("Failed to store job");
+        let mut stale = job.clone();
+        
+        let from = job.transition(JobStatus::Running).unwrap();
+        backend.transition_job(&mut job, from).await.expect("Failed to start job");
+        assert_eq!(job.version, 1);
+        
+        // Un second worker partant de la même version perd la course
+        let from = stale.transition(JobStatus::Running).unwrap();
+        let err = backend.transition_job(&mut stale, from).await.unwrap_err();
+        assert!(matches!(err, IronForgeError::VersionConflict { expected: 0, actual: 1, .. }));
+        assert_eq!(stale.version, 0);
+        
+        // Rejouée sur la dernière version, la transition n'est plus permise
+        let err = backend.modify_job(job.id, Some(JobStatus::Running), |_| Ok(())).await.unwrap_err();
+        assert!(matches!(err, IronForgeError::InvalidStatusTransition { from: JobStatus::Running, .. }));
+        
+        let stored = backend.get_job(job.id).await.unwrap().unwrap();
+        assert_eq!((stored.status, stored.version), (JobStatus::Running, 1));
+        
+        let _ = backend.delete_job(job.id).await;
+    }
+    
+    #[tokio::test]
+    #[ignore]
+    async fn test_redis_tenants_served_in_turn() {
+        let backend = RedisQueueBackend::new("redis://127.0.0.1:6379")
+            .await
+            .expect("Failed to connect to Redis");
+        
+        let suffix = Uuid::new_v4().simple().to_string();
+        let busy = format!("busy-{}", suffix);
+        let quiet = format!("quiet-{}", suffix);
+        
+        let mut jobs = Vec::new();
+        for tenant in [&busy, &busy, &busy, &quiet] {
+            let mut job = Job::new("test.tenant".to_string(), serde_json::json!({}));
+            job.assign_tenant(Some(tenant.clone()));
+            jobs.push(job);
+        }
+        backend.enqueue_batch(&jobs).await.expect("Failed to enqueue");
+        
+        // Le tenant peu chargé passe au plus tard en deuxième position
+        let mut served = Vec::new();
+        for _ in 0..2 {
+            let job = backend.dequeue(1).await.expect("Failed to dequeue").expect("No job dequeued");
+            served.push(job.tenant.unwrap());
+        }
+        assert!(served.contains(&quiet));
+        
+        let stats = backend.get_stats().await.expect("Failed to get stats");
+        assert_eq!(stats.tenant_queue_depths.get(&busy), Some(&2));
+    }
 }
@@ -1,25 +1,176 @@
 use async_trait::async_trait;
+use futures::stream::BoxStream;
+use std::collections::HashMap;
 use uuid::Uuid;
-use crate::models::{Job, Result};
+use crate::models::{
+    IronForgeError, Job, JobCursor, JobEvent, JobFilter, JobPage, JobStatus, Result, Schedule,
+    TenantQuota, WebhookDelivery, WorkerInfo, Workflow, WorkflowPlan,
+};
+use chrono::{DateTime, Utc};
+
+/// Nombre d'écritures conditionnelles tentées par `modify_job` avant d'abandonner
+pub const JOB_UPDATE_ATTEMPTS: u32 = 5;
 
 /// Trait définissant l'interface d'un backend de queue
 #[async_trait]
 pub trait QueueBackend: Send + Sync {
-    /// Enqueue un job dans la queue principale
+    /// Enqueue un job dans la queue principale (ou celle de son tenant), ou parmi
+    /// les jobs différés si sa date d'exécution n'est pas atteinte
     async fn enqueue(&self, job: &Job) -> Result<()>;
     
-    /// Dequeue le job le plus prioritaire (opération bloquante)
+    /// Réserve la soumission de `count` jobs pour un tenant, dans la limite de
+    /// ses quotas. Retourne `QuotaExceeded` si un quota serait dépassé.
+    async fn reserve_submissions(&self, tenant: &str, quota: &TenantQuota, count: u64) -> Result<()>;
+    
+    /// Rend `count` soumissions réservées mais finalement non enqueue
+    /// (ex: l'enqueue a échoué)
+    async fn release_submissions(&self, tenant: &str, quota: &TenantQuota, count: u64) -> Result<()>;
+    
+    /// Réserve une clé d'idempotence pour `job_id` pendant `ttl_secs` secondes.
+    ///
+    /// Si la clé est déjà réservée, retourne `JobAlreadyExists` avec l'ID du job
+    /// d'origine quand l'empreinte est identique, `IdempotencyKeyConflict` sinon.
+    async fn claim_idempotency_key(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        job_id: Uuid,
+        ttl_secs: u64,
+    ) -> Result<()>;
+    
+    /// Libère une clé d'idempotence (ex: l'enqueue du job a échoué)
+    async fn release_idempotency_key(&self, key: &str) -> Result<()>;
+    
+    /// Réserve le verrou d'unicité du job (sans effet si le job n'est pas unique).
+    ///
+    /// Retourne l'ID du job qui détient déjà le verrou, le cas échéant.
+    async fn claim_unique(&self, job: &Job) -> Result<Option<Uuid>>;
+    
+    /// Libère le verrou d'unicité du job s'il le détient (ex: l'enqueue a échoué)
+    async fn release_unique(&self, job: &Job) -> Result<()>;
+    
+    /// Fusionne un doublon dans le job existant et retourne le job mis à jour
+    /// (`None` si le job existant n'est pas encore enregistré)
+    async fn merge_unique(&self, existing_id: Uuid, duplicate: &Job) -> Result<Option<Job>>;
+    
+    /// Enqueue plusieurs jobs sans dépendances en un seul aller-retour atomique
+    async fn enqueue_batch(&self, jobs: &[Job]) -> Result<()>;
+    
+    /// Enregistre un job qui dépend d'autres jobs : il reste en Waiting tant que
+    /// ses parents ne sont pas complétés. Retourne le statut résultant.
+    async fn enqueue_with_dependencies(&self, job: &Job) -> Result<JobStatus>;
+    
+    /// Débloque (ou annule selon leur politique) les jobs qui dépendent d'un job terminé
+    async fn resolve_dependents(&self, parent_id: Uuid) -> Result<()>;
+    
+    /// Enregistre un workflow et soumet ses jobs (l'errback reste en attente)
+    async fn create_workflow(&self, plan: &WorkflowPlan) -> Result<()>;
+    
+    /// Récupère un workflow par son ID
+    async fn get_workflow(&self, workflow_id: Uuid) -> Result<Option<Workflow>>;
+    
+    /// Dequeue le job le plus prioritaire (opération bloquante), en alternant
+    /// entre les tenants pour qu'aucun ne monopolise les workers
     async fn dequeue(&self, timeout_secs: u64) -> Result<Option<Job>>;
     
     /// Récupère un job par son ID
     async fn get_job(&self, job_id: Uuid) -> Result<Option<Job>>;
     
-    /// Met à jour un job existant
+    /// Liste les jobs correspondant au filtre, du plus récent au plus ancien
+    async fn list_jobs(
+        &self,
+        filter: &JobFilter,
+        cursor: Option<JobCursor>,
+        limit: usize,
+    ) -> Result<JobPage>;
+    
+    /// Enregistre un job sans contrôle de version (ex: job qui vient d'être créé)
     async fn update_job(&self, job: &Job) -> Result<()>;
     
+    /// Met à jour un job sans changer son statut, uniquement si sa version stockée
+    /// est encore `job.version` (écriture conditionnelle). En cas de succès,
+    /// `job.version` est incrémentée ; retourne `VersionConflict` sinon. Un job
+    /// Queued est replacé dans sa queue d'exécution ou parmi les jobs différés
+    /// selon sa priorité et sa date d'exécution.
+    async fn update_job_if_version(&self, job: &mut Job) -> Result<()>;
+    
+    /// Change le statut d'un job, passé de `from` à `job.status`, avec la même
+    /// écriture conditionnelle que `update_job_if_version`. Un job passant en
+    /// Queued rejoint sa queue d'exécution. Retourne `InvalidStatusTransition`
+    /// si la transition est interdite.
+    async fn transition_job(&self, job: &mut Job, from: JobStatus) -> Result<()>;
+    
+    /// Relit un job, lui applique `change` puis, si `to` est renseigné, le fait
+    /// passer dans ce statut, et l'écrit sous condition de version. Sur un conflit,
+    /// la même modification est rejouée sur la dernière version : la transition
+    /// doit alors rester permise depuis le statut relu, sinon l'écriture
+    /// concurrente l'emporte (`InvalidStatusTransition`). Retourne le job écrit.
+    async fn modify_job<F>(&self, job_id: Uuid, to: Option<JobStatus>, mut change: F) -> Result<Job>
+    where
+        F: FnMut(&mut Job) -> Result<()> + Send,
+    {
+        let mut attempt = 1;
+        
+        loop {
+            let mut job = self
+                .get_job(job_id)
+                .await?
+                .ok_or_else(|| IronForgeError::JobNotFound(job_id.to_string()))?;
+            change(&mut job)?;
+            
+            let written = match to {
+                Some(to) => {
+                    let from = job.transition(to)?;
+                    self.transition_job(&mut job, from).await
+                }
+                None => self.update_job_if_version(&mut job).await,
+            };
+            
+            match written {
+                Ok(()) => return Ok(job),
+                Err(IronForgeError::VersionConflict { .. }) if attempt < JOB_UPDATE_ATTEMPTS => attempt += 1,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+    
     /// Supprime un job
     async fn delete_job(&self, job_id: Uuid) -> Result<()>;
     
+    /// Publie un avancement du job sur le flux d'événements
+    async fn publish_progress(&self, job: &Job, progress: serde_json::Value) -> Result<()>;
+    
+    /// S'abonne aux événements de tous les jobs (changements d'état et avancements),
+    /// quelle que soit l'instance qui les publie
+    async fn subscribe_events(&self) -> Result<BoxStream<'static, JobEvent>>;
+    
+    /// Réserve les livraisons de webhooks échues (au plus `limit`) pour
+    /// `lease_secs` secondes : chacune n'est retournée qu'à une seule instance,
+    /// et redevient échue si elle n'est pas terminée avant la fin du bail
+    /// (instance arrêtée pendant la livraison). Retourne (job, tentative).
+    async fn claim_due_webhooks(
+        &self,
+        now: DateTime<Utc>,
+        limit: usize,
+        lease_secs: u64,
+    ) -> Result<Vec<(Uuid, u32)>>;
+    
+    /// Termine une livraison réservée (réussie ou abandonnée), en planifiant
+    /// la tentative suivante à `next_attempt_at` le cas échéant
+    async fn complete_webhook(
+        &self,
+        job_id: Uuid,
+        attempt: u32,
+        next_attempt_at: Option<DateTime<Utc>>,
+    ) -> Result<()>;
+    
+    /// Ajoute une tentative de livraison à l'historique du job
+    async fn record_webhook_delivery(&self, job_id: Uuid, delivery: &WebhookDelivery) -> Result<()>;
+    
+    /// Remet dans leur queue d'exécution les jobs différés arrivés à échéance
+    /// (au plus `limit`), retourne le nombre de jobs promus
+    async fn promote_delayed(&self, now: DateTime<Utc>, limit: usize) -> Result<u64>;
+    
     /// Ajoute un job à la Dead Letter Queue
     async fn move_to_dlq(&self, job: &Job) -> Result<()>;
     
@@ -31,6 +182,78 @@ pub trait QueueBackend: Send + Sync {
     
     /// Libère un verrou distribué
     async fn release_lock(&self, job_id: Uuid) -> Result<()>;
+    
+    /// Enregistre (ou rafraîchit) un worker pour `ttl_secs` secondes
+    async fn register_worker(&self, worker: &WorkerInfo, ttl_secs: u64) -> Result<()>;
+    
+    /// Retire un worker du registre
+    async fn unregister_worker(&self, worker_id: &str) -> Result<()>;
+    
+    /// Liste les workers vivants (les workers expirés sont purgés)
+    async fn list_workers(&self) -> Result<Vec<WorkerInfo>>;
+    
+    /// Récupère un worker vivant par son ID
+    async fn get_worker(&self, worker_id: &str) -> Result<Option<WorkerInfo>>;
+    
+    /// Met en pause une queue ou un type de job
+    async fn pause(&self, target: &PauseTarget) -> Result<()>;
+    
+    /// Reprend une queue ou un type de job, retourne le nombre de jobs remis en queue
+    async fn resume(&self, target: &PauseTarget) -> Result<u64>;
+    
+    /// Récupère les queues et types de jobs en pause
+    async fn paused_targets(&self) -> Result<PausedTargets>;
+    
+    /// Met de côté un job dequeue dont la queue ou le type est en pause
+    async fn park_job(&self, job: &Job) -> Result<()>;
+    
+    /// Crée ou remplace une planification
+    async fn save_schedule(&self, schedule: &Schedule) -> Result<()>;
+    
+    /// Récupère une planification par son ID
+    async fn get_schedule(&self, schedule_id: Uuid) -> Result<Option<Schedule>>;
+    
+    /// Liste toutes les planifications
+    async fn list_schedules(&self) -> Result<Vec<Schedule>>;
+    
+    /// Enregistre l'avancement d'une planification (`next_run_at`, `last_run_at`,
+    /// `last_job_id`) sans réécrire sa définition, si `holder_id` détient toujours
+    /// le bail `lease` (`LeadershipLost` sinon). Retourne `false` si elle a été
+    /// supprimée entre-temps.
+    async fn record_schedule_run(&self, schedule: &Schedule, lease: &str, holder_id: &str) -> Result<bool>;
+    
+    /// Supprime une planification, retourne `false` si elle n'existait pas
+    async fn delete_schedule(&self, schedule_id: Uuid) -> Result<bool>;
+    
+    /// Acquiert ou renouvelle le bail de leader `lease` pour `holder_id`
+    async fn try_acquire_leadership(&self, lease: &str, holder_id: &str, ttl_secs: u64) -> Result<bool>;
+    
+    /// Récupère le détenteur actuel du bail de leader `lease`
+    async fn current_leader(&self, lease: &str) -> Result<Option<String>>;
+    
+    /// Vérifie que le backend répond (sonde de readiness)
+    async fn ping(&self) -> Result<()>;
+}
+
+/// Cible d'une mise en pause
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PauseTarget {
+    Queue(String),
+    Kind(String),
+}
+
+/// Queues et types de jobs actuellement en pause
+#[derive(Debug, Clone, Default)]
+pub struct PausedTargets {
+    pub queues: Vec<String>,
+    pub kinds: Vec<String>,
+}
+
+impl PausedTargets {
+    /// Indique si le job ne doit pas être exécuté
+    pub fn is_paused(&self, job: &Job) -> bool {
+        self.queues.contains(&job.queue) || self.kinds.contains(&job.kind)
+    }
 }
 
 /// Statistiques de la queue
@@ -39,4 +262,36 @@ pub struct QueueStats {
     pub queue_depth: i64,
     pub dlq_depth: i64,
     pub active_jobs: i64,
+    /// Jobs mis de côté car leur queue ou leur type est en pause
+    pub parked_jobs: i64,
+    /// Jobs différés dont la date d'exécution n'est pas encore atteinte
+    pub delayed_jobs: i64,
+    pub paused: PausedTargets,
+    /// Jobs en attente d'exécution par tenant
+    pub tenant_queue_depths: HashMap<String, i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paused_targets_match_queue_or_kind() {
+        let mut job = Job::new("email.send".to_string(), serde_json::json!({}));
+        job.queue = "emails".to_string();
+
+        let paused = PausedTargets {
+            queues: vec!["emails".to_string()],
+            kinds: vec![],
+        };
+        assert!(paused.is_paused(&job));
+
+        let paused = PausedTargets {
+            queues: vec![],
+            kinds: vec!["email.send".to_string()],
+        };
+        assert!(paused.is_paused(&job));
+
+        assert!(!PausedTargets::default().is_paused(&job));
+    }
 }
@@ -0,0 +1,3 @@
+mod runner;
+
+pub use runner::{Scheduler, SchedulerConfig, SCHEDULER_LEASE};
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::{
+    models::{IronForgeError, Job, OverlapPolicy, Result, Schedule, TenantQuota, WorkerInfo},
+    queue::QueueBackend,
+};
+
+/// Nom du bail de leader partagé par les schedulers
+pub const SCHEDULER_LEASE: &str = "scheduler";
+
+/// Configuration du scheduler cron
+#[derive(Debug, Clone)]
+pub struct SchedulerConfig {
+    /// Identifiant de l'instance candidate au rôle de leader
+    pub instance_id: String,
+
+    /// Intervalle d'évaluation des planifications (en millisecondes)
+    pub tick_interval_ms: u64,
+
+    /// Durée du bail de leader (en secondes)
+    pub leader_ttl_secs: u64,
+
+    /// Quotas par tenant, appliqués aux exécutions comme aux soumissions de l'API
+    pub tenant_quotas: HashMap<String, TenantQuota>,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            instance_id: WorkerInfo::generate_id(),
+            tick_interval_ms: 1000,
+            leader_ttl_secs: 10,
+            tenant_quotas: HashMap::new(),
+        }
+    }
+}
+
+/// Scheduler cron - enqueue les exécutions des planifications.
+///
+/// Plusieurs instances peuvent tourner en parallèle : seule celle qui détient
+/// le bail de leader dans Redis évalue les planifications.
+pub struct Scheduler<Q: QueueBackend> {
+    queue: Arc<Q>,
+    config: SchedulerConfig,
+}
+
+impl<Q: QueueBackend + 'static> Scheduler<Q> {
+    /// Crée un nouveau scheduler
+    pub fn new(queue: Arc<Q>, config: SchedulerConfig) -> Self {
+        Self { queue, config }
+    }
+
+    /// Démarre la boucle d'élection et d'évaluation
+    pub async fn run(&self) -> Result<()> {
+        tracing::info!(instance_id = %self.config.instance_id, "Starting IronForge scheduler");
+
+        let mut interval = tokio::time::interval(Duration::from_millis(self.config.tick_interval_ms));
+        let mut is_leader = false;
+
+        loop {
+            interval.tick().await;
+
+            let leader = self
+                .queue
+                .try_acquire_leadership(SCHEDULER_LEASE, &self.config.instance_id, self.config.leader_ttl_secs)
+                .await;
+
+            match leader {
+                Ok(true) => {
+                    if !is_leader {
+                        tracing::info!(instance_id = %self.config.instance_id, "Scheduler leadership acquired");
+                        is_leader = true;
+                    }
+
+                    match self.tick(Utc::now()).await {
+                        Ok(_) => {}
+                        Err(IronForgeError::LeadershipLost(_)) => {
+                            tracing::warn!(instance_id = %self.config.instance_id, "Scheduler leadership lost during tick");
+                            is_leader = false;
+                        }
+                        Err(e) => tracing::error!(error = %e, "Failed to evaluate schedules"),
+                    }
+                }
+                Ok(false) => {
+                    if is_leader {
+                        tracing::warn!(instance_id = %self.config.instance_id, "Scheduler leadership lost");
+                        is_leader = false;
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to acquire scheduler leadership");
+                    is_leader = false;
+                }
+            }
+        }
+    }
+
+    /// Évalue toutes les planifications à `now`, retourne le nombre de jobs enqueue.
+    ///
+    /// Les exécutions sont réservées (avancement enregistré sous le bail de leader)
+    /// avant d'être enqueue : une instance qui a perdu le bail s'arrête sur
+    /// `LeadershipLost` sans rien enqueue.
+    pub async fn tick(&self, now: DateTime<Utc>) -> Result<usize> {
+        let mut enqueued = 0;
+
+        for mut schedule in self.queue.list_schedules().await? {
+            if !schedule.enabled {
+                continue;
+            }
+
+            match schedule.plan_runs(now) {
+                Ok(plan) => {
+                    if plan.runs.is_empty() && schedule.next_run_at == plan.next_run_at {
+                        continue;
+                    }
+
+                    // Seul l'avancement est écrit : une modification ou une suppression
+                    // concurrente de la planification n'est pas écrasée
+                    schedule.next_run_at = plan.next_run_at;
+                    if !self.record_run(&schedule).await? {
+                        tracing::info!(schedule_id = %schedule.id, "Schedule deleted before firing");
+                        continue;
+                    }
+
+                    let fired = self.fire(&mut schedule, &plan.runs).await;
+                    enqueued += fired;
+                    if fired > 0 && !self.record_run(&schedule).await? {
+                        tracing::info!(schedule_id = %schedule.id, "Schedule deleted while firing");
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(schedule_id = %schedule.id, error = %e, "Invalid schedule");
+                }
+            }
+        }
+
+        Ok(enqueued)
+    }
+
+    /// Enregistre l'avancement de la planification tant que l'instance détient le bail
+    async fn record_run(&self, schedule: &Schedule) -> Result<bool> {
+        self.queue
+            .record_schedule_run(schedule, SCHEDULER_LEASE, &self.config.instance_id)
+            .await
+    }
+
+    /// Enqueue un job par exécution, en respectant la politique de chevauchement.
+    ///
+    /// Une exécution en échec est journalisée puis abandonnée : les suivantes
+    /// sont tentées et l'avancement est enregistré dans tous les cas.
+    async fn fire(&self, schedule: &mut Schedule, runs: &[DateTime<Utc>]) -> usize {
+        let mut enqueued = 0;
+
+        for run_at in runs {
+            match self.fire_run(schedule, *run_at).await {
+                Ok(Some(job_id)) => {
+                    schedule.last_run_at = Some(*run_at);
+                    schedule.last_job_id = Some(job_id);
+                    enqueued += 1;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    tracing::error!(
+                        schedule_id = %schedule.id,
+                        run_at = %run_at,
+                        error = %e,
+                        "Failed to enqueue scheduled run"
+                    );
+                }
+            }
+        }
+
+        enqueued
+    }
+
+    /// Enqueue le job d'une exécution, retourne son ID (`None` si elle est sautée)
+    async fn fire_run(&self, schedule: &Schedule, run_at: DateTime<Utc>) -> Result<Option<Uuid>> {
+        if schedule.overlap == OverlapPolicy::Skip && self.previous_run_active(schedule).await? {
+            tracing::info!(
+                schedule_id = %schedule.id,
+                run_at = %run_at,
+                "Previous run still active, skipping"
+            );
+            return Ok(None);
+        }
+
+        let mut job = schedule.new_job();
+        job.metadata.insert("schedule_id".to_string(), schedule.id.to_string());
+        job.metadata.insert("schedule_run_at".to_string(), run_at.to_rfc3339());
+
+        if let Some(existing_id) = self.queue.claim_unique(&job).await? {
+            tracing::info!(
+                schedule_id = %schedule.id,
+                existing_job_id = %existing_id,
+                run_at = %run_at,
+                "Matching unique job exists, skipping"
+            );
+            return Ok(None);
+        }
+
+        if let Err(e) = self.reserve_quota(schedule).await {
+            self.release_unique(&job).await;
+            return match e {
+                IronForgeError::QuotaExceeded(reason) => {
+                    tracing::warn!(
+                        schedule_id = %schedule.id,
+                        run_at = %run_at,
+                        reason = %reason,
+                        "Tenant quota exceeded, skipping"
+                    );
+                    Ok(None)
+                }
+                e => Err(e),
+            };
+        }
+
+        if let Err(e) = self.queue.enqueue_with_dependencies(&job).await {
+            self.release_unique(&job).await;
+            return Err(e);
+        }
+
+        tracing::info!(
+            schedule_id = %schedule.id,
+            name = %schedule.name,
+            job_id = %job.id,
+            run_at = %run_at,
+            "Scheduled job enqueued"
+        );
+
+        Ok(Some(job.id))
+    }
+
+    /// Réserve une soumission dans les quotas du tenant de la planification
+    async fn reserve_quota(&self, schedule: &Schedule) -> Result<()> {
+        let Some(tenant) = &schedule.tenant else {
+            return Ok(());
+        };
+        let Some(quota) = self.config.tenant_quotas.get(tenant) else {
+            return Ok(());
+        };
+
+        self.queue.reserve_submissions(tenant, quota, 1).await
+    }
+
+    /// Libère le verrou d'unicité d'un job finalement non enqueue
+    async fn release_unique(&self, job: &Job) {
+        if let Err(e) = self.queue.release_unique(job).await {
+            tracing::warn!(error = %e, job_id = %job.id, "Failed to release unique lock");
+        }
+    }
+
+    async fn previous_run_active(&self, schedule: &Schedule) -> Result<bool> {
+        let Some(last_job_id) = schedule.last_job_id else {
+            return Ok(false);
+        };
+
+        Ok(self
+            .queue
+            .get_job(last_job_id)
+            .await?
+            .is_some_and(|job| !job.status.is_terminal()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{JobFilter, ScheduleRequest};
+    use crate::queue::MemoryQueueBackend;
+
+    #[tokio::test]
+    async fn test_only_the_lease_holder_enqueues_runs() {
+        let queue = Arc::new(MemoryQueueBackend::new());
+        let req: ScheduleRequest = serde_json::from_value(serde_json::json!({
+            "name": "every-second",
+            "cron": "* * * * * *",
+            "job": { "kind": "report.build", "payload": {} }
+        }))
+        .unwrap();
+        let mut schedule = Schedule::from_request(req).unwrap();
+        schedule.next_run_at = Some(Utc::now() - chrono::Duration::seconds(1));
+        queue.save_schedule(&schedule).await.unwrap();
+
+        let leader = Scheduler::new(queue.clone(), SchedulerConfig::default());
+        let stale = Scheduler::new(queue.clone(), SchedulerConfig::default());
+        assert!(queue
+            .try_acquire_leadership(SCHEDULER_LEASE, &leader.config.instance_id, 10)
+            .await
+            .unwrap());
+
+        // L'ancien leader ne réserve ni n'enqueue aucune exécution
+        let error = stale.tick(Utc::now()).await.unwrap_err();
+        assert!(matches!(error, IronForgeError::LeadershipLost(_)));
+        let stored = queue.get_schedule(schedule.id).await.unwrap().unwrap();
+        assert_eq!(stored.next_run_at, schedule.next_run_at);
+
+        assert_eq!(leader.tick(Utc::now()).await.unwrap(), 1);
+        let page = queue.list_jobs(&JobFilter::default(), None, 10).await.unwrap();
+        assert_eq!(page.jobs.len(), 1);
+        let stored = queue.get_schedule(schedule.id).await.unwrap().unwrap();
+        assert_eq!(stored.last_job_id, Some(page.jobs[0].id));
+    }
+}
@@ -0,0 +1,254 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use uuid::Uuid;
+
+use crate::{
+    models::{
+        is_internal_address, sign_webhook, validate_callback_url, IronForgeError, Result,
+        WebhookDelivery, WebhookNotification,
+    },
+    queue::QueueBackend,
+};
+
+/// Header portant la signature HMAC du corps (`sha256=<hex>`)
+pub const SIGNATURE_HEADER: &str = "X-IronForge-Signature";
+
+/// Header portant le timestamp (secondes Unix) inclus dans la signature
+pub const TIMESTAMP_HEADER: &str = "X-IronForge-Timestamp";
+
+/// Configuration de la livraison des webhooks
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    /// Secret de signature HMAC (obligatoire pour démarrer le dispatcher)
+    pub secret: Option<String>,
+
+    /// Nombre maximum de tentatives par notification
+    pub max_attempts: u32,
+
+    /// Délai avant la première nouvelle tentative (doublé à chaque échec)
+    pub retry_base_delay_secs: u64,
+
+    /// Délai maximum entre deux tentatives
+    pub retry_max_delay_secs: u64,
+
+    /// Timeout d'une requête HTTP
+    pub request_timeout_secs: u64,
+
+    /// Intervalle de recherche des livraisons échues (en millisecondes)
+    pub poll_interval_ms: u64,
+
+    /// Nombre maximum de livraisons traitées par intervalle
+    pub batch_size: usize,
+
+    /// Durée de réservation d'une livraison (en secondes) : passé ce délai sans
+    /// qu'elle soit terminée, une autre instance la reprend
+    pub claim_lease_secs: u64,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            secret: None,
+            max_attempts: 8,
+            retry_base_delay_secs: 5,
+            retry_max_delay_secs: 3600,
+            request_timeout_secs: 10,
+            poll_interval_ms: 1000,
+            batch_size: 100,
+            claim_lease_secs: 60,
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// Configuration par défaut, avec le secret lu dans `WEBHOOK_SECRET`
+    pub fn from_env() -> Self {
+        Self {
+            secret: std::env::var("WEBHOOK_SECRET").ok().filter(|secret| !secret.is_empty()),
+            ..Default::default()
+        }
+    }
+
+    /// Délai avant la tentative suivant la tentative `attempt` (échouée)
+    pub fn retry_delay(&self, attempt: u32) -> chrono::Duration {
+        let delay = self
+            .retry_base_delay_secs
+            .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.retry_max_delay_secs);
+        chrono::Duration::seconds(delay as i64)
+    }
+}
+
+/// Résolveur DNS qui écarte les adresses internes : un nom de domaine public
+/// peut pointer vers le réseau interne
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| !is_internal_address(addr.ip()))
+                .collect();
+
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", host).into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Livre les webhooks de fin de job planifiés dans le backend.
+///
+/// Plusieurs instances peuvent tourner en parallèle : chaque livraison échue
+/// n'est réservée que par une seule d'entre elles, le temps d'un bail. Une
+/// livraison n'est retirée qu'après un succès ou sa dernière tentative : elle
+/// est faite au moins une fois.
+pub struct WebhookDispatcher<Q: QueueBackend> {
+    queue: Arc<Q>,
+    config: WebhookConfig,
+    client: reqwest::Client,
+}
+
+impl<Q: QueueBackend + 'static> WebhookDispatcher<Q> {
+    /// Crée un nouveau dispatcher ; refuse de démarrer sans secret de signature
+    pub fn new(queue: Arc<Q>, config: WebhookConfig) -> Result<Self> {
+        if config.secret.is_none() {
+            return Err(IronForgeError::Config(
+                "WEBHOOK_SECRET is required to deliver webhooks".to_string(),
+            ));
+        }
+
+        // Pas de redirection : elle pourrait mener vers le réseau interne
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(config.request_timeout_secs))
+            .redirect(reqwest::redirect::Policy::none())
+            .dns_resolver(Arc::new(PublicResolver))
+            .build()
+            .map_err(|e| IronForgeError::Worker(format!("failed to build HTTP client: {}", e)))?;
+
+        Ok(Self { queue, config, client })
+    }
+
+    /// Démarre la boucle de livraison
+    pub async fn run(&self) -> Result<()> {
+        let mut interval = tokio::time::interval(Duration::from_millis(self.config.poll_interval_ms));
+
+        loop {
+            interval.tick().await;
+
+            if let Err(e) = self.tick(Utc::now()).await {
+                tracing::error!(error = %e, "Failed to deliver webhooks");
+            }
+        }
+    }
+
+    /// Livre les notifications échues à `now`, retourne le nombre de tentatives
+    pub async fn tick(&self, now: DateTime<Utc>) -> Result<usize> {
+        let due = self
+            .queue
+            .claim_due_webhooks(now, self.config.batch_size, self.config.claim_lease_secs)
+            .await?;
+        let count = due.len();
+
+        for (job_id, attempt) in due {
+            // Une livraison en erreur reste réservée : elle sera reprise à la fin du bail
+            if let Err(e) = self.deliver(job_id, attempt).await {
+                tracing::error!(job_id = %job_id, attempt, error = %e, "Failed to process webhook delivery");
+            }
+        }
+
+        Ok(count)
+    }
+
+    async fn deliver(&self, job_id: Uuid, attempt: u32) -> Result<()> {
+        let Some(job) = self.queue.get_job(job_id).await? else {
+            return self.queue.complete_webhook(job_id, attempt, None).await;
+        };
+        let Some(url) = &job.callback_url else {
+            return self.queue.complete_webhook(job_id, attempt, None).await;
+        };
+
+        // URL enregistrée avant le contrôle des cibles internes : abandon sans envoi
+        if let Err(reason) = validate_callback_url(url) {
+            tracing::warn!(job_id = %job_id, error = %reason, "Webhook target refused");
+            self.queue.complete_webhook(job_id, attempt, None).await?;
+            return self
+                .queue
+                .record_webhook_delivery(
+                    job_id,
+                    &WebhookDelivery {
+                        attempt,
+                        status: job.status,
+                        attempted_at: Utc::now(),
+                        response_status: None,
+                        error: Some(reason),
+                        success: false,
+                        next_attempt_at: None,
+                    },
+                )
+                .await;
+        }
+
+        let body = serde_json::to_vec(&WebhookNotification::new(&job, attempt))?;
+        let timestamp = Utc::now().timestamp();
+
+        let secret = self.config.secret.as_deref().unwrap_or_default();
+        let signature = sign_webhook(secret, timestamp, &body);
+
+        let request = self
+            .client
+            .post(url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .header(TIMESTAMP_HEADER, timestamp.to_string())
+            .header(SIGNATURE_HEADER, format!("sha256={}", signature));
+
+        let (response_status, error) = match request.body(body).send().await {
+            Ok(response) if response.status().is_success() => (Some(response.status().as_u16()), None),
+            Ok(response) => (
+                Some(response.status().as_u16()),
+                Some(format!("unexpected response status {}", response.status())),
+            ),
+            Err(e) => (None, Some(e.to_string())),
+        };
+
+        let success = error.is_none();
+        let next_attempt_at = (!success && attempt < self.config.max_attempts)
+            .then(|| Utc::now() + self.config.retry_delay(attempt));
+
+        self.queue.complete_webhook(job_id, attempt, next_attempt_at).await?;
+
+        match &error {
+            None => tracing::info!(job_id = %job_id, attempt, "Webhook delivered"),
+            Some(error) => tracing::warn!(
+                job_id = %job_id,
+                attempt,
+                error = %error,
+                will_retry = next_attempt_at.is_some(),
+                "Webhook delivery failed"
+            ),
+        }
+
+        self.queue
+            .record_webhook_delivery(
+                job_id,
+                &WebhookDelivery {
+                    attempt,
+                    status: job.status,
+                    attempted_at: Utc::now(),
+                    response_status,
+                    error,
+                    success,
+                    next_attempt_at,
+                },
+            )
+            .await
+    }
+}
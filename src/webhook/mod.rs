@@ -0,0 +1,3 @@
+mod dispatcher;
+
+pub use dispatcher::{WebhookConfig, WebhookDispatcher, SIGNATURE_HEADER, TIMESTAMP_HEADER};
@@ -1,13 +1,23 @@
-use std::sync::Arc;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
+use chrono::Utc;
+use tokio::sync::mpsc;
 use tokio::time::sleep;
+use uuid::Uuid;
 
 use crate::{
-    models::{Job, JobStatus, Result},
+    models::{AttemptOutcome, IronForgeError, Job, JobStatus, Result, WorkerInfo},
     queue::QueueBackend,
-    worker::handler::JobHandler,
+    worker::{handler::JobHandler, progress::ProgressReporter},
 };
 
+/// Délai maximum de publication des derniers avancements d'un job terminé
+const PROGRESS_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Nombre maximum de jobs différés promus par passage
+const PROMOTE_BATCH: usize = 100;
+
 /// Configuration du worker executor
 #[derive(Debug, Clone)]
 pub struct ExecutorConfig {
@@ -19,6 +29,21 @@ pub struct ExecutorConfig {
     
     /// Activer le mode graceful shutdown
     pub graceful_shutdown: bool,
+    
+    /// Identifiant de l'instance dans le registre des workers
+    pub instance_id: String,
+    
+    /// Types de jobs traités, annoncés dans le registre (vide = tous)
+    pub kinds: Vec<String>,
+    
+    /// Intervalle entre deux heartbeats (en secondes)
+    pub heartbeat_interval_secs: u64,
+    
+    /// Durée sans heartbeat après laquelle l'instance expire (en secondes)
+    pub heartbeat_ttl_secs: u64,
+    
+    /// Intervalle de promotion des jobs différés échus (en millisecondes)
+    pub delayed_poll_interval_ms: u64,
 }
 
 impl Default for ExecutorConfig {
@@ -27,15 +52,24 @@ impl Default for ExecutorConfig {
             dequeue_timeout_secs: 5,
             worker_count: 4,
             graceful_shutdown: true,
+            instance_id: WorkerInfo::generate_id(),
+            kinds: Vec::new(),
+            heartbeat_interval_secs: 10,
+            heartbeat_ttl_secs: 30,
+            delayed_poll_interval_ms: 1000,
         }
     }
 }
 
+/// Jobs en cours d'exécution sur l'instance
+type CurrentJobs = Arc<Mutex<HashSet<Uuid>>>;
+
 /// Worker executor - traite les jobs de la queue
 pub struct Executor<Q: QueueBackend, H: JobHandler> {
     queue: Arc<Q>,
     handler: Arc<H>,
     config: ExecutorConfig,
+    current_jobs: CurrentJobs,
 }
 
 impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
@@ -45,6 +79,7 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
             queue,
             handler,
             config,
+            current_jobs: Arc::new(Mutex::new(HashSet::new())),
         }
     }
 
@@ -52,9 +87,23 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
     pub async fn run(&self) -> Result<()> {
         tracing::info!(
             worker_count = self.config.worker_count,
+            instance_id = %self.config.instance_id,
             "Starting IronForge executor"
         );
 
+        // Annoncer l'instance dans le registre des workers
+        let heartbeat_handle = tokio::spawn(Self::heartbeat_loop(
+            self.queue.clone(),
+            self.config.clone(),
+            self.current_jobs.clone(),
+        ));
+
+        // Remettre en queue les jobs différés dont l'heure est venue
+        let promote_handle = tokio::spawn(Self::promote_loop(
+            self.queue.clone(),
+            self.config.delayed_poll_interval_ms,
+        ));
+
         let mut handles = vec![];
 
         // Lancer plusieurs workers en parallèle
@@ -62,9 +111,10 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
             let queue = self.queue.clone();
             let handler = self.handler.clone();
             let config = self.config.clone();
+            let current_jobs = self.current_jobs.clone();
 
             let handle = tokio::spawn(async move {
-                Self::worker_loop(worker_id, queue, handler, config).await
+                Self::worker_loop(worker_id, queue, handler, config, current_jobs).await
             });
 
             handles.push(handle);
@@ -77,15 +127,64 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
             }
         }
 
+        heartbeat_handle.abort();
+        promote_handle.abort();
+        if let Err(e) = self.queue.unregister_worker(&self.config.instance_id).await {
+            tracing::error!(error = %e, "Failed to unregister worker");
+        }
+
         Ok(())
     }
 
+    /// Boucle de heartbeat : publie l'état de l'instance à intervalle régulier
+    async fn heartbeat_loop(queue: Arc<Q>, config: ExecutorConfig, current_jobs: CurrentJobs) {
+        let mut info = WorkerInfo::new(
+            config.instance_id.clone(),
+            config.kinds.clone(),
+            config.worker_count,
+        );
+        let mut interval = tokio::time::interval(Duration::from_secs(config.heartbeat_interval_secs));
+
+        loop {
+            interval.tick().await;
+
+            info.last_heartbeat = Utc::now();
+            info.current_jobs = current_jobs.lock().unwrap().iter().copied().collect();
+
+            if let Err(e) = queue.register_worker(&info, config.heartbeat_ttl_secs).await {
+                tracing::error!(instance_id = %info.id, error = %e, "Failed to send heartbeat");
+            }
+        }
+    }
+
+    /// Boucle de promotion des jobs différés échus vers leur queue d'exécution
+    async fn promote_loop(queue: Arc<Q>, poll_interval_ms: u64) {
+        let mut interval = tokio::time::interval(Duration::from_millis(poll_interval_ms.max(1)));
+
+        loop {
+            interval.tick().await;
+
+            // Vider l'arriéré par lots avant d'attendre le prochain tick
+            loop {
+                match queue.promote_delayed(Utc::now(), PROMOTE_BATCH).await {
+                    Ok(count) if count as usize >= PROMOTE_BATCH => continue,
+                    Ok(_) => break,
+                    Err(e) => {
+                        tracing::error!(error = %e, "Failed to promote delayed jobs");
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
     /// Boucle de traitement d'un worker
     async fn worker_loop(
         worker_id: usize,
         queue: Arc<Q>,
         handler: Arc<H>,
         config: ExecutorConfig,
+        current_jobs: CurrentJobs,
     ) -> Result<()> {
         tracing::info!(worker_id, "Worker started");
 
@@ -104,14 +203,32 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
                 }
             };
 
+            // Ne pas exécuter les jobs dont la queue ou le type est en pause
+            match queue.paused_targets().await {
+                Ok(paused) if paused.is_paused(&job) => {
+                    if let Err(e) = queue.park_job(&job).await {
+                        tracing::error!(worker_id, job_id = %job.id, error = %e, "Failed to park job");
+                    }
+                    continue;
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    tracing::warn!(worker_id, error = %e, "Failed to fetch paused queues");
+                }
+            }
+
             // Traiter le job
-            Self::process_job(worker_id, &queue, &handler, job).await;
+            let job_id = job.id;
+            current_jobs.lock().unwrap().insert(job_id);
+            Self::process_job(worker_id, &config.instance_id, &queue, &handler, job).await;
+            current_jobs.lock().unwrap().remove(&job_id);
         }
     }
 
     /// Traite un job avec gestion des erreurs et retry
     async fn process_job(
         worker_id: usize,
+        instance_id: &str,
         queue: &Arc<Q>,
         handler: &Arc<H>,
         mut job: Job,
@@ -145,25 +262,52 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
             return;
         }
 
-        // Mettre à jour le statut
-        job.status = JobStatus::Running;
-        if let Err(e) = queue.update_job(&job).await {
-            tracing::error!(worker_id, job_id = %job_id, error = %e, "Failed to update job status");
+        // Démarrer le job, sauf s'il a changé d'état depuis sa mise en queue
+        // (ex: annulé ou supprimé entre-temps)
+        job.start_attempt(format!("{}/{}", instance_id, worker_id), Utc::now());
+        if let Err(e) = Self::transition(queue, &mut job, JobStatus::Running).await {
+            tracing::warn!(worker_id, job_id = %job_id, error = %e, "Job cannot be started, skipping");
+            if let Err(e) = queue.release_lock(job_id).await {
+                tracing::error!(worker_id, job_id = %job_id, error = %e, "Failed to release lock");
+            }
+            return;
         }
 
+        // Publie l'avancement signalé par le handler au fil de l'eau
+        let (progress_tx, mut progress_rx) = mpsc::unbounded_channel();
+        let progress = ProgressReporter::new(progress_tx);
+        let mut progress_forwarder = {
+            let queue = queue.clone();
+            let job = job.clone();
+            tokio::spawn(async move {
+                while let Some(value) = progress_rx.recv().await {
+                    if let Err(e) = queue.publish_progress(&job, value).await {
+                        tracing::warn!(job_id = %job.id, error = %e, "Failed to publish progress");
+                    }
+                }
+            })
+        };
+
         // Exécuter le handler avec timeout
         let timeout_duration = Duration::from_millis(job.timeout_ms);
         let handler_result = tokio::time::timeout(
             timeout_duration,
-            handler.handle(&job)
+            handler.handle_with_progress(&job, &progress)
         ).await;
 
+        // Les derniers avancements sont publiés avant le nouvel état du job
+        drop(progress);
+        if tokio::time::timeout(PROGRESS_FLUSH_TIMEOUT, &mut progress_forwarder).await.is_err() {
+            progress_forwarder.abort();
+        }
+
         // Gérer le résultat
         match handler_result {
-            Ok(Ok(())) => {
+            Ok(Ok(result)) => {
                 // Succès !
-                job.status = JobStatus::Completed;
-                if let Err(e) = queue.update_job(&job).await {
+                job.result = result;
+                job.finish_attempt(AttemptOutcome::Completed, None, Utc::now());
+                if let Err(e) = Self::transition(queue, &mut job, JobStatus::Completed).await {
                     tracing::error!(worker_id, job_id = %job_id, error = %e, "Failed to update job");
                 }
 
@@ -173,6 +317,8 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
                     kind = %job.kind,
                     "Job completed successfully"
                 );
+
+                Self::release_dependents(worker_id, queue, job_id).await;
             }
             Ok(Err(e)) => {
                 // Erreur du handler
@@ -185,7 +331,10 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
                     "Job failed"
                 );
 
-                Self::handle_job_failure(worker_id, queue, job).await;
+                let permanent = matches!(e, IronForgeError::PermanentFailure(_));
+                job.last_error = Some(e.to_string());
+                job.finish_attempt(AttemptOutcome::Failed, job.last_error.clone(), Utc::now());
+                Self::handle_job_failure(worker_id, queue, job, permanent).await;
             }
             Err(_) => {
                 // Timeout
@@ -197,7 +346,9 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
                     "Job timed out"
                 );
 
-                Self::handle_job_failure(worker_id, queue, job).await;
+                job.last_error = Some(format!("timed out after {} ms", job.timeout_ms));
+                job.finish_attempt(AttemptOutcome::TimedOut, job.last_error.clone(), Utc::now());
+                Self::handle_job_failure(worker_id, queue, job, false).await;
             }
         }
 
@@ -207,8 +358,25 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
         }
     }
 
-    /// Gère l'échec d'un job (retry ou DLQ)
-    async fn handle_job_failure(worker_id: usize, queue: &Arc<Q>, mut job: Job) {
+    /// Gère l'échec d'un job (retry, DLQ, ou Failed si l'échec est définitif)
+    async fn handle_job_failure(worker_id: usize, queue: &Arc<Q>, mut job: Job, permanent: bool) {
+        if permanent {
+            tracing::warn!(
+                worker_id,
+                job_id = %job.id,
+                kind = %job.kind,
+                "Job failed permanently, not retrying"
+            );
+
+            if let Err(e) = Self::transition(queue, &mut job, JobStatus::Failed).await {
+                tracing::error!(worker_id, job_id = %job.id, error = %e, "Failed to update job");
+                return;
+            }
+
+            Self::release_dependents(worker_id, queue, job.id).await;
+            return;
+        }
+
         job.retry_count += 1;
 
         if job.retry_count < job.max_retries {
@@ -223,12 +391,10 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
                 "Scheduling job retry"
             );
 
-            job.status = JobStatus::Queued;
-            
             // Attendre le backoff avant de re-enqueue
             sleep(Duration::from_millis(delay_ms)).await;
 
-            if let Err(e) = queue.enqueue(&job).await {
+            if let Err(e) = Self::transition(queue, &mut job, JobStatus::Queued).await {
                 tracing::error!(
                     worker_id,
                     job_id = %job.id,
@@ -253,7 +419,38 @@ impl<Q: QueueBackend + 'static, H: JobHandler + 'static> Executor<Q, H> {
                     error = %e,
                     "Failed to move job to DLQ"
                 );
+                return;
             }
+
+            Self::release_dependents(worker_id, queue, job.id).await;
+        }
+    }
+
+    /// Fait passer le job dans le statut `to` avec les changements de son exécution.
+    /// Si le job a été modifié entre-temps, la transition est rejouée sur sa
+    /// dernière version tant qu'elle reste permise ; sinon l'écriture concurrente
+    /// (annulation, retry...) l'emporte et celle du worker est abandonnée.
+    async fn transition(queue: &Arc<Q>, job: &mut Job, to: JobStatus) -> Result<()> {
+        let executed = job.clone();
+        *job = queue
+            .modify_job(job.id, Some(to), |latest| {
+                latest.absorb_execution(&executed);
+                Ok(())
+            })
+            .await?;
+
+        Ok(())
+    }
+
+    /// Débloque ou annule les jobs qui dépendent d'un job terminé
+    async fn release_dependents(worker_id: usize, queue: &Arc<Q>, job_id: Uuid) {
+        if let Err(e) = queue.resolve_dependents(job_id).await {
+            tracing::error!(
+                worker_id,
+                job_id = %job_id,
+                error = %e,
+                "Failed to resolve dependent jobs"
+            );
         }
     }
 }
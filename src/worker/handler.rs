@@ -1,9 +1,35 @@
 // Trait pour les handlers de jobs personnalisés
 use async_trait::async_trait;
 use crate::models::{Job, Result};
+use super::progress::ProgressReporter;
 
 #[async_trait]
 pub trait JobHandler: Send + Sync {
-    /// Exécute le traitement d'un job
+    /// Exécute le traitement d'un job.
+    ///
+    /// Une erreur déclenche un retry (puis la DLQ), sauf `PermanentFailure`
+    /// qui fait passer le job directement en Failed.
     async fn handle(&self, job: &Job) -> Result<()>;
+    
+    /// Exécute le traitement d'un job et retourne son résultat.
+    ///
+    /// Le résultat est stocké sur le job et transmis aux jobs qui en dépendent
+    /// (étape suivante d'une chaîne, callback d'un chord). Par défaut, délègue
+    /// à `handle` sans résultat.
+    async fn handle_with_result(&self, job: &Job) -> Result<Option<serde_json::Value>> {
+        self.handle(job).await.map(|()| None)
+    }
+    
+    /// Exécute le traitement d'un job en pouvant signaler son avancement.
+    ///
+    /// C'est la méthode appelée par l'executor. Par défaut, délègue à
+    /// `handle_with_result` sans signaler d'avancement.
+    async fn handle_with_progress(
+        &self,
+        job: &Job,
+        progress: &ProgressReporter,
+    ) -> Result<Option<serde_json::Value>> {
+        let _ = progress;
+        self.handle_with_result(job).await
+    }
 }
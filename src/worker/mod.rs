@@ -1,5 +1,9 @@
 mod executor;
 mod handler;
+mod progress;
+mod reaper;
 
 pub use executor::{Executor, ExecutorConfig};
 pub use handler::JobHandler;
+pub use progress::ProgressReporter;
+pub use reaper::{Reaper, ReaperConfig, REAPER_LEASE};
@@ -0,0 +1,28 @@
+use tokio::sync::mpsc;
+
+/// Permet à un handler de signaler l'avancement du job en cours.
+///
+/// Les valeurs sont publiées en arrière-plan sur le flux d'événements du job
+/// (`GET /jobs/:id/events`) ; `report` ne bloque jamais le handler.
+#[derive(Debug, Clone)]
+pub struct ProgressReporter {
+    sender: Option<mpsc::UnboundedSender<serde_json::Value>>,
+}
+
+impl ProgressReporter {
+    pub(crate) fn new(sender: mpsc::UnboundedSender<serde_json::Value>) -> Self {
+        Self { sender: Some(sender) }
+    }
+
+    /// Reporter sans destination (ex: tests de handlers)
+    pub fn disabled() -> Self {
+        Self { sender: None }
+    }
+
+    /// Signale l'avancement (ex: `json!({ "percent": 40 })`)
+    pub fn report(&self, progress: serde_json::Value) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(progress);
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    models::{AttemptOutcome, Job, JobCursor, JobFilter, JobStatus, Result, WorkerInfo},
+    queue::QueueBackend,
+};
+
+/// Nom du bail de leader partagé par les reapers ; son détenteur sert de
+/// heartbeat à la sonde de readiness
+pub const REAPER_LEASE: &str = "reaper";
+
+/// Nombre de jobs Running lus par page
+const REAP_PAGE_SIZE: usize = 100;
+
+/// Configuration du reaper
+#[derive(Debug, Clone)]
+pub struct ReaperConfig {
+    /// Identifiant de l'instance candidate au rôle de leader
+    pub instance_id: String,
+
+    /// Intervalle entre deux passages (en secondes)
+    pub interval_secs: u64,
+
+    /// Durée du bail de leader (en secondes)
+    pub leader_ttl_secs: u64,
+
+    /// Ancienneté minimum d'une tentative avant qu'elle puisse être récupérée
+    /// (en secondes), pour ne pas devancer le premier heartbeat d'un worker
+    pub grace_secs: i64,
+}
+
+impl Default for ReaperConfig {
+    fn default() -> Self {
+        Self {
+            instance_id: WorkerInfo::generate_id(),
+            interval_secs: 15,
+            leader_ttl_secs: 45,
+            grace_secs: 60,
+        }
+    }
+}
+
+/// Reaper - récupère les jobs Running dont l'instance a disparu du registre
+/// des workers (arrêt brutal, perte réseau).
+///
+/// Le job est traité comme une tentative échouée : remis en queue s'il lui
+/// reste des retries, envoyé en DLQ sinon. Seule l'instance qui détient le
+/// bail de leader effectue les passages.
+pub struct Reaper<Q: QueueBackend> {
+    queue: Arc<Q>,
+    config: ReaperConfig,
+}
+
+impl<Q: QueueBackend + 'static> Reaper<Q> {
+    /// Crée un nouveau reaper
+    pub fn new(queue: Arc<Q>, config: ReaperConfig) -> Self {
+        Self { queue, config }
+    }
+
+    /// Démarre la boucle d'élection et de récupération
+    pub async fn run(&self) -> Result<()> {
+        tracing::info!(instance_id = %self.config.instance_id, "Starting IronForge reaper");
+
+        let mut interval = tokio::time::interval(Duration::from_secs(self.config.interval_secs.max(1)));
+
+        loop {
+            interval.tick().await;
+
+            let leader = self
+                .queue
+                .try_acquire_leadership(REAPER_LEASE, &self.config.instance_id, self.config.leader_ttl_secs)
+                .await;
+
+            match leader {
+                Ok(true) => {
+                    if let Err(e) = self.reap(Utc::now()).await {
+                        tracing::error!(error = %e, "Failed to reap orphaned jobs");
+                    }
+                }
+                Ok(false) => {}
+                Err(e) => {
+                    tracing::error!(error = %e, "Failed to acquire reaper leadership");
+                }
+            }
+        }
+    }
+
+    /// Récupère les jobs orphelins à `now`, retourne le nombre de jobs récupérés
+    pub async fn reap(&self, now: DateTime<Utc>) -> Result<usize> {
+        let alive: HashSet<String> = self
+            .queue
+            .list_workers()
+            .await?
+            .into_iter()
+            .map(|worker| worker.id)
+            .collect();
+
+        let filter = JobFilter {
+            status: Some(JobStatus::Running),
+            ..Default::default()
+        };
+        let mut orphans = Vec::new();
+        let mut cursor: Option<JobCursor> = None;
+
+        loop {
+            let page = self.queue.list_jobs(&filter, cursor, REAP_PAGE_SIZE).await?;
+            orphans.extend(
+                page.jobs
+                    .into_iter()
+                    .filter(|job| is_orphaned(job, &alive, now, self.config.grace_secs)),
+            );
+
+            match page.next_cursor {
+                Some(next) => cursor = Some(next.parse()?),
+                None => break,
+            }
+        }
+
+        let mut reaped = 0;
+        for job in orphans {
+            match self.recover(job).await {
+                Ok(()) => reaped += 1,
+                Err(e) => tracing::warn!(error = %e, "Failed to recover orphaned job"),
+            }
+        }
+
+        Ok(reaped)
+    }
+
+    /// Clôt la tentative interrompue puis remet le job en queue ou en DLQ
+    async fn recover(&self, mut job: Job) -> Result<()> {
+        let worker_id = job.attempts.last().map(|attempt| attempt.worker_id.clone()).unwrap_or_default();
+        job.last_error = Some(format!("worker {} lost while running the job", worker_id));
+        job.finish_attempt(AttemptOutcome::Failed, job.last_error.clone(), Utc::now());
+        job.retry_count += 1;
+
+        // Une écriture concurrente (le worker qui termine malgré tout) l'emporte
+        let executed = job.clone();
+        if job.retry_count < job.max_retries {
+            // Même backoff que les retries de l'executor : le job attend parmi les différés
+            let retry_at = Utc::now() + chrono::Duration::milliseconds(job.calculate_backoff_delay() as i64);
+            self.queue
+                .modify_job(job.id, Some(JobStatus::Queued), |latest| {
+                    latest.absorb_execution(&executed);
+                    latest.scheduled_for = Some(retry_at);
+                    Ok(())
+                })
+                .await?;
+        } else {
+            self.queue.move_to_dlq(&job).await?;
+            self.queue.resolve_dependents(job.id).await?;
+        }
+
+        self.queue.release_lock(job.id).await?;
+
+        tracing::warn!(
+            job_id = %job.id,
+            kind = %job.kind,
+            worker_id = %worker_id,
+            retry_count = job.retry_count,
+            "Orphaned job recovered"
+        );
+
+        Ok(())
+    }
+}
+
+/// Un job Running est orphelin si l'instance de sa dernière tentative
+/// (`{instance}/{worker}`) n'est plus dans le registre depuis le délai de grâce
+fn is_orphaned(job: &Job, alive: &HashSet<String>, now: DateTime<Utc>, grace_secs: i64) -> bool {
+    let Some(attempt) = job.attempts.last() else {
+        return false;
+    };
+    let instance = attempt
+        .worker_id
+        .rsplit_once('/')
+        .map_or(attempt.worker_id.as_str(), |(instance, _)| instance);
+
+    attempt.finished_at.is_none()
+        && (now - attempt.started_at).num_seconds() >= grace_secs
+        && !alive.contains(instance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use crate::queue::MemoryQueueBackend;
+
+    #[test]
+    fn test_orphan_detection() {
+        let now = Utc::now();
+        let mut job = Job::new("test".to_string(), serde_json::json!({}));
+        job.status = JobStatus::Running;
+        job.start_attempt("host-1-abcd/2".to_string(), now - Duration::seconds(120));
+
+        let mut alive = HashSet::from(["host-1-abcd".to_string()]);
+        assert!(!is_orphaned(&job, &alive, now, 60));
+
+        alive.clear();
+        assert!(is_orphaned(&job, &alive, now, 60));
+
+        // Tentative trop récente : le worker n'a peut-être pas encore publié son heartbeat
+        assert!(!is_orphaned(&job, &alive, now - Duration::seconds(90), 60));
+
+        job.finish_attempt(AttemptOutcome::Completed, None, now);
+        assert!(!is_orphaned(&job, &alive, now, 60));
+    }
+
+    #[tokio::test]
+    async fn test_recovered_job_waits_for_its_backoff() {
+        let queue = Arc::new(MemoryQueueBackend::new());
+        let job = Job::new("test".to_string(), serde_json::json!({}));
+        queue.enqueue(&job).await.unwrap();
+        // Pris par un worker qui s'est arrêté depuis
+        queue.dequeue(0).await.unwrap().unwrap();
+        let started_at = Utc::now() - Duration::seconds(120);
+        queue
+            .modify_job(job.id, Some(JobStatus::Running), |latest| {
+                latest.start_attempt("host-1-abcd/2".to_string(), started_at);
+                Ok(())
+            })
+            .await
+            .unwrap();
+
+        let reaper = Reaper::new(queue.clone(), ReaperConfig::default());
+        assert_eq!(reaper.reap(Utc::now()).await.unwrap(), 1);
+
+        let recovered = queue.get_job(job.id).await.unwrap().unwrap();
+        assert_eq!(recovered.status, JobStatus::Queued);
+        assert_eq!(recovered.retry_count, 1);
+        assert!(recovered.is_delayed(Utc::now()));
+        assert!(queue.dequeue(0).await.unwrap().is_none());
+        assert_eq!(queue.get_stats().await.unwrap().delayed_jobs, 1);
+    }
+}
@@ -1,8 +1,8 @@
 /// Tests d'intégration end-to-end
 use iron_forge::{
     worker::{Executor, ExecutorConfig, JobHandler},
-    Job, Priority, RedisQueueBackend, QueueBackend,
-    models::{IronForgeError, Result},
+    Job, JobStatus, Priority, RedisQueueBackend, QueueBackend,
+    models::{CreateWorkflowRequest, IronForgeError, Result},
 };
 use async_trait::async_trait;
 use std::sync::{Arc, atomic::{AtomicU32, Ordering}};
@@ -66,6 +66,7 @@ async fn test_full_flow_submit_process_complete() {
         dequeue_timeout_secs: 1,
         worker_count: 1,
         graceful_shutdown: true,
+        ..Default::default()
     };
 
     let executor = Executor::new(backend.clone(), handler, config);
@@ -155,6 +156,7 @@ async fn test_retry_on_failure() {
         dequeue_timeout_secs: 1,
         worker_count: 1,
         graceful_shutdown: true,
+        ..Default::default()
     };
 
     let executor = Executor::new(backend.clone(), handler, config);
@@ -196,3 +198,78 @@ async fn test_stats_accuracy() {
         initial_stats.queue_depth + 3
     );
 }
+
+#[tokio::test]
+#[ignore] // Nécessite Redis
+async fn test_dependent_job_runs_after_parent() {
+    let backend = Arc::new(
+        RedisQueueBackend::new("redis://127.0.0.1:6379")
+            .await
+            .expect("Failed to connect to Redis")
+    );
+
+    let parent = Job::new("test.parent".to_string(), json!({}));
+    let mut child = Job::new("test.child".to_string(), json!({}));
+    child.depends_on = vec![parent.id];
+
+    backend.enqueue(&parent).await.expect("Failed to enqueue parent");
+    let status = backend
+        .enqueue_with_dependencies(&child)
+        .await
+        .expect("Failed to enqueue child");
+    assert_eq!(status, JobStatus::Waiting);
+
+    let completed_count = Arc::new(AtomicU32::new(0));
+    let handler = Arc::new(TestHandler {
+        completed_count: completed_count.clone(),
+        should_fail: false,
+    });
+
+    let config = ExecutorConfig {
+        dequeue_timeout_secs: 1,
+        worker_count: 1,
+        ..Default::default()
+    };
+
+    let executor = Executor::new(backend.clone(), handler, config);
+    let executor_handle = tokio::spawn(async move {
+        executor.run().await
+    });
+
+    sleep(Duration::from_secs(3)).await;
+
+    let child = backend.get_job(child.id).await.expect("Failed to get job").expect("No job");
+    assert_eq!(child.status, JobStatus::Completed);
+    assert_eq!(completed_count.load(Ordering::SeqCst), 2);
+
+    executor_handle.abort();
+    let _ = backend.delete_job(parent.id).await;
+    let _ = backend.delete_job(child.id).await;
+}
+
+#[tokio::test]
+#[ignore] // Nécessite Redis
+async fn test_workflow_with_missing_parent_is_not_created() {
+    let backend = RedisQueueBackend::new("redis://127.0.0.1:6379")
+        .await
+        .expect("Failed to connect to Redis");
+
+    let request: CreateWorkflowRequest = serde_json::from_value(json!({
+        "type": "chain",
+        "jobs": [
+            { "kind": "test.first", "payload": {} },
+            { "kind": "test.second", "payload": {}, "depends_on": [uuid::Uuid::new_v4()] }
+        ],
+        "on_failure": { "kind": "test.errback", "payload": {} }
+    })).expect("Invalid workflow");
+    let plan = request.into_plan();
+
+    let result = backend.create_workflow(&plan).await;
+    assert!(matches!(result, Err(IronForgeError::JobNotFound(_))));
+
+    // Aucun job ni workflow partiellement écrit
+    assert!(backend.get_workflow(plan.workflow.id).await.expect("Failed to get workflow").is_none());
+    for job in plan.jobs.iter().chain(&plan.errback) {
+        assert!(backend.get_job(job.id).await.expect("Failed to get job").is_none());
+    }
+}